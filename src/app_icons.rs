@@ -0,0 +1,192 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+
+use gtk::glib;
+
+use crate::utils::run_command;
+
+/// File types an icon may be found as, in order of preference
+const ICON_EXTENSIONS: [&str; 3] = ["svg", "png", "xpm"];
+
+/// Looks up each icon passed as an argument inside a box - as an absolute path,
+/// in the hicolor theme of each data dir from the largest useful size down, then
+/// in `/usr/share/pixmaps`. For each icon found it prints the icon, the file's
+/// extension and its size in bytes, each followed by a NUL byte, then the file itself.
+const FIND_ICONS_SCRIPT: &str = r#"if [ -n "$DISTROBOX_HOST_HOME" ] && [ "$HOME" != "$DISTROBOX_HOST_HOME" ]; then
+    data_dirs="${XDG_DATA_HOME:-$HOME/.local/share}:${XDG_DATA_DIRS:-/usr/local/share:/usr/share}"
+else
+    data_dirs="${XDG_DATA_DIRS:-/usr/local/share:/usr/share}"
+fi
+find_icon() {
+    case "$1" in
+        /*) [ -f "$1" ] && echo "$1"; return ;;
+    esac
+    old_ifs=$IFS
+    IFS=:
+    for d in $data_dirs; do
+        for size in scalable 128x128 256x256 96x96 64x64 48x48 512x512 32x32 24x24 22x22 16x16; do
+            for ext in svg png xpm; do
+                f="${d%/}/icons/hicolor/$size/apps/$1.$ext"
+                [ -f "$f" ] && echo "$f" && IFS=$old_ifs && return
+            done
+        done
+    done
+    IFS=$old_ifs
+    for f in "/usr/share/pixmaps/$1.svg" "/usr/share/pixmaps/$1.png" "/usr/share/pixmaps/$1.xpm" "/usr/share/pixmaps/$1"; do
+        [ -f "$f" ] && echo "$f" && return
+    done
+}
+for icon; do
+    f=$(find_icon "$icon")
+    [ -n "$f" ] || continue
+    printf '%s\0%s\0%s\0' "$icon" "${f##*.}" "$(wc -c < "$f" | tr -d ' ')"
+    cat "$f"
+done"#;
+
+/// Returns the directory which icons copied out of a box are kept in
+fn get_box_icons_dir(box_name: &str) -> PathBuf {
+    glib::user_cache_dir()
+        .join("boxbuddy")
+        .join("icons")
+        .join(box_name)
+}
+
+/// Names the cached copy of an icon. Icons given as names and as paths are
+/// kept apart by a prefix, and `%` and `/` are escaped so no two icons share a file.
+fn get_cached_icon_path(box_name: &str, icon: &str, extension: &str) -> PathBuf {
+    let (kind, icon) = match icon.strip_prefix('/') {
+        Some(path) => ("path", path),
+        None => ("name", icon),
+    };
+    let file_stem = format!("{kind}-{}", icon.replace('%', "%25").replace('/', "%2F"));
+
+    get_box_icons_dir(box_name).join(format!("{file_stem}.{extension}"))
+}
+
+/// Removes the icons copied out of a box, e.g. once it has been deleted
+pub fn forget_box_icons(box_name: &str) {
+    let _ = fs::remove_dir_all(get_box_icons_dir(box_name));
+}
+
+fn find_cached_icon(box_name: &str, icon: &str) -> Option<PathBuf> {
+    ICON_EXTENSIONS
+        .iter()
+        .map(|ext| get_cached_icon_path(box_name, icon, ext))
+        .find(|path| path.is_file())
+}
+
+/// Gets a host copy of each of `icons`, as named in desktop files inside a box.
+/// Icons which haven't been copied before are all fetched in a single
+/// `distrobox enter`. Icons which can't be found in the box are left out.
+pub fn get_app_icons_in_box(box_name: &str, icons: &[String]) -> HashMap<String, PathBuf> {
+    let mut icon_files = HashMap::new();
+    let mut missing_icons: Vec<&str> = vec![];
+
+    for icon in icons {
+        if icon.is_empty()
+            || icon_files.contains_key(icon)
+            || missing_icons.contains(&icon.as_str())
+        {
+            continue;
+        }
+
+        match find_cached_icon(box_name, icon) {
+            Some(path) => {
+                icon_files.insert(icon.clone(), path);
+            }
+            None => missing_icons.push(icon),
+        }
+    }
+
+    if missing_icons.is_empty() {
+        return icon_files;
+    }
+
+    let mut args = vec!["enter", box_name, "--", "sh", "-c", FIND_ICONS_SCRIPT, "sh"];
+    args.extend(missing_icons);
+
+    let Ok(output) = run_command("distrobox", Some(&args)) else {
+        return icon_files;
+    };
+
+    if fs::create_dir_all(get_box_icons_dir(box_name)).is_err() {
+        return icon_files;
+    }
+
+    for (icon, extension, contents) in parse_icon_files(&output.stdout) {
+        if !ICON_EXTENSIONS.contains(&extension.as_str()) {
+            continue;
+        }
+
+        let path = get_cached_icon_path(box_name, &icon, &extension);
+        if fs::write(&path, contents).is_ok() {
+            icon_files.insert(icon, path);
+        }
+    }
+
+    icon_files
+}
+
+/// Splits the output of `FIND_ICONS_SCRIPT` into each icon, its extension and contents
+fn parse_icon_files(mut output: &[u8]) -> Vec<(String, String, &[u8])> {
+    let mut icon_files = vec![];
+
+    while let Some((icon, extension, len, rest)) = read_icon_header(output) {
+        if rest.len() < len {
+            break;
+        }
+
+        let (contents, rest) = rest.split_at(len);
+        icon_files.push((icon, extension, contents));
+        output = rest;
+    }
+
+    icon_files
+}
+
+fn read_icon_header(output: &[u8]) -> Option<(String, String, usize, &[u8])> {
+    let mut fields = output.splitn(4, |b| *b == 0);
+
+    // distrobox may print its own messages first, e.g. when starting the box
+    let icon = String::from_utf8_lossy(fields.next()?)
+        .lines()
+        .last()?
+        .to_string();
+    let extension = String::from_utf8_lossy(fields.next()?).into_owned();
+    let len = std::str::from_utf8(fields.next()?).ok()?.parse().ok()?;
+
+    Some((icon, extension, len, fields.next()?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cached_icon_paths_are_distinct() {
+        let icons = [
+            "foo",
+            "/foo",
+            "a/b",
+            "a_b",
+            "/a/b",
+            "a%2Fb",
+            "name-foo",
+            "/usr/share/pixmaps/foo.png",
+            "usr/share/pixmaps/foo.png",
+        ];
+
+        let paths: Vec<PathBuf> = icons
+            .iter()
+            .map(|icon| get_cached_icon_path("box", icon, "png"))
+            .collect();
+
+        for (i, path) in paths.iter().enumerate() {
+            assert_eq!(path.parent(), Some(get_box_icons_dir("box").as_path()));
+            for (j, other) in paths.iter().enumerate().skip(i + 1) {
+                assert_ne!(path, other, "{:?} and {:?}", icons[i], icons[j]);
+            }
+        }
+    }
+}
@@ -0,0 +1,66 @@
+use std::path::PathBuf;
+
+use gtk::glib::{self, KeyFile, KeyFileFlags};
+
+use crate::create_presets::{read_options_group, write_options_group};
+use crate::distrobox_handler::BoxCreateOptions;
+
+/// Returns the path of the file remembering the options each box was created
+/// with, so it can be recreated the same way. It may not exist yet.
+///
+/// Each box is a group named after it, in the same format as the presets file.
+pub fn get_box_options_path() -> PathBuf {
+    glib::user_config_dir().join("boxbuddy").join("boxes.ini")
+}
+
+/// Gets the options a box was created with, or None if it wasn't created by `BoxBuddy`
+pub fn get_saved_box_options(box_name: &str) -> Option<BoxCreateOptions> {
+    let key_file = load_box_options_file();
+    if !key_file.has_group(box_name) {
+        return None;
+    }
+
+    Some(BoxCreateOptions {
+        name: box_name.to_string(),
+        ..read_options_group(&key_file, box_name)
+    })
+}
+
+/// Remembers the options a box was created with, replacing any from an earlier box of the same name
+pub fn save_box_options(options: &BoxCreateOptions) -> Result<(), glib::Error> {
+    let key_file = load_box_options_file();
+
+    let _ = key_file.remove_group(&options.name);
+    write_options_group(&key_file, &options.name, options);
+
+    save_box_options_file(&key_file)
+}
+
+/// Forgets the options of a box which has been deleted
+pub fn forget_box_options(box_name: &str) -> Result<(), glib::Error> {
+    let key_file = load_box_options_file();
+    if key_file.remove_group(box_name).is_err() {
+        return Ok(());
+    }
+
+    save_box_options_file(&key_file)
+}
+
+fn load_box_options_file() -> KeyFile {
+    let key_file = KeyFile::new();
+    // a missing or broken file just means no box's options are known
+    let _ = key_file.load_from_file(get_box_options_path(), KeyFileFlags::KEEP_COMMENTS);
+
+    key_file
+}
+
+fn save_box_options_file(key_file: &KeyFile) -> Result<(), glib::Error> {
+    let path = get_box_options_path();
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| glib::Error::new(glib::FileError::Failed, &e.to_string()))?;
+    }
+
+    key_file.save_to_file(path)
+}
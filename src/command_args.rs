@@ -0,0 +1,134 @@
+/// Characters which never need quoting for a POSIX shell
+fn is_shell_safe_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.' | '/' | ':' | ',' | '+' | '=' | '@')
+}
+
+/// Quotes an argument for a POSIX shell, so that it is read back as exactly
+/// the same single word - whatever spaces, quotes or `$` it contains.
+pub fn quote_shell_arg(arg: &str) -> String {
+    if !arg.is_empty() && arg.chars().all(is_shell_safe_char) {
+        return arg.to_string();
+    }
+
+    // nothing is special inside single quotes, apart from the closing quote
+    format!("'{}'", arg.replace('\'', "'\\''"))
+}
+
+/// Quotes and joins an argv into a command line for a POSIX shell
+pub fn join_shell_args<S: AsRef<str>>(args: &[S]) -> String {
+    args.iter()
+        .map(|a| quote_shell_arg(a.as_ref()))
+        .collect::<Vec<String>>()
+        .join(" ")
+}
+
+/// Why a volume can't be passed to the container manager
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum VolumeArgError {
+    /// Both sides of a volume have to be absolute paths
+    NotAbsolute,
+    /// `:` separates the host path, box path and options, so can't be in either path
+    ContainsColon,
+    /// Distrobox splits its volumes on whitespace, so they can't contain any
+    ContainsWhitespace,
+}
+
+/// Formats a `--volume` argument mounting `host_path` at `box_path`
+pub fn format_volume_arg(host_path: &str, box_path: &str) -> Result<String, VolumeArgError> {
+    for path in [host_path, box_path] {
+        if !path.starts_with('/') {
+            return Err(VolumeArgError::NotAbsolute);
+        }
+        if path.contains(':') {
+            return Err(VolumeArgError::ContainsColon);
+        }
+        if path.chars().any(char::is_whitespace) {
+            return Err(VolumeArgError::ContainsWhitespace);
+        }
+    }
+
+    Ok(format!("{host_path}:{box_path}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::process::Command;
+
+    /// Awkward inputs - spaces, both quotes, `$` and friends, and unicode
+    const TRICKY_ARGS: [&str; 10] = [
+        "my box",
+        "it's",
+        "say \"hi\"",
+        "$HOME",
+        "$(touch /tmp/boxbuddy-should-not-exist)",
+        "`id`; rm -rf ~",
+        "ünïcödé-箱",
+        "tab\tand\nnewline",
+        "back\\slash",
+        "",
+    ];
+
+    /// Has `sh` parse a command line and print back each word it ends up with
+    fn words_seen_by_shell(command_line: &str) -> Vec<String> {
+        let script = format!("for word in {command_line}; do printf '%s\\0' \"$word\"; done");
+        let output = Command::new("sh").arg("-c").arg(script).output().unwrap();
+        assert!(output.status.success());
+
+        String::from_utf8(output.stdout)
+            .unwrap()
+            .split_terminator('\0')
+            .map(String::from)
+            .collect()
+    }
+
+    #[test]
+    fn quoted_args_survive_the_shell() {
+        for arg in TRICKY_ARGS {
+            assert_eq!(
+                words_seen_by_shell(&quote_shell_arg(arg)),
+                vec![arg],
+                "{arg:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn safe_args_are_left_alone() {
+        assert_eq!(quote_shell_arg("fedora-40"), "fedora-40");
+        assert_eq!(quote_shell_arg("/home/me/boxes"), "/home/me/boxes");
+        assert_eq!(quote_shell_arg(""), "''");
+    }
+
+    #[test]
+    fn joined_args_stay_separate() {
+        assert_eq!(
+            words_seen_by_shell(&join_shell_args(&TRICKY_ARGS)),
+            TRICKY_ARGS
+        );
+    }
+
+    #[test]
+    fn volume_args() {
+        assert_eq!(
+            format_volume_arg("/opt/my_dir", "/usr/local/my_dir"),
+            Ok(String::from("/opt/my_dir:/usr/local/my_dir"))
+        );
+        assert_eq!(
+            format_volume_arg("/mnt/ünïcödé", "/mnt/it's-$HOME"),
+            Ok(String::from("/mnt/ünïcödé:/mnt/it's-$HOME"))
+        );
+        assert_eq!(
+            format_volume_arg("/mnt/my files", "/mnt/files"),
+            Err(VolumeArgError::ContainsWhitespace)
+        );
+        assert_eq!(
+            format_volume_arg("/mnt/files", "/mnt/a:ro"),
+            Err(VolumeArgError::ContainsColon)
+        );
+        assert_eq!(
+            format_volume_arg("/mnt/files", "files"),
+            Err(VolumeArgError::NotAbsolute)
+        );
+    }
+}
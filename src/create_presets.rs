@@ -0,0 +1,204 @@
+use std::path::PathBuf;
+
+use gtk::glib::{self, KeyFile, KeyFileFlags};
+
+use crate::distrobox_handler::{BoxCreateOptions, NvidiaMode};
+
+/// Written in a home directory to stand for the new box's name, so a
+/// preset can give every box its own home.
+pub const HOME_NAME_PLACEHOLDER: &str = "{name}";
+
+/// A saved set of options for the create pop-up
+#[derive(Debug, Clone, Default)]
+pub struct CreatePreset {
+    /// Name shown in the Preset dropdown
+    pub name: String,
+    /// Everything the create pop-up would fill in, apart from the box's name.
+    /// The home directory may contain `HOME_NAME_PLACEHOLDER`.
+    pub options: BoxCreateOptions,
+}
+
+/// Returns the path of the presets file. It may not exist yet.
+///
+/// Each preset is a group in an ini file, using the same key names as
+/// `distrobox assemble` where it has them, so the file is easy to read and share.
+pub fn get_presets_path() -> PathBuf {
+    glib::user_config_dir().join("boxbuddy").join("presets.ini")
+}
+
+/// Reads every saved preset, in the order they appear in the file
+pub fn read_presets() -> Vec<CreatePreset> {
+    let key_file = load_presets_file();
+
+    key_file
+        .groups()
+        .iter()
+        .map(|group| preset_from_group(&key_file, group.as_str()))
+        .collect()
+}
+
+/// Saves a preset, replacing any existing one with the same name.
+/// Comments and other presets in the file are kept.
+pub fn save_preset(preset: &CreatePreset) -> Result<(), glib::Error> {
+    let key_file = load_presets_file();
+
+    // start afresh, so options which were turned off don't linger
+    let _ = key_file.remove_group(&preset.name);
+    write_options_group(&key_file, &preset.name, &preset.options);
+
+    save_presets_file(&key_file)
+}
+
+/// Removes a saved preset
+pub fn delete_preset(name: &str) -> Result<(), glib::Error> {
+    let key_file = load_presets_file();
+    key_file.remove_group(name)?;
+
+    save_presets_file(&key_file)
+}
+
+fn load_presets_file() -> KeyFile {
+    let key_file = KeyFile::new();
+    // a missing or broken file just means no presets
+    let _ = key_file.load_from_file(get_presets_path(), KeyFileFlags::KEEP_COMMENTS);
+
+    key_file
+}
+
+fn save_presets_file(key_file: &KeyFile) -> Result<(), glib::Error> {
+    let path = get_presets_path();
+
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| glib::Error::new(glib::FileError::Failed, &e.to_string()))?;
+    }
+
+    key_file.save_to_file(path)
+}
+
+fn preset_from_group(key_file: &KeyFile, group: &str) -> CreatePreset {
+    CreatePreset {
+        name: group.to_string(),
+        options: read_options_group(key_file, group),
+    }
+}
+
+/// Reads the create options in a group. Also used for the options each box
+/// was created with, which are kept in the same format.
+pub fn read_options_group(key_file: &KeyFile, group: &str) -> BoxCreateOptions {
+    let string = |key: &str| {
+        key_file
+            .string(group, key)
+            .ok()
+            .map(|v| v.trim().to_string())
+            .filter(|v| !v.is_empty())
+    };
+    let boolean = |key: &str| key_file.boolean(group, key).ok();
+    let list = |key: &str| -> Vec<String> {
+        key_file
+            .string_list(group, key)
+            .map(|items| {
+                items
+                    .iter()
+                    .map(|i| i.trim().to_string())
+                    .filter(|i| !i.is_empty())
+                    .collect()
+            })
+            .unwrap_or_default()
+    };
+
+    BoxCreateOptions {
+        image: string("image").unwrap_or_default(),
+        home_path: string("home"),
+        use_init: boolean("init").unwrap_or(false),
+        volumes: list("volume"),
+        hostname: string("hostname"),
+        additional_flags: string("additional_flags"),
+        additional_packages: list("additional_packages"),
+        init_hooks: string("init_hooks"),
+        pre_init_hooks: string("pre_init_hooks"),
+        unshare_netns: boolean("unshare_netns").unwrap_or(false),
+        unshare_ipc: boolean("unshare_ipc").unwrap_or(false),
+        unshare_all: boolean("unshare_all").unwrap_or(false),
+        pull: boolean("pull").unwrap_or(false),
+        no_entry: !boolean("entry").unwrap_or(true),
+        nvidia: match boolean("nvidia") {
+            Some(true) => NvidiaMode::On,
+            Some(false) => NvidiaMode::Off,
+            None => NvidiaMode::Auto,
+        },
+        ..Default::default()
+    }
+}
+
+/// Writes create options to a group, in the format `read_options_group` reads
+pub fn write_options_group(key_file: &KeyFile, group: &str, options: &BoxCreateOptions) {
+    key_file.set_string(group, "image", &options.image);
+    key_file.set_boolean(group, "init", options.use_init);
+
+    let optional_strings = [
+        ("home", &options.home_path),
+        ("hostname", &options.hostname),
+        ("additional_flags", &options.additional_flags),
+        ("init_hooks", &options.init_hooks),
+        ("pre_init_hooks", &options.pre_init_hooks),
+    ];
+    for (key, value) in optional_strings {
+        if let Some(value) = value {
+            key_file.set_string(group, key, value);
+        }
+    }
+
+    for (key, values) in [
+        ("volume", &options.volumes),
+        ("additional_packages", &options.additional_packages),
+    ] {
+        if !values.is_empty() {
+            key_file.set_value(group, key, &format_string_list(values));
+        }
+    }
+
+    // only write the switches which differ from distrobox's defaults
+    let flags = [
+        ("unshare_netns", options.unshare_netns),
+        ("unshare_ipc", options.unshare_ipc),
+        ("unshare_all", options.unshare_all),
+        ("pull", options.pull),
+    ];
+    for (key, value) in flags {
+        if value {
+            key_file.set_boolean(group, key, true);
+        }
+    }
+
+    if options.no_entry {
+        key_file.set_boolean(group, "entry", false);
+    }
+
+    match options.nvidia {
+        NvidiaMode::On => key_file.set_boolean(group, "nvidia", true),
+        NvidiaMode::Off => key_file.set_boolean(group, "nvidia", false),
+        NvidiaMode::Auto => {}
+    }
+}
+
+/// Writes a list in `GKeyFile`'s own format, escaping anything which would
+/// otherwise split or change an item. glib can read lists but not write them.
+fn format_string_list(values: &[String]) -> String {
+    values
+        .iter()
+        .map(|value| {
+            let escaped = value
+                .replace('\\', "\\\\")
+                .replace(';', "\\;")
+                .replace('\n', "\\n")
+                .replace('\t', "\\t");
+
+            match escaped.strip_prefix(' ') {
+                Some(rest) => format!("\\s{rest}"),
+                None => escaped,
+            }
+        })
+        .map(|item| item + ";")
+        .collect()
+}
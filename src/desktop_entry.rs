@@ -0,0 +1,539 @@
+use std::collections::HashMap;
+use std::env;
+
+/// The group holding an application's own keys. Other groups, such as
+/// `[Desktop Action new-window]`, describe extra actions and are ignored.
+const DESKTOP_ENTRY_GROUP: &str = "Desktop Entry";
+
+/// A parsed `.desktop` file, following the freedesktop.org Desktop Entry
+/// Specification. Only the `[Desktop Entry]` group is kept.
+#[derive(Debug, Clone, Default)]
+pub struct DesktopEntry {
+    /// Raw values keyed by key, including any locale, e.g. `Name[de]`
+    values: HashMap<String, String>,
+}
+
+impl DesktopEntry {
+    /// Reads a `.desktop` file's contents. Comments, blank lines, other
+    /// groups and lines which aren't valid entries are skipped.
+    pub fn parse(contents: &str) -> Self {
+        let mut values = HashMap::new();
+        let mut in_desktop_entry = false;
+
+        for line in contents.lines() {
+            let line = line.trim();
+
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            if let Some(group) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+                in_desktop_entry = group == DESKTOP_ENTRY_GROUP;
+                continue;
+            }
+
+            if !in_desktop_entry {
+                continue;
+            }
+
+            let Some((key, value)) = line.split_once('=') else {
+                continue;
+            };
+            let key = key.trim_end();
+
+            if !is_valid_key(key) {
+                continue;
+            }
+
+            // a key given twice is invalid, the first one wins
+            values
+                .entry(key.to_string())
+                .or_insert_with(|| value.trim_start().to_string());
+        }
+
+        DesktopEntry { values }
+    }
+
+    /// Gets a string value, with escapes such as `\s` removed
+    pub fn get(&self, key: &str) -> Option<String> {
+        self.values.get(key).map(|v| unescape_value(v))
+    }
+
+    /// Gets a localised value, trying each of `locales` in turn before
+    /// falling back to the unlocalised key
+    pub fn get_localized(&self, key: &str, locales: &[String]) -> Option<String> {
+        locales
+            .iter()
+            .find_map(|locale| self.get(&format!("{key}[{locale}]")))
+            .or_else(|| self.get(key))
+    }
+
+    /// Gets a boolean value. Anything other than "true" is false.
+    pub fn get_bool(&self, key: &str) -> bool {
+        self.values.get(key).is_some_and(|v| v.trim() == "true")
+    }
+
+    /// Gets a list of strings, which are separated by `;`
+    pub fn get_list(&self, key: &str) -> Vec<String> {
+        let Some(value) = self.values.get(key) else {
+            return vec![];
+        };
+
+        let mut items = vec![];
+        let mut current = String::new();
+        let mut chars = value.chars();
+
+        while let Some(c) = chars.next() {
+            match c {
+                '\\' => match chars.next() {
+                    Some(';') => current.push(';'),
+                    Some(escaped) => {
+                        current.push('\\');
+                        current.push(escaped);
+                    }
+                    None => current.push('\\'),
+                },
+                ';' => items.push(unescape_value(&std::mem::take(&mut current))),
+                _ => current.push(c),
+            }
+        }
+
+        if !current.is_empty() {
+            items.push(unescape_value(&current));
+        }
+
+        items.retain(|i| !i.is_empty());
+        items
+    }
+
+    /// Whether this entry is an application which should be listed, according to
+    /// its `Type`, `Hidden`, `NoDisplay`, `OnlyShowIn` and `NotShowIn` keys.
+    /// `current_desktops` are the names in `$XDG_CURRENT_DESKTOP`.
+    pub fn should_show(&self, current_desktops: &[String]) -> bool {
+        if self.get("Type").as_deref() != Some("Application") {
+            return false;
+        }
+
+        if self.get_bool("Hidden") || self.get_bool("NoDisplay") {
+            return false;
+        }
+
+        let only_show_in = self.get_list("OnlyShowIn");
+        if !only_show_in.is_empty() && !only_show_in.iter().any(|d| current_desktops.contains(d)) {
+            return false;
+        }
+
+        !self
+            .get_list("NotShowIn")
+            .iter()
+            .any(|d| current_desktops.contains(d))
+    }
+
+    /// Splits `Exec` into arguments, expanding its field codes for launching
+    /// without any files. `desktop_file_path` is used for `%k`.
+    /// Returns None if there's no `Exec`, or it isn't quoted correctly.
+    pub fn exec_args(&self, locales: &[String], desktop_file_path: &str) -> Option<Vec<String>> {
+        let exec = self.get("Exec")?;
+        let words = split_exec(&exec)?;
+
+        let mut args = vec![];
+        for word in words {
+            // %i becomes two arguments, or none if there's no icon
+            if word == "%i" {
+                if let Some(icon) = self.get("Icon").filter(|i| !i.is_empty()) {
+                    args.push(String::from("--icon"));
+                    args.push(icon);
+                }
+                continue;
+            }
+
+            // a word that is only a file code stands for files we aren't passing
+            if matches!(word.as_str(), "%f" | "%F" | "%u" | "%U") {
+                continue;
+            }
+
+            let name = || self.get_localized("Name", locales).unwrap_or_default();
+            args.push(expand_field_codes(&word, name, desktop_file_path));
+        }
+
+        (!args.is_empty()).then_some(args)
+    }
+}
+
+/// Keys are made of `A-Za-z0-9-`, optionally followed by a `[locale]`
+fn is_valid_key(key: &str) -> bool {
+    let (name, locale) = match key.split_once('[') {
+        Some((name, rest)) => match rest.strip_suffix(']') {
+            Some(locale) => (name, Some(locale)),
+            None => return false,
+        },
+        None => (key, None),
+    };
+
+    !name.is_empty()
+        && name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+        && locale.is_none_or(|l| !l.is_empty() && !l.contains(['[', ']']))
+}
+
+/// Removes the escapes allowed in string values: `\s`, `\n`, `\t`, `\r` and `\\`
+fn unescape_value(value: &str) -> String {
+    let mut unescaped = String::new();
+    let mut chars = value.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            unescaped.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('s') => unescaped.push(' '),
+            Some('n') => unescaped.push('\n'),
+            Some('t') => unescaped.push('\t'),
+            Some('r') => unescaped.push('\r'),
+            Some('\\') => unescaped.push('\\'),
+            Some(other) => {
+                unescaped.push('\\');
+                unescaped.push(other);
+            }
+            None => unescaped.push('\\'),
+        }
+    }
+
+    unescaped
+}
+
+/// Splits an `Exec` value into words. Words may be double-quoted, and inside
+/// quotes `"`, `` ` ``, `$` and `\` are escaped with a backslash.
+fn split_exec(exec: &str) -> Option<Vec<String>> {
+    let mut words = vec![];
+    let mut current: Option<String> = None;
+    let mut chars = exec.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            ' ' | '\t' | '\n' => {
+                if let Some(word) = current.take() {
+                    words.push(word);
+                }
+            }
+            '"' => {
+                let word = current.get_or_insert_with(String::new);
+                loop {
+                    match chars.next()? {
+                        '"' => break,
+                        '\\' => match chars.next()? {
+                            escaped @ ('"' | '`' | '$' | '\\') => word.push(escaped),
+                            other => {
+                                word.push('\\');
+                                word.push(other);
+                            }
+                        },
+                        other => word.push(other),
+                    }
+                }
+            }
+            _ => current.get_or_insert_with(String::new).push(c),
+        }
+    }
+
+    if let Some(word) = current {
+        words.push(word);
+    }
+
+    Some(words)
+}
+
+/// Expands the field codes in a word of `Exec`, for launching without any
+/// files. Deprecated codes are removed, as the spec asks.
+fn expand_field_codes<N: Fn() -> String>(word: &str, name: N, desktop_file_path: &str) -> String {
+    let mut expanded = String::new();
+    let mut chars = word.chars();
+
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            expanded.push(c);
+            continue;
+        }
+
+        match chars.next() {
+            Some('%') => expanded.push('%'),
+            Some('c') => expanded.push_str(&name()),
+            Some('k') => expanded.push_str(desktop_file_path),
+            // files and URLs, %i when not on its own, and the deprecated codes
+            Some(_) | None => {}
+        }
+    }
+
+    expanded
+}
+
+/// Gets the locale names to look for localised keys with, most specific
+/// first - e.g. "de_AT@euro" gives "de_AT@euro", "de_AT", "de@euro" and "de".
+pub fn get_user_locales() -> Vec<String> {
+    get_locales_from(|var| env::var(var).ok())
+}
+
+/// `get_user_locales`, reading the environment with `get_var`
+fn get_locales_from<F: Fn(&str) -> Option<String>>(get_var: F) -> Vec<String> {
+    let locale = ["LC_ALL", "LC_MESSAGES", "LANG"]
+        .iter()
+        .find_map(|var| get_var(var).filter(|v| !v.is_empty()))
+        .unwrap_or_default();
+
+    // the encoding never appears in keys
+    let (without_modifier, modifier) = match locale.split_once('@') {
+        Some((rest, modifier)) => (rest, Some(modifier)),
+        None => (locale.as_str(), None),
+    };
+    let without_encoding = without_modifier.split('.').next().unwrap_or_default();
+    let (lang, country) = match without_encoding.split_once('_') {
+        Some((lang, country)) => (lang, Some(country)),
+        None => (without_encoding, None),
+    };
+
+    if lang.is_empty() || lang == "C" || lang == "POSIX" {
+        return vec![];
+    }
+
+    let mut locales = vec![];
+    if let (Some(country), Some(modifier)) = (country, modifier) {
+        locales.push(format!("{lang}_{country}@{modifier}"));
+    }
+    if let Some(country) = country {
+        locales.push(format!("{lang}_{country}"));
+    }
+    if let Some(modifier) = modifier {
+        locales.push(format!("{lang}@{modifier}"));
+    }
+    locales.push(lang.to_string());
+
+    locales
+}
+
+/// Gets the names of the desktop environment in use, from `$XDG_CURRENT_DESKTOP`,
+/// for `OnlyShowIn` and `NotShowIn`
+pub fn get_current_desktops() -> Vec<String> {
+    env::var("XDG_CURRENT_DESKTOP")
+        .unwrap_or_default()
+        .split(':')
+        .filter(|d| !d.is_empty())
+        .map(String::from)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(items: &[&str]) -> Vec<String> {
+        items.iter().map(|i| i.to_string()).collect()
+    }
+
+    #[test]
+    fn parse_keeps_only_desktop_entry_group() {
+        let entry = DesktopEntry::parse(
+            "# comment\n\
+             Name=Before any group\n\
+             [Desktop Entry]\n\
+             Name = Files\n\
+             Name[de]=Dateien\n\
+             Name=Second Name\n\
+             Bad Key=x\n\
+             Broken[de=x\n\
+             not an entry\n\
+             [Desktop Action new-window]\n\
+             Exec=files --new-window\n",
+        );
+
+        let cases = [
+            ("Name", Some("Files")),
+            ("Name[de]", Some("Dateien")),
+            ("Bad Key", None),
+            ("Broken[de", None),
+            ("Exec", None),
+        ];
+        for (key, expected) in cases {
+            assert_eq!(entry.get(key).as_deref(), expected, "{key:?}");
+        }
+    }
+
+    #[test]
+    fn get_removes_escapes() {
+        let cases = [
+            (r"a\sb", "a b"),
+            (r"a\nb", "a\nb"),
+            (r"a\tb", "a\tb"),
+            (r"a\rb", "a\rb"),
+            (r"a\\sb", r"a\sb"),
+            (r"a\qb", r"a\qb"),
+            (r"trailing\", r"trailing\"),
+            ("  leading space", "leading space"),
+        ];
+
+        for (value, expected) in cases {
+            let entry = DesktopEntry::parse(&format!("[Desktop Entry]\nComment={value}\n"));
+            assert_eq!(entry.get("Comment").as_deref(), Some(expected), "{value:?}");
+        }
+    }
+
+    #[test]
+    fn get_localized_falls_back() {
+        let entry = DesktopEntry::parse("[Desktop Entry]\nName=Files\nName[de]=Dateien\n");
+        let cases = [
+            (strings(&["de_AT", "de"]), "Dateien"),
+            (strings(&["fr_FR", "fr"]), "Files"),
+            (vec![], "Files"),
+        ];
+
+        for (locales, expected) in cases {
+            assert_eq!(
+                entry.get_localized("Name", &locales).as_deref(),
+                Some(expected),
+                "{locales:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn get_list_splits_on_unescaped_semicolons() {
+        let cases = [
+            ("GNOME;KDE;", strings(&["GNOME", "KDE"])),
+            ("GNOME;KDE", strings(&["GNOME", "KDE"])),
+            (r"a\;b;c", strings(&["a;b", "c"])),
+            (r"a\sb;;c\\;", strings(&["a b", "c\\"])),
+            (";", vec![]),
+            ("", vec![]),
+        ];
+
+        for (value, expected) in cases {
+            let entry = DesktopEntry::parse(&format!("[Desktop Entry]\nKeywords={value}\n"));
+            assert_eq!(entry.get_list("Keywords"), expected, "{value:?}");
+        }
+        assert_eq!(
+            DesktopEntry::default().get_list("Keywords"),
+            Vec::<String>::new()
+        );
+    }
+
+    #[test]
+    fn should_show() {
+        let desktops = strings(&["ubuntu", "GNOME"]);
+        let cases = [
+            ("", true),
+            ("Hidden=true", false),
+            ("Hidden=false", true),
+            ("NoDisplay=true", false),
+            ("OnlyShowIn=GNOME;", true),
+            ("OnlyShowIn=KDE;XFCE;", false),
+            ("NotShowIn=GNOME;", false),
+            ("NotShowIn=KDE;", true),
+            ("OnlyShowIn=GNOME;\nNotShowIn=ubuntu;", false),
+        ];
+
+        for (extra, expected) in cases {
+            let entry = DesktopEntry::parse(&format!(
+                "[Desktop Entry]\nType=Application\nName=App\n{extra}\n"
+            ));
+            assert_eq!(entry.should_show(&desktops), expected, "{extra:?}");
+        }
+
+        let link = DesktopEntry::parse("[Desktop Entry]\nType=Link\nName=App\n");
+        assert!(!link.should_show(&desktops));
+    }
+
+    #[test]
+    fn exec_args_expand_field_codes() {
+        let path = "/usr/share/applications/files.desktop";
+        let cases = [
+            ("files %U", Some(strings(&["files"]))),
+            ("files %f --new", Some(strings(&["files", "--new"]))),
+            ("files %i", Some(strings(&["files", "--icon", "org.files"]))),
+            (
+                "files --name=%c",
+                Some(strings(&["files", "--name=Dateien"])),
+            ),
+            ("files %k", Some(strings(&["files", path]))),
+            ("files 100%%", Some(strings(&["files", "100%"]))),
+            ("files %d%D%n%N%v%m", Some(strings(&["files", ""]))),
+            (
+                r#"sh -c "echo \\"$HOME\\" a\\\\b""#,
+                Some(strings(&["sh", "-c", r#"echo "$HOME" a\b"#])),
+            ),
+            (
+                r#""/opt/My App/app" --x"#,
+                Some(strings(&["/opt/My App/app", "--x"])),
+            ),
+            (r#"app "unterminated"#, None),
+            ("%U", None),
+        ];
+
+        for (exec, expected) in cases {
+            let entry = DesktopEntry::parse(&format!(
+                "[Desktop Entry]\nName=Files\nName[de]=Dateien\nIcon=org.files\nExec={exec}\n"
+            ));
+            assert_eq!(
+                entry.exec_args(&strings(&["de"]), path),
+                expected,
+                "{exec:?}"
+            );
+        }
+
+        let no_icon = DesktopEntry::parse("[Desktop Entry]\nExec=files %i\n");
+        assert_eq!(no_icon.exec_args(&[], path), Some(strings(&["files"])));
+        assert_eq!(DesktopEntry::default().exec_args(&[], path), None);
+    }
+
+    #[test]
+    fn split_exec_words() {
+        let cases = [
+            ("a b\tc", Some(strings(&["a", "b", "c"]))),
+            ("  a   b  ", Some(strings(&["a", "b"]))),
+            (r#"a "b c" d"#, Some(strings(&["a", "b c", "d"]))),
+            (r#"a"b c"d"#, Some(strings(&["ab cd"]))),
+            (r#""""#, Some(strings(&[""]))),
+            (r#""\$x \` \\ \q""#, Some(strings(&[r"$x ` \ \q"]))),
+            (r#"a "b"#, None),
+            (r#"a "b\"#, None),
+            ("", Some(vec![])),
+        ];
+
+        for (exec, expected) in cases {
+            assert_eq!(split_exec(exec), expected, "{exec:?}");
+        }
+    }
+
+    #[test]
+    fn user_locales() {
+        let cases = [
+            (
+                vec![("LANG", "de_AT.UTF-8@euro")],
+                strings(&["de_AT@euro", "de_AT", "de@euro", "de"]),
+            ),
+            (vec![("LANG", "de_AT.UTF-8")], strings(&["de_AT", "de"])),
+            (vec![("LANG", "sr@latin")], strings(&["sr@latin", "sr"])),
+            (vec![("LANG", "fr")], strings(&["fr"])),
+            (
+                vec![
+                    ("LC_ALL", "pt_BR"),
+                    ("LC_MESSAGES", "es_ES"),
+                    ("LANG", "fr_FR"),
+                ],
+                strings(&["pt_BR", "pt"]),
+            ),
+            (
+                vec![("LC_ALL", ""), ("LC_MESSAGES", "es_ES"), ("LANG", "fr_FR")],
+                strings(&["es_ES", "es"]),
+            ),
+            (vec![("LC_ALL", "C.UTF-8"), ("LANG", "fr_FR")], vec![]),
+            (vec![("LANG", "POSIX")], vec![]),
+            (vec![], vec![]),
+        ];
+
+        for (vars, expected) in cases {
+            let vars: HashMap<&str, &str> = vars.into_iter().collect();
+            let locales = get_locales_from(|var| vars.get(var).map(|v| v.to_string()));
+            assert_eq!(locales, expected, "{vars:?}");
+        }
+    }
+}
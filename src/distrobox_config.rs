@@ -0,0 +1,484 @@
+use std::collections::HashMap;
+use std::env;
+use std::fmt;
+use std::sync::{Mutex, PoisonError};
+
+use crate::utils::{get_command_output, is_flatpak, run_command, run_command_with_input};
+
+/// System-wide config files, in the order distrobox reads them. Values in
+/// later files replace those in earlier ones, the user's file replaces them all,
+/// and `~/.distroboxrc` replaces even that.
+pub const SYSTEM_CONFIG_PATHS: [&str; 3] = [
+    "/usr/share/distrobox/distrobox.conf",
+    "/usr/etc/distrobox/distrobox.conf",
+    "/etc/distrobox/distrobox.conf",
+];
+
+/// Cache of the combined config, since it is checked on every `distrobox`
+/// call. Cleared whenever the user's config file is written.
+static EFFECTIVE_CONFIG: Mutex<Option<HashMap<String, String>>> = Mutex::new(None);
+
+/// A `distrobox.conf` file. Every line is kept, including comments, so that
+/// writing it back only touches the values which were changed.
+#[derive(Debug, Clone, Default)]
+pub struct DistroboxConfig {
+    lines: Vec<String>,
+}
+
+/// A value set in one of the system config files
+#[derive(Debug, Clone)]
+pub struct InheritedValue {
+    /// The value itself
+    pub value: String,
+    /// Path of the config file it was set in
+    pub path: String,
+}
+
+impl DistroboxConfig {
+    /// Reads a config file's contents. Lines which aren't assignments are kept as-is.
+    pub fn parse(contents: &str) -> Self {
+        DistroboxConfig {
+            lines: contents.lines().map(String::from).collect(),
+        }
+    }
+
+    /// Gets the value of a key. If a key is set more than once the last one
+    /// wins, as the file is sourced by a shell.
+    pub fn get(&self, key: &str) -> Option<String> {
+        self.lines
+            .iter()
+            .filter_map(|l| parse_config_line(l))
+            .rfind(|(k, _)| k == key)
+            .map(|(_, v)| v)
+    }
+
+    /// Gets the value of a key which distrobox treats as a boolean
+    pub fn get_bool(&self, key: &str) -> Option<bool> {
+        self.get(key).and_then(|v| parse_config_bool(&v))
+    }
+
+    /// Gets all keys and their values
+    pub fn values(&self) -> HashMap<String, String> {
+        self.lines
+            .iter()
+            .filter_map(|l| parse_config_line(l))
+            .collect()
+    }
+
+    /// Sets a key, replacing its existing assignment if it has one
+    pub fn set(&mut self, key: &str, value: &str) {
+        let new_line = format!("{key}={}", quote_config_value(value));
+
+        let existing: Vec<usize> = self.assignment_indexes(key);
+        match existing.split_last() {
+            Some((last, earlier)) => {
+                self.lines[*last] = new_line;
+                for idx in earlier.iter().rev() {
+                    self.lines.remove(*idx);
+                }
+            }
+            None => self.lines.push(new_line),
+        }
+    }
+
+    /// Removes every assignment of a key, so the system default applies again
+    pub fn remove(&mut self, key: &str) {
+        for idx in self.assignment_indexes(key).iter().rev() {
+            self.lines.remove(*idx);
+        }
+    }
+
+    fn assignment_indexes(&self, key: &str) -> Vec<usize> {
+        self.lines
+            .iter()
+            .enumerate()
+            .filter(|(_, l)| parse_config_line(l).is_some_and(|(k, _)| k == key))
+            .map(|(idx, _)| idx)
+            .collect()
+    }
+}
+
+impl fmt::Display for DistroboxConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for line in &self.lines {
+            writeln!(f, "{line}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Parses a `key=value` line, as found in a shell-sourced config file.
+/// Returns None for comments, blank lines and anything else.
+fn parse_config_line(line: &str) -> Option<(String, String)> {
+    let line = line.trim();
+    let line = line.strip_prefix("export ").unwrap_or(line);
+
+    let (key, value) = line.split_once('=')?;
+
+    let mut key_chars = key.chars();
+    let is_valid_key = key_chars
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_')
+        && key_chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+
+    if !is_valid_key {
+        return None;
+    }
+
+    Some((key.to_string(), unquote_config_value(value)))
+}
+
+/// Removes shell quoting from a value. Double-quoted values have their
+/// escapes removed, single-quoted ones are taken literally, and unquoted
+/// ones end at the first space (anything after is a comment or a mistake).
+fn unquote_config_value(value: &str) -> String {
+    let value = value.trim();
+
+    if let Some(rest) = value.strip_prefix('"') {
+        let mut unquoted = String::new();
+        let mut chars = rest.chars();
+        while let Some(c) = chars.next() {
+            match c {
+                '"' => break,
+                '\\' => match chars.next() {
+                    Some(escaped @ ('"' | '\\' | '$' | '`')) => unquoted.push(escaped),
+                    Some(other) => {
+                        unquoted.push('\\');
+                        unquoted.push(other);
+                    }
+                    None => unquoted.push('\\'),
+                },
+                _ => unquoted.push(c),
+            }
+        }
+        return unquoted;
+    }
+
+    if let Some(rest) = value.strip_prefix('\'') {
+        return rest.split('\'').next().unwrap_or_default().to_string();
+    }
+
+    value
+        .split_whitespace()
+        .next()
+        .unwrap_or_default()
+        .to_string()
+}
+
+/// Double-quotes a value for writing. `$` is left alone so that values like
+/// `$HOME/boxes` are expanded by distrobox, as they would be if typed into the file.
+fn quote_config_value(value: &str) -> String {
+    let escaped = value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('`', "\\`");
+
+    format!("\"{escaped}\"")
+}
+
+/// Reads a boolean the way distrobox's scripts do - "1" or "true" is on,
+/// "0" or "false" is off.
+pub fn parse_config_bool(value: &str) -> Option<bool> {
+    match value.trim().to_lowercase().as_str() {
+        "1" | "true" | "yes" => Some(true),
+        "0" | "false" | "no" => Some(false),
+        _ => None,
+    }
+}
+
+/// Returns the user's home directory on the host
+fn get_host_home_dir() -> String {
+    if is_flatpak() {
+        let home_dir = get_command_output("bash", Some(&["-c", "echo $HOME"]));
+        return home_dir.trim().to_string();
+    }
+
+    env::var("HOME").unwrap_or_else(|_| ".".to_string())
+}
+
+/// Returns the path of the user's own `distrobox.conf`. It may not exist yet.
+pub fn get_user_config_path() -> String {
+    let config_home = if is_flatpak() {
+        // the sandbox has its own XDG_CONFIG_HOME, so ask the host
+        let config_home = get_command_output("bash", Some(&["-c", "echo $XDG_CONFIG_HOME"]));
+        if config_home.trim().is_empty() {
+            format!("{}/.config", get_host_home_dir())
+        } else {
+            config_home.trim().to_string()
+        }
+    } else {
+        env::var("XDG_CONFIG_HOME").unwrap_or_else(|_| format!("{}/.config", get_host_home_dir()))
+    };
+
+    format!("{config_home}/distrobox/distrobox.conf")
+}
+
+/// Returns the path of `~/.distroboxrc`, which distrobox reads after every other
+/// config file. It may not exist.
+pub fn get_distroboxrc_path() -> String {
+    format!("{}/.distroboxrc", get_host_home_dir())
+}
+
+/// Reads a file from the host. Returns None if it doesn't exist.
+/// Goes through `cat` since a Flatpak can't see the host's /etc.
+fn read_host_file(path: &str) -> Option<String> {
+    let output = run_command("cat", Some(&[path])).ok()?;
+    if !output.status.success() {
+        return None;
+    }
+
+    Some(String::from_utf8_lossy(&output.stdout).into_owned())
+}
+
+/// Writes a file on the host, creating its directory if needed. Goes through
+/// the host's shell, the same way `read_host_file` reads it.
+fn write_host_file(path: &str, contents: &str) -> Result<(), std::io::Error> {
+    let output = run_command_with_input(
+        "sh",
+        Some(&[
+            "-c",
+            r#"mkdir -p "$(dirname "$1")" && cat > "$1""#,
+            "sh",
+            path,
+        ]),
+        contents.as_bytes(),
+    )?;
+
+    if !output.status.success() {
+        return Err(std::io::Error::other(
+            String::from_utf8_lossy(&output.stderr).trim().to_string(),
+        ));
+    }
+
+    Ok(())
+}
+
+/// Reads the user's `distrobox.conf`, or an empty config if there isn't one
+pub fn read_user_config() -> DistroboxConfig {
+    read_host_file(&get_user_config_path())
+        .map(|c| DistroboxConfig::parse(&c))
+        .unwrap_or_default()
+}
+
+/// Writes the user's `distrobox.conf`, creating its directory if needed
+pub fn write_user_config(config: &DistroboxConfig) -> Result<(), std::io::Error> {
+    write_host_file(&get_user_config_path(), &config.to_string())?;
+
+    *EFFECTIVE_CONFIG
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner) = None;
+
+    Ok(())
+}
+
+/// Gets every value set in `~/.distroboxrc`. These win over the user's
+/// `distrobox.conf`, so they can't be changed from BoxBuddy.
+pub fn get_distroboxrc_values() -> HashMap<String, String> {
+    read_host_file(&get_distroboxrc_path())
+        .map(|c| DistroboxConfig::parse(&c).values())
+        .unwrap_or_default()
+}
+
+/// Gets every value set in the system config files, along with which file
+/// set it. Later files replace earlier ones, as in distrobox.
+pub fn get_system_config_values() -> HashMap<String, InheritedValue> {
+    let mut values = HashMap::new();
+
+    for path in SYSTEM_CONFIG_PATHS {
+        let Some(contents) = read_host_file(path) else {
+            continue;
+        };
+
+        for (key, value) in DistroboxConfig::parse(&contents).values() {
+            values.insert(
+                key,
+                InheritedValue {
+                    value,
+                    path: path.to_string(),
+                },
+            );
+        }
+    }
+
+    values
+}
+
+/// Gets the value distrobox will use for a key - from `~/.distroboxrc` or the
+/// user's config if it is set there, otherwise from the system config files.
+pub fn get_distrobox_config_value(key: &str) -> Option<String> {
+    let mut effective_config = EFFECTIVE_CONFIG
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+
+    effective_config
+        .get_or_insert_with(|| {
+            let mut values: HashMap<String, String> = get_system_config_values()
+                .into_iter()
+                .map(|(k, v)| (k, v.value))
+                .collect();
+            values.extend(read_user_config().values());
+            values.extend(get_distroboxrc_values());
+            values
+        })
+        .get(key)
+        .filter(|v| !v.is_empty())
+        .cloned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::process::Command;
+
+    /// Has `sh` source a config file and print back the value it gives a key
+    fn value_seen_by_shell(contents: &str, key: &str) -> String {
+        let script = format!("{contents}\nprintf '%s' \"${key}\"");
+        let output = Command::new("sh").arg("-c").arg(script).output().unwrap();
+        assert!(output.status.success());
+
+        String::from_utf8(output.stdout).unwrap()
+    }
+
+    #[test]
+    fn config_lines() {
+        let cases = [
+            (
+                "container_manager=podman",
+                Some(("container_manager", "podman")),
+            ),
+            ("  container_manager = podman", None),
+            (
+                "export container_manager=docker",
+                Some(("container_manager", "docker")),
+            ),
+            ("non_interactive=\"1\"", Some(("non_interactive", "1"))),
+            (
+                "image='registry:5000/img $x'",
+                Some(("image", "registry:5000/img $x")),
+            ),
+            (
+                "image=\"say \\\"hi\\\" \\$HOME \\n\"",
+                Some(("image", "say \"hi\" $HOME \\n")),
+            ),
+            ("image=fedora # the default", Some(("image", "fedora"))),
+            ("home=", Some(("home", ""))),
+            ("# container_manager=podman", None),
+            ("", None),
+            ("2fast=1", None),
+            ("my-key=1", None),
+            ("if [ -n \"$x\" ]; then", None),
+        ];
+
+        for (line, expected) in cases {
+            assert_eq!(
+                parse_config_line(line),
+                expected.map(|(k, v)| (k.to_string(), v.to_string())),
+                "{line:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn last_assignment_wins() {
+        let config = DistroboxConfig::parse(
+            "# my config\ncontainer_manager=podman\ncontainer_manager=\"docker\"\nnon_interactive=true\n",
+        );
+
+        assert_eq!(
+            config.get("container_manager"),
+            Some(String::from("docker"))
+        );
+        assert_eq!(config.get_bool("non_interactive"), Some(true));
+        assert_eq!(config.get("container_image_default"), None);
+        assert_eq!(config.values().len(), 2);
+    }
+
+    #[test]
+    fn bools() {
+        for (value, expected) in [
+            ("1", Some(true)),
+            ("true", Some(true)),
+            (" TRUE ", Some(true)),
+            ("yes", Some(true)),
+            ("0", Some(false)),
+            ("false", Some(false)),
+            ("no", Some(false)),
+            ("", None),
+            ("maybe", None),
+        ] {
+            assert_eq!(parse_config_bool(value), expected, "{value:?}");
+        }
+    }
+
+    #[test]
+    fn set_replaces_every_assignment() {
+        let mut config = DistroboxConfig::parse(
+            "# keep me\ncontainer_manager=podman\nother=1\nexport container_manager=docker\n",
+        );
+        config.set("container_manager", "lilipod");
+
+        assert_eq!(
+            config.to_string(),
+            "# keep me\nother=1\ncontainer_manager=\"lilipod\"\n"
+        );
+
+        config.set("container_image_default", "fedora");
+        assert_eq!(
+            config.to_string(),
+            "# keep me\nother=1\ncontainer_manager=\"lilipod\"\ncontainer_image_default=\"fedora\"\n"
+        );
+    }
+
+    #[test]
+    fn remove_keeps_other_lines() {
+        let mut config =
+            DistroboxConfig::parse("# container_manager=podman\ncontainer_manager=podman\nother=1\ncontainer_manager=docker");
+        config.remove("container_manager");
+
+        assert_eq!(config.to_string(), "# container_manager=podman\nother=1\n");
+        assert_eq!(config.get("container_manager"), None);
+    }
+
+    #[test]
+    fn set_values_survive_the_shell() {
+        for value in [
+            "fedora",
+            "my image",
+            "it's",
+            "say \"hi\"",
+            "back\\slash",
+            "`id`",
+            "ünïcödé",
+            "",
+        ] {
+            let mut config = DistroboxConfig::default();
+            config.set("key", value);
+
+            assert_eq!(config.get("key").as_deref(), Some(value), "{value:?}");
+            assert_eq!(
+                value_seen_by_shell(&config.to_string(), "key"),
+                value,
+                "{value:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn dollar_is_left_for_distrobox_to_expand() {
+        let mut config = DistroboxConfig::default();
+        config.set("container_user_custom_home", "$HOME/boxes");
+
+        assert_eq!(
+            config.to_string(),
+            "container_user_custom_home=\"$HOME/boxes\"\n"
+        );
+        assert_eq!(
+            value_seen_by_shell(
+                &format!("HOME=/home/me\n{config}"),
+                "container_user_custom_home"
+            ),
+            "/home/me/boxes"
+        );
+    }
+}
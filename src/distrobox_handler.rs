@@ -1,8 +1,96 @@
+use crate::app_icons::{forget_box_icons, get_app_icons_in_box};
+use crate::box_options::{forget_box_options, get_saved_box_options, save_box_options};
+use crate::command_args::join_shell_args;
+use crate::desktop_entry::{get_current_desktops, get_user_locales, DesktopEntry};
+use crate::distrobox_config::get_distrobox_config_value;
 use crate::utils::{
-    get_command_output, get_host_desktop_files, get_repository_list,
-    get_terminal_and_separator_arg, is_flatpak, is_nvidia, run_command,
+    detect_nvidia, get_command_output, get_command_output_no_err, get_container_runtime,
+    get_custom_home_path, get_distrobox_command, get_host_desktop_files, get_repository_list,
+    get_terminal_and_separator_arg, host_path_exists, is_flatpak, is_image_downloaded,
+    parse_json_string_array, run_command,
 };
+use gtk::glib;
+use std::collections::{HashMap, HashSet};
+use std::path::PathBuf;
 use std::process::Command;
+use std::sync::{Mutex, PoisonError};
+
+/// Finds the applications directories in a box - the user's data dir, then each
+/// of `XDG_DATA_DIRS` - and prints the newest modification time of anything
+/// under each of them. Unless these match the times passed as `$1`, it then
+/// prints every desktop file's directory, path within that directory, whether
+/// its `TryExec` program is missing ("1" if so) and contents. Each piece is
+/// followed by a NUL byte.
+///
+/// The user's data dir is skipped when the box shares the host's home, as the
+/// apps there are the host's own and those already exported from boxes.
+const DESKTOP_FILES_SCRIPT: &str = r#"if [ -n "$DISTROBOX_HOST_HOME" ] && [ "$HOME" != "$DISTROBOX_HOST_HOME" ]; then
+    shared_home=
+    data_dirs="${XDG_DATA_HOME:-$HOME/.local/share}:${XDG_DATA_DIRS:-/usr/local/share:/usr/share}"
+else
+    shared_home=1
+    data_dirs="${XDG_DATA_DIRS:-/usr/local/share:/usr/share}"
+fi
+app_dirs=
+mtimes=
+IFS=:
+for d in $data_dirs; do
+    [ -n "$d" ] || continue
+    d="${d%/}/applications"
+    if [ -n "$shared_home" ]; then
+        case "$d" in "$HOME"/*) continue ;; esac
+    fi
+    [ -d "$d" ] || continue
+    app_dirs="$app_dirs:$d"
+    newest=$(find -L "$d" \( -type d -o -name '*.desktop' \) -exec stat -L -c %Y {} + 2>/dev/null | sort -n | tail -n 1)
+    mtimes="$mtimes$d=$newest;"
+done
+printf '%s\0' "$mtimes"
+[ -n "$mtimes" ] && [ "$mtimes" = "$1" ] && exit 0
+for d in $app_dirs; do
+    [ -n "$d" ] || continue
+    find -L "$d" -type f -name '*.desktop' 2>/dev/null | while IFS= read -r f; do
+        try_exec=$(sed -n '/^\[Desktop Entry\]/,/^\[/s/^[[:space:]]*TryExec[[:space:]]*=[[:space:]]*//p' "$f" | head -n 1)
+        missing=
+        if [ -n "$try_exec" ] && ! command -v "$try_exec" > /dev/null 2>&1; then
+            missing=1
+        fi
+        printf '%s\0%s\0%s\0' "$d" "${f#"$d"/}" "$missing"
+        cat "$f"
+        printf '\0'
+    done
+done"#;
+
+/// Prints the path and contents of every desktop file in the host's
+/// applications directory, each followed by a NUL byte
+const HOST_DESKTOP_FILES_SCRIPT: &str = r#"for f in "${XDG_DATA_HOME:-$HOME/.local/share}"/applications/*.desktop; do
+    [ -f "$f" ] || continue
+    printf '%s\0' "$f"
+    cat "$f"
+    printf '\0'
+done"#;
+
+/// A desktop file found in a box
+#[derive(Debug, Clone)]
+struct BoxDesktopFile {
+    /// Desktop file ID, e.g. `org.gnome.Nautilus.desktop`
+    id: String,
+    /// Applications directory it was found in
+    source_dir: String,
+    /// Path within `source_dir`
+    relative_path: String,
+    entry: DesktopEntry,
+}
+
+/// Desktop files which should be listed for each box, by box name. Reused
+/// until one of the box's applications directories is modified.
+static BOX_DESKTOP_ENTRIES: Mutex<Option<HashMap<String, CachedDesktopEntries>>> = Mutex::new(None);
+
+/// The desktop files read from a box, and when its applications directories were last modified
+struct CachedDesktopEntries {
+    dir_mtimes: String,
+    entries: Vec<BoxDesktopFile>,
+}
 
 /// Struct representing a distrobox installed on the user's machine
 pub struct DBox {
@@ -23,18 +111,113 @@ pub struct DBox {
 /// Struct representing an application installed in a box
 #[derive(Debug, Clone)]
 pub struct DBoxApp {
-    /// User-facing name of the application
+    /// User-facing name of the application, in the user's language if it has one
     pub name: String,
-    /// Executable command to run the application
-    pub exec_name: String,
+    /// Command to run the application, split into arguments with field codes removed
+    pub exec_args: Vec<String>,
+    /// Whether the application needs to be run in a terminal
+    pub terminal: bool,
+    /// The desktop file's `Categories`, e.g. `Graphics` and `Viewer`
+    pub categories: Vec<String>,
     /// Icon name of the application
     pub icon: String,
-    /// Path to the desktop file
+    /// Copy of the icon taken from inside the box, if it could be found there
+    pub icon_file: Option<PathBuf>,
+    /// Desktop file ID, without the `.desktop` extension
     pub desktop_file: String,
+    /// Applications directory in the box which the desktop file was found in
+    pub source_dir: String,
+    /// Full path of the desktop file inside the box, which it is exported by
+    pub desktop_file_path: String,
     /// Whether or not this app has been exported to the host
     pub is_on_host: bool,
 }
 
+/// How an exported app is labelled in the host's menu
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum ExportLabel {
+    /// distrobox's own label, "(on <box name>)"
+    #[default]
+    Default,
+    /// Just the app's name
+    None,
+    /// A label of the user's choosing
+    Custom(String),
+}
+
+impl DBoxApp {
+    /// Name of the desktop file `distrobox-export` writes to the host's applications
+    /// directory for this app - the box's name, then the file's own name
+    pub fn host_desktop_file_name(&self, box_name: &str) -> String {
+        let file_name = self
+            .desktop_file_path
+            .rsplit('/')
+            .next()
+            .unwrap_or_default();
+
+        format!("{box_name}-{file_name}")
+    }
+}
+
+/// Options for exporting an app with `distrobox-export --app`
+#[derive(Debug, Clone, Default)]
+pub struct AppExportOptions {
+    /// Added to the end of the exported app's command, e.g. `--ozone-platform=wayland`
+    pub extra_flags: Option<String>,
+    /// Label added after the app's name in the host's menu
+    pub label: ExportLabel,
+}
+
+/// Options for creating a new box with `distrobox create`
+#[derive(Debug, Clone, Default)]
+pub struct BoxCreateOptions {
+    /// Name of the new box
+    pub name: String,
+    /// The container image to create it from
+    pub image: String,
+    /// Separate home directory, or None to share the user's
+    pub home_path: Option<String>,
+    /// Whether to run systemd inside the box
+    pub use_init: bool,
+    /// Extra volumes to mount, as `host-path:box-path`
+    pub volumes: Vec<String>,
+    /// Hostname of the box, if not the default
+    pub hostname: Option<String>,
+    /// Flags passed straight to the container manager
+    pub additional_flags: Option<String>,
+    /// Packages to install while the box is first set up
+    pub additional_packages: Vec<String>,
+    /// Commands run at the end of the box's set up
+    pub init_hooks: Option<String>,
+    /// Commands run at the start of the box's set up
+    pub pre_init_hooks: Option<String>,
+    /// Give the box its own network namespace
+    pub unshare_netns: bool,
+    /// Give the box its own IPC namespace
+    pub unshare_ipc: bool,
+    /// Give the box its own namespaces for everything distrobox can separate
+    pub unshare_all: bool,
+    /// Pull the image even if it has already been downloaded
+    pub pull: bool,
+    /// Don't add an entry for the box to the applications menu
+    pub no_entry: bool,
+    /// Whether to share the host's NVIDIA driver with the box
+    pub nvidia: NvidiaMode,
+}
+
+/// Whether a new box gets the host's NVIDIA driver
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum NvidiaMode {
+    /// Follow distrobox.conf, otherwise share it if the driver is loaded
+    #[default]
+    Auto,
+    /// Always pass `--nvidia`
+    On,
+    /// Never pass `--nvidia`. distrobox.conf can still turn it on, as
+    /// distrobox has no flag to turn it off.
+    Off,
+}
+
 /// Used to determine which column contains which data when splitting
 /// the output of `distrobox list`, since it changes between versions.
 pub struct ColsIndexes {
@@ -175,8 +358,7 @@ pub fn open_terminal_in_box(box_name: String) {
                 .arg("run")
                 .arg(term)
                 .arg(sep)
-                .arg("distrobox")
-                .arg("enter")
+                .args(get_distrobox_command("enter"))
                 .arg(box_name)
                 .spawn()
                 .unwrap();
@@ -185,8 +367,7 @@ pub fn open_terminal_in_box(box_name: String) {
                 .arg("--host")
                 .arg(term)
                 .arg(sep)
-                .arg("distrobox")
-                .arg("enter")
+                .args(get_distrobox_command("enter"))
                 .arg(box_name)
                 .spawn()
                 .unwrap();
@@ -197,16 +378,14 @@ pub fn open_terminal_in_box(box_name: String) {
                 .arg("run")
                 .arg(term)
                 .arg(sep)
-                .arg("distrobox")
-                .arg("enter")
+                .args(get_distrobox_command("enter"))
                 .arg(box_name)
                 .spawn()
                 .unwrap();
         } else {
             Command::new(term)
                 .arg(sep)
-                .arg("distrobox")
-                .arg("enter")
+                .args(get_distrobox_command("enter"))
                 .arg(box_name)
                 .spawn()
                 .unwrap();
@@ -214,23 +393,37 @@ pub fn open_terminal_in_box(box_name: String) {
     }
 }
 
-/// Exports the desktop file from a box.
-pub fn export_app_from_box(app_name: &str, box_name: &str) -> String {
-    get_command_output(
-        "distrobox",
-        Some(&[
-            "enter",
-            box_name,
-            "--",
-            "distrobox-export",
-            "--app",
-            app_name,
-        ]),
-    )
+/// Exports the desktop file from a box. `desktop_file_path` is the file's full
+/// path in the box, so that exactly that app is exported.
+pub fn export_app_from_box(
+    desktop_file_path: &str,
+    box_name: &str,
+    options: &AppExportOptions,
+) -> String {
+    let mut args = vec![
+        "enter",
+        box_name,
+        "--",
+        "distrobox-export",
+        "--app",
+        desktop_file_path,
+    ];
+
+    if let Some(extra_flags) = &options.extra_flags {
+        args.extend(["--extra-flags", extra_flags]);
+    }
+
+    match &options.label {
+        ExportLabel::Default => {}
+        ExportLabel::None => args.extend(["--export-label", "none"]),
+        ExportLabel::Custom(label) => args.extend(["--export-label", label]),
+    }
+
+    get_command_output("distrobox", Some(&args))
 }
 
-/// Unexports a desktop file from the host.
-pub fn remove_app_from_host(app_name: &str, box_name: &str) -> String {
+/// Unexports a desktop file from the host. `desktop_file_path` is the file's full path in the box.
+pub fn remove_app_from_host(desktop_file_path: &str, box_name: &str) -> String {
     get_command_output(
         "distrobox",
         Some(&[
@@ -239,33 +432,172 @@ pub fn remove_app_from_host(app_name: &str, box_name: &str) -> String {
             "--",
             "distrobox-export",
             "--app",
-            app_name,
+            desktop_file_path,
             "--delete",
         ]),
     )
 }
 
+/// Removes every app exported from a box from the host's menu, e.g. before
+/// deleting the box, so no menu entries are left pointing at it. The host's
+/// desktop files are checked rather than the box's apps, so entries for apps
+/// which have since been uninstalled from the box are removed too.
+pub fn unexport_all_apps_from_box(box_name: &str) -> Result<(), String> {
+    let output =
+        run_command("sh", Some(&["-c", HOST_DESKTOP_FILES_SCRIPT])).map_err(|e| e.to_string())?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let mut pieces = stdout.split('\0');
+
+    let mut exported_files = vec![];
+    while let (Some(path), Some(contents)) = (pieces.next(), pieces.next()) {
+        let entry = DesktopEntry::parse(contents);
+        if entry
+            .exec_args(&[], path)
+            .is_some_and(|args| runs_distrobox_enter_for(&args, box_name))
+        {
+            exported_files.push(path);
+        }
+    }
+
+    if exported_files.is_empty() {
+        return Ok(());
+    }
+
+    let mut args = vec!["-f", "--"];
+    args.extend(exported_files);
+    let output = run_command("rm", Some(&args)).map_err(|e| e.to_string())?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    }
+
+    Ok(())
+}
+
+/// Whether a command enters a box, as the desktop files written by
+/// `distrobox-export` do, e.g. `/usr/bin/distrobox-enter -n my-box -- code`
+fn runs_distrobox_enter_for(args: &[String], box_name: &str) -> bool {
+    let program = |arg: &String| arg.rsplit('/').next().unwrap_or_default().to_string();
+    let Some(enter_args_start) = (0..args.len()).find_map(|i| {
+        if program(&args[i]) == "distrobox-enter" {
+            Some(i + 1)
+        } else if program(&args[i]) == "distrobox" && args.get(i + 1).is_some_and(|a| a == "enter")
+        {
+            Some(i + 2)
+        } else {
+            None
+        }
+    }) else {
+        return false;
+    };
+
+    let mut enter_args = args[enter_args_start..].iter().take_while(|a| *a != "--");
+    while let Some(arg) = enter_args.next() {
+        let name = match arg.as_str() {
+            "-n" | "--name" => enter_args.next().map(String::as_str),
+            "-a" | "--additional-flags" => {
+                enter_args.next();
+                None
+            }
+            // e.g. `distrobox enter my-box`
+            _ if !arg.starts_with('-') => Some(arg.as_str()),
+            _ => arg.strip_prefix("--name="),
+        };
+        if name == Some(box_name) {
+            return true;
+        }
+    }
+
+    false
+}
+
+/// Checks the host's applications directory for an app's exported desktop file
+pub fn is_app_exported(app: &DBoxApp, box_name: &str) -> bool {
+    get_host_desktop_files().contains(&app.host_desktop_file_name(box_name))
+}
+
 /// Runs a command inside a box using `distrobox enter --`. Does NOT spawn terminal.
-pub fn run_command_in_box(command: &str, box_name: &str) {
+pub fn run_command_in_box(command: &[String], box_name: &str) {
     if is_flatpak() {
         Command::new(String::from("flatpak-spawn"))
-            .args(["--host", "distrobox", "enter", box_name, "--", command])
+            .arg("--host")
+            .args(get_distrobox_command("enter"))
+            .args([box_name, "--"])
+            .args(command)
             .spawn()
             .unwrap();
     } else {
-        Command::new(String::from("distrobox"))
-            .args(["enter", box_name, "--", command])
+        let distrobox_command = get_distrobox_command("enter");
+        Command::new(&distrobox_command[0])
+            .args(&distrobox_command[1..])
+            .args([box_name, "--"])
+            .args(command)
             .spawn()
             .unwrap();
     }
 }
 
+/// Spawns a terminal running a command inside the provided box, for
+/// applications which set `Terminal=true`.
+pub fn run_command_in_box_in_terminal(command: &[String], box_name: &str) {
+    let (term, sep, term_is_flatpak) = get_terminal_and_separator_arg();
+
+    if is_flatpak() {
+        if term_is_flatpak {
+            Command::new("flatpak-spawn")
+                .arg("--host")
+                .arg("flatpak")
+                .arg("run")
+                .arg(term)
+                .arg(sep)
+                .args(get_distrobox_command("enter"))
+                .args([box_name, "--"])
+                .args(command)
+                .spawn()
+                .unwrap();
+        } else {
+            Command::new("flatpak-spawn")
+                .arg("--host")
+                .arg(term)
+                .arg(sep)
+                .args(get_distrobox_command("enter"))
+                .args([box_name, "--"])
+                .args(command)
+                .spawn()
+                .unwrap();
+        }
+    } else {
+        if term_is_flatpak {
+            Command::new("flatpak")
+                .arg("run")
+                .arg(term)
+                .arg(sep)
+                .args(get_distrobox_command("enter"))
+                .args([box_name, "--"])
+                .args(command)
+                .spawn()
+                .unwrap();
+        } else {
+            Command::new(term)
+                .arg(sep)
+                .args(get_distrobox_command("enter"))
+                .args([box_name, "--"])
+                .args(command)
+                .spawn()
+                .unwrap();
+        }
+    }
+}
+
 /// Performs `distrobox upgrade` inside a box.
 /// Spawns a terminal, and runs `distrobox enter` afterwards just so the terminal
 /// stays open.
 pub fn upgrade_box(box_name: &str) {
     let (term, sep, term_is_flatpak) = get_terminal_and_separator_arg();
-    let command = format!("distrobox upgrade {box_name}; distrobox enter {box_name}");
+    let command = [
+        join_shell_args(&[get_distrobox_command("upgrade"), vec![box_name.to_string()]].concat()),
+        join_shell_args(&[get_distrobox_command("enter"), vec![box_name.to_string()]].concat()),
+    ]
+    .join("; ");
 
     if is_flatpak() {
         if term_is_flatpak {
@@ -315,50 +647,322 @@ pub fn upgrade_box(box_name: &str) {
 }
 
 pub fn delete_box(box_name: &str) -> String {
-    get_command_output("distrobox", Some(&["rm", box_name, "--force"]))
+    let output = get_command_output("distrobox", Some(&["rm", box_name, "--force"]));
+    let _ = forget_box_options(box_name);
+    forget_box_icons(box_name);
+
+    output
 }
 
-/// Creates a new distrobox, spawns a terminal with `distrobox enter` afterwards
-/// to initialise it.
-pub fn create_box(
-    box_name: &str,
-    image: &str,
-    home_path: &str,
-    use_init: bool,
-    volumes: &[String],
-) -> String {
-    let mut args = vec!["create", "-n", box_name, "-i", image, "-Y"];
-    if is_nvidia() {
+/// Gets the options to recreate a box with, from `image`, and whether all of
+/// them are known. Boxes created by `BoxBuddy` had their options saved. For
+/// others, as many as possible are read back from the container, but not the
+/// volumes or container manager flags they were given.
+pub fn get_box_create_options(box_name: &str, image: &str) -> (BoxCreateOptions, bool) {
+    let (mut options, is_complete) = match get_saved_box_options(box_name) {
+        Some(options) => (options, true),
+        None => (inspect_box_create_options(box_name), false),
+    };
+
+    options.name = box_name.to_string();
+    options.image = image.to_string();
+    // the image is always pulled first, with a progress bar
+    options.pull = false;
+
+    (options, is_complete)
+}
+
+/// Reads the options a box was created with from its container. Distrobox
+/// passes most of them on to the box's entrypoint.
+fn inspect_box_create_options(box_name: &str) -> BoxCreateOptions {
+    let runtime = get_container_runtime();
+    let output = get_command_output_no_err(
+        &runtime,
+        Some(&[
+            "inspect",
+            "--type",
+            "container",
+            "--format",
+            "{{.Config.Hostname}}|{{.HostConfig.NetworkMode}}|{{.HostConfig.IpcMode}}|{{.HostConfig.PidMode}}|{{json .Args}}",
+            box_name,
+        ]),
+    );
+
+    let mut options = BoxCreateOptions {
+        home_path: get_custom_home_path(box_name),
+        ..Default::default()
+    };
+
+    let mut fields = output.trim().splitn(5, '|');
+    let (Some(hostname), Some(network_mode), Some(ipc_mode), Some(pid_mode), Some(args)) = (
+        fields.next(),
+        fields.next(),
+        fields.next(),
+        fields.next(),
+        fields.next(),
+    ) else {
+        return options;
+    };
+
+    options.hostname = Some(hostname.to_string()).filter(|h| !h.is_empty());
+
+    // only --unshare-all gives the box its own process namespace
+    options.unshare_all = pid_mode != "host";
+    if !options.unshare_all {
+        options.unshare_netns = network_mode != "host";
+        options.unshare_ipc = ipc_mode != "host";
+    }
+
+    let args = parse_json_string_array(args).unwrap_or_default();
+    apply_entrypoint_args(&mut options, &args);
+
+    options
+}
+
+/// Fills in the options distrobox passes to a box's entrypoint - such as
+/// `--init 1 --nvidia 0 --pre-init-hooks ... --additional-packages ... -- <init hooks>`
+fn apply_entrypoint_args(options: &mut BoxCreateOptions, args: &[String]) {
+    let value_after = |flag: &str| {
+        args.iter()
+            .position(|a| a == flag)
+            .and_then(|idx| args.get(idx + 1))
+            .map(|v| v.trim().to_string())
+    };
+
+    options.use_init = value_after("--init").is_some_and(|v| v == "1");
+    options.nvidia = match value_after("--nvidia").as_deref() {
+        Some("1") => NvidiaMode::On,
+        Some("0") => NvidiaMode::Off,
+        _ => NvidiaMode::Auto,
+    };
+    options.pre_init_hooks = value_after("--pre-init-hooks").filter(|h| !h.is_empty());
+    options.init_hooks = value_after("--").filter(|h| !h.is_empty());
+
+    // systemd is added again by `--init`
+    options.additional_packages = value_after("--additional-packages")
+        .unwrap_or_default()
+        .split_whitespace()
+        .filter(|p| !(options.use_init && *p == "systemd"))
+        .map(String::from)
+        .collect();
+}
+
+/// Deletes a box and creates it again from `options`, which should come from
+/// `get_box_create_options`. The image has to have been pulled already, so that
+/// the box is never deleted when its new image can't be downloaded.
+pub fn recreate_box(options: &BoxCreateOptions) -> Result<String, String> {
+    if !is_image_downloaded(&options.image) {
+        return Err(format!("{} has not been downloaded", options.image));
+    }
+
+    // a previous attempt may have deleted it and then failed to create it
+    let runtime = get_container_runtime();
+    let box_exists = run_command(
+        &runtime,
+        Some(&[
+            "inspect",
+            "--type",
+            "container",
+            "--format",
+            "{{.Id}}",
+            &options.name,
+        ]),
+    )
+    .is_ok_and(|output| output.status.success());
+
+    if box_exists {
+        stop_box(&options.name);
+
+        let output = run_command("distrobox", Some(&["rm", &options.name, "--force"]))
+            .map_err(|e| e.to_string())?;
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+        }
+        forget_box_icons(&options.name);
+    }
+
+    create_box(options)
+}
+
+/// Creates a new distrobox and remembers the options it was created with.
+/// A terminal with `distrobox enter` should be opened afterwards to initialise it.
+/// Returns distrobox's error if it fails.
+pub fn create_box(options: &BoxCreateOptions) -> Result<String, String> {
+    let args = get_create_box_args(options, should_share_nvidia(options.nvidia));
+    let output = run_command("distrobox", Some(args.as_slice())).map_err(|e| e.to_string())?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+    }
+
+    // only needed for recreating the box, which can manage without
+    let _ = save_box_options(options);
+
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Whether to ask distrobox to share the NVIDIA driver with a new box
+fn should_share_nvidia(nvidia: NvidiaMode) -> bool {
+    match nvidia {
+        // distrobox.conf's own setting applies if it has one, so only add this when it doesn't
+        NvidiaMode::Auto => {
+            get_distrobox_config_value("nvidia").is_none() && detect_nvidia().has_driver()
+        }
+        NvidiaMode::On => true,
+        NvidiaMode::Off => false,
+    }
+}
+
+/// Builds the arguments for `distrobox create`. Every value is its own
+/// argument, exactly as entered - none of them go through a shell.
+fn get_create_box_args(options: &BoxCreateOptions, use_nvidia: bool) -> Vec<&str> {
+    let mut args = vec!["create", "-n", &options.name, "-i", &options.image, "-Y"];
+
+    if use_nvidia {
         args.push("--nvidia");
     }
 
-    if use_init {
+    if options.use_init {
         args.push("--init");
         args.push("--additional-packages");
         args.push("systemd");
     }
 
-    if !home_path.is_empty() {
+    for package in &options.additional_packages {
+        args.push("--additional-packages");
+        args.push(package);
+    }
+
+    if let Some(home_path) = &options.home_path {
         args.push("--home");
         args.push(home_path);
     }
 
-    if !volumes.is_empty() {
-        for vol in volumes {
-            args.push("--volume");
-            args.push(vol);
+    for vol in &options.volumes {
+        args.push("--volume");
+        args.push(vol);
+    }
+
+    if let Some(hostname) = &options.hostname {
+        args.push("--hostname");
+        args.push(hostname);
+    }
+
+    if let Some(additional_flags) = &options.additional_flags {
+        args.push("--additional-flags");
+        args.push(additional_flags);
+    }
+
+    if let Some(init_hooks) = &options.init_hooks {
+        args.push("--init-hooks");
+        args.push(init_hooks);
+    }
+
+    if let Some(pre_init_hooks) = &options.pre_init_hooks {
+        args.push("--pre-init-hooks");
+        args.push(pre_init_hooks);
+    }
+
+    // --unshare-all covers the other two
+    if options.unshare_all {
+        args.push("--unshare-all");
+    } else {
+        if options.unshare_netns {
+            args.push("--unshare-netns");
         }
+        if options.unshare_ipc {
+            args.push("--unshare-ipc");
+        }
+    }
+
+    if options.pull {
+        args.push("--pull");
+    }
+
+    if options.no_entry {
+        args.push("--no-entry");
     }
 
-    get_command_output("distrobox", Some(args.as_slice()))
+    args
 }
 
-/// Runs `distrobox-assemble` with the provided file.
-pub fn assemble_box(ini_file: &str) -> String {
-    let args = &["assemble", "create", "--file", ini_file];
+/// Runs `distrobox-assemble` with the provided file. On and Off set `nvidia=`
+/// for every box in the file, while Automatic only turns it on for boxes which
+/// don't say, the same way `create_box` does. The file itself is left alone -
+/// a copy with the change is assembled instead.
+pub fn assemble_box(ini_file: &str, nvidia: NvidiaMode) -> String {
+    let nvidia_change = match nvidia {
+        NvidiaMode::Auto => should_share_nvidia(nvidia).then_some((true, false)),
+        NvidiaMode::On => Some((true, true)),
+        NvidiaMode::Off => Some((false, true)),
+    };
+
+    let mut ini_file = ini_file.to_string();
+
+    if let Some((use_nvidia, replace_existing)) = nvidia_change {
+        let copy_path = glib::user_cache_dir().join("boxbuddy").join("assemble.ini");
+
+        let copied = std::fs::read_to_string(&ini_file).is_ok_and(|contents| {
+            let contents = set_assemble_nvidia(&contents, use_nvidia, replace_existing);
+            copy_path
+                .parent()
+                .is_some_and(|dir| std::fs::create_dir_all(dir).is_ok())
+                && std::fs::write(&copy_path, contents).is_ok()
+        });
+
+        if copied {
+            ini_file = copy_path.to_string_lossy().into_owned();
+        }
+    }
+
+    let args = &["assemble", "create", "--file", &ini_file];
     get_command_output("distrobox", Some(args))
 }
 
+/// Adds `nvidia=` to every box in a `distrobox assemble` file. Boxes which
+/// already set it keep theirs unless `replace_existing` is true. The file is
+/// edited line by line, as keys such as `volume=` may be given more than once.
+fn set_assemble_nvidia(contents: &str, use_nvidia: bool, replace_existing: bool) -> String {
+    let nvidia_line = format!("nvidia={use_nvidia}");
+    let is_nvidia_line = |line: &str| {
+        line.split_once('=')
+            .is_some_and(|(key, _)| key.trim() == "nvidia")
+    };
+
+    // anything before the first box's header isn't part of a box
+    let mut preamble: Vec<&str> = vec![];
+    let mut sections: Vec<Vec<&str>> = vec![];
+    for line in contents.lines() {
+        if line.trim_start().starts_with('[') {
+            sections.push(vec![line]);
+        } else if let Some(section) = sections.last_mut() {
+            section.push(line);
+        } else {
+            preamble.push(line);
+        }
+    }
+
+    let mut lines: Vec<&str> = preamble;
+    for section in &sections {
+        let (header, body) = section.split_at(1);
+
+        if !replace_existing && body.iter().any(|l| is_nvidia_line(l)) {
+            lines.extend(section);
+            continue;
+        }
+
+        lines.extend(header);
+        lines.push(&nvidia_line);
+        lines.extend(body.iter().filter(|l| !is_nvidia_line(l)));
+    }
+
+    let mut edited = lines.join("\n");
+    if contents.ends_with('\n') {
+        edited.push('\n');
+    }
+
+    edited
+}
+
 /// Grabs the list of available images via `distrobox create -C`.
 /// Prepends the parsed distro name for sortability and readability.
 /// Appends a little diamond if the image is already downloaded.
@@ -399,71 +1003,146 @@ pub fn get_apps_in_box(box_name: &str) -> Vec<DBoxApp> {
     // get list of host apps to check against afterwards
     let host_apps = get_host_desktop_files();
 
-    let desktop_files = get_command_output(
+    let locales = get_user_locales();
+
+    for desktop_file in get_desktop_files_in_box(box_name) {
+        let entry = &desktop_file.entry;
+        let path = format!("{}/{}", desktop_file.source_dir, desktop_file.relative_path);
+
+        let Some(name) = entry.get_localized("Name", &locales) else {
+            continue;
+        };
+        let Some(exec_args) = entry.exec_args(&locales, &path) else {
+            continue;
+        };
+
+        let desktop_file_name = desktop_file
+            .id
+            .strip_suffix(".desktop")
+            .unwrap_or(&desktop_file.id)
+            .to_string();
+
+        let mut app = DBoxApp {
+            name,
+            exec_args,
+            terminal: entry.get_bool("Terminal"),
+            categories: entry.get_list("Categories"),
+            icon: entry.get_localized("Icon", &locales).unwrap_or_default(),
+            icon_file: None,
+            desktop_file: desktop_file_name,
+            source_dir: desktop_file.source_dir,
+            desktop_file_path: path,
+            is_on_host: false,
+        };
+
+        // figure out if this exists on the host so we can show remove btn instead
+        app.is_on_host = host_apps.contains(&app.host_desktop_file_name(box_name));
+
+        apps.push(app);
+    }
+
+    // the host's icon theme won't have icons for apps which are only in the box
+    let icons: Vec<String> = apps.iter().map(|app| app.icon.clone()).collect();
+    let icon_files = get_app_icons_in_box(box_name, &icons);
+    for app in &mut apps {
+        app.icon_file = icon_files.get(&app.icon).cloned();
+    }
+
+    apps
+}
+
+/// Reads every desktop file in a box which should be listed, in a single
+/// `distrobox enter`. Where two directories have the same desktop file ID, the
+/// one from the earlier directory is used. The result is cached, and reused for
+/// as long as nothing in the box's applications directories has been modified.
+fn get_desktop_files_in_box(box_name: &str) -> Vec<BoxDesktopFile> {
+    let cached_mtimes = BOX_DESKTOP_ENTRIES
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner)
+        .as_ref()
+        .and_then(|cache| cache.get(box_name))
+        .map(|cached| cached.dir_mtimes.clone())
+        .unwrap_or_default();
+
+    let Ok(output) = run_command(
         "distrobox",
         Some(&[
             "enter",
             box_name,
             "--",
-            "bash",
+            "sh",
             "-c",
-            "grep --files-without-match \"NoDisplay=true\" /usr/share/applications/*.desktop",
+            DESKTOP_FILES_SCRIPT,
+            "sh",
+            &cached_mtimes,
         ]),
-    );
+    ) else {
+        return vec![];
+    };
 
-    for line in desktop_files.split('\n') {
-        if line.is_empty() || line.contains("No such file") || !line.starts_with("/usr/share") {
-            continue;
-        }
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let mut pieces = stdout.split('\0');
 
-        let desktop_file_contents =
-            get_command_output("distrobox", Some(&["enter", box_name, "--", "cat", line]));
-
-        let mut pieces: [String; 3] = [String::new(), String::new(), String::new()];
-
-        for df_line in desktop_file_contents.split('\n') {
-            if pieces[0].is_empty() && df_line.starts_with("Name=") {
-                if let Some(l) = df_line.strip_prefix("Name=") {
-                    pieces[0] = l.to_string();
-                }
-            } else if pieces[1].is_empty() && df_line.starts_with("Exec=") {
-                if let Some(l) = df_line.strip_prefix("Exec=") {
-                    pieces[1] = l.to_string();
-                }
-            } else if pieces[2].is_empty() && df_line.starts_with("Icon=") {
-                if let Some(l) = df_line.strip_prefix("Icon=") {
-                    pieces[2] = l.to_string();
-                }
-            }
+    // distrobox may print its own messages first, e.g. when starting the box
+    let dir_mtimes = pieces
+        .next()
+        .and_then(|p| p.lines().last())
+        .unwrap_or_default()
+        .trim()
+        .to_string();
+
+    let mut cache = BOX_DESKTOP_ENTRIES
+        .lock()
+        .unwrap_or_else(PoisonError::into_inner);
+
+    if !dir_mtimes.is_empty() && dir_mtimes == cached_mtimes {
+        if let Some(cached) = cache.as_ref().and_then(|c| c.get(box_name)) {
+            return cached.entries.clone();
         }
+    }
+    drop(cache);
+
+    let current_desktops = get_current_desktops();
+    let mut seen_ids: HashSet<String> = HashSet::new();
+    let mut entries: Vec<BoxDesktopFile> = vec![];
+
+    while let (Some(source_dir), Some(relative_path), Some(try_exec_missing), Some(contents)) =
+        (pieces.next(), pieces.next(), pieces.next(), pieces.next())
+    {
+        // e.g. applications/kde/foo.desktop has the ID kde-foo.desktop
+        let id = relative_path.replace('/', "-");
 
-        if pieces[0].is_empty() || pieces[1].is_empty() {
+        // the first file with an ID is used even if it's hidden, which is
+        // how a user's own copy can hide a system one
+        if !seen_ids.insert(id.clone()) || try_exec_missing == "1" {
             continue;
         }
 
-        // figure out if this exists on the host so we can show remove btn instead
-        let desktop_file_name = line
-            .replace("/usr/share/applications/", "")
-            .replace(".desktop", "");
-
-        let host_desktop_name = format!("{box_name}-{desktop_file_name}.desktop");
-
-        let app = DBoxApp {
-            name: pieces[0].clone(),
-            exec_name: pieces[1]
-                .replace("%F", "")
-                .replace("%U", "")
-                .trim()
-                .to_owned(),
-            icon: pieces[2].clone(),
-            desktop_file: desktop_file_name,
-            is_on_host: host_apps.contains(&host_desktop_name),
-        };
+        let entry = DesktopEntry::parse(contents);
+        if entry.should_show(&current_desktops) {
+            entries.push(BoxDesktopFile {
+                id,
+                source_dir: source_dir.to_string(),
+                relative_path: relative_path.to_string(),
+                entry,
+            });
+        }
+    }
 
-        apps.push(app);
+    if !dir_mtimes.is_empty() {
+        cache = BOX_DESKTOP_ENTRIES
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        cache.get_or_insert_with(HashMap::new).insert(
+            box_name.to_string(),
+            CachedDesktopEntries {
+                dir_mtimes,
+                entries: entries.clone(),
+            },
+        );
     }
 
-    apps
+    entries
 }
 
 pub fn get_binaries_exported_from_box(box_name: &str) -> Vec<String> {
@@ -513,6 +1192,92 @@ pub fn remove_exported_binary_from_box(box_name: &str, binary: &str) {
     );
 }
 
+/// Lists every executable on `$PATH` inside a box, as full paths. Where two
+/// directories have a binary with the same name, only the one which would be run is kept.
+/// Directories in the home folder are skipped when the box shares the host's home,
+/// as they hold the host's own binaries and those already exported.
+pub fn get_binaries_on_box_path(box_name: &str) -> Vec<String> {
+    let output = get_command_output_no_err(
+        "distrobox",
+        Some(&[
+            "enter",
+            box_name,
+            "--",
+            "sh",
+            "-c",
+            r#"shared_home=1
+if [ -n "$DISTROBOX_HOST_HOME" ] && [ "$HOME" != "$DISTROBOX_HOST_HOME" ]; then
+    shared_home=
+fi
+IFS=:
+for d in $PATH; do
+    [ -d "$d" ] || continue
+    if [ -n "$shared_home" ]; then
+        case "$d" in "$HOME"/*) continue ;; esac
+    fi
+    for f in "$d"/*; do
+        [ -f "$f" ] && [ -x "$f" ] && printf '%s\n' "$f"
+    done
+done"#,
+        ]),
+    );
+
+    let mut seen_names: HashSet<String> = HashSet::new();
+    let mut binaries = Vec::<String>::new();
+
+    for line in output.lines() {
+        if !line.starts_with('/') {
+            continue;
+        }
+
+        let name = line.rsplit('/').next().unwrap_or_default();
+        if seen_names.insert(name.to_string()) {
+            binaries.push(line.to_string());
+        }
+    }
+
+    binaries.sort_by_key(|b| b.rsplit('/').next().unwrap_or_default().to_lowercase());
+
+    binaries
+}
+
+/// Exports a binary from inside a box to `export_path` on the host.
+/// Returns distrobox's error if it fails, or if nothing appeared in `export_path`.
+pub fn export_binary_from_box(
+    box_name: &str,
+    binary: &str,
+    export_path: &str,
+) -> Result<String, String> {
+    let output = run_command(
+        "distrobox",
+        Some(&[
+            "enter",
+            box_name,
+            "--",
+            "distrobox-export",
+            "--bin",
+            binary,
+            "--export-path",
+            export_path,
+        ]),
+    )
+    .map_err(|e| e.to_string())?;
+
+    let stdout = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr).trim().to_string();
+        return Err(if stderr.is_empty() { stdout } else { stderr });
+    }
+
+    let name = binary.rsplit('/').next().unwrap_or_default();
+    let exported_path = format!("{}/{}", export_path.trim_end_matches('/'), name);
+    if !host_path_exists(&exported_path) {
+        return Err(format!("{exported_path} was not created"));
+    }
+
+    Ok(stdout)
+}
+
 pub fn stop_box(box_name: &str) {
     let _ = run_command("distrobox", Some(&["stop", box_name, "--yes"]));
 }
@@ -548,8 +1313,7 @@ pub fn install_deb_in_box(box_name: String, file_path: String) {
                 .arg("run")
                 .arg(term)
                 .arg(sep)
-                .arg("distrobox")
-                .arg("enter")
+                .args(get_distrobox_command("enter"))
                 .arg(box_name)
                 .arg("--")
                 .arg("sudo")
@@ -563,8 +1327,7 @@ pub fn install_deb_in_box(box_name: String, file_path: String) {
                 .arg("--host")
                 .arg(term)
                 .arg(sep)
-                .arg("distrobox")
-                .arg("enter")
+                .args(get_distrobox_command("enter"))
                 .arg(box_name)
                 .arg("--")
                 .arg("sudo")
@@ -580,8 +1343,7 @@ pub fn install_deb_in_box(box_name: String, file_path: String) {
                 .arg("run")
                 .arg(term)
                 .arg(sep)
-                .arg("distrobox")
-                .arg("enter")
+                .args(get_distrobox_command("enter"))
                 .arg(box_name)
                 .arg("--")
                 .arg("sudo")
@@ -593,8 +1355,7 @@ pub fn install_deb_in_box(box_name: String, file_path: String) {
         } else {
             Command::new(term)
                 .arg(sep)
-                .arg("distrobox")
-                .arg("enter")
+                .args(get_distrobox_command("enter"))
                 .arg(box_name)
                 .arg("--")
                 .arg("sudo")
@@ -629,8 +1390,7 @@ pub fn install_rpm_in_box(box_name: String, file_path: String) {
                 .arg("run")
                 .arg(term)
                 .arg(sep)
-                .arg("distrobox")
-                .arg("enter")
+                .args(get_distrobox_command("enter"))
                 .arg(box_name)
                 .arg("--")
                 .arg("sudo")
@@ -644,8 +1404,7 @@ pub fn install_rpm_in_box(box_name: String, file_path: String) {
                 .arg("--host")
                 .arg(term)
                 .arg(sep)
-                .arg("distrobox")
-                .arg("enter")
+                .args(get_distrobox_command("enter"))
                 .arg(box_name)
                 .arg("--")
                 .arg("sudo")
@@ -661,8 +1420,7 @@ pub fn install_rpm_in_box(box_name: String, file_path: String) {
                 .arg("run")
                 .arg(term)
                 .arg(sep)
-                .arg("distrobox")
-                .arg("enter")
+                .args(get_distrobox_command("enter"))
                 .arg(box_name)
                 .arg("--")
                 .arg("sudo")
@@ -674,8 +1432,7 @@ pub fn install_rpm_in_box(box_name: String, file_path: String) {
         } else {
             Command::new(term)
                 .arg(sep)
-                .arg("distrobox")
-                .arg("enter")
+                .args(get_distrobox_command("enter"))
                 .arg(box_name)
                 .arg("--")
                 .arg("sudo")
@@ -699,7 +1456,14 @@ pub fn clone_box(box_to_clone: &str, new_name: &str) -> String {
 
 pub fn upgrade_all_boxes() {
     let (term, sep, term_is_flatpak) = get_terminal_and_separator_arg();
-    let command = format!("distrobox-upgrade --all");
+    let command = [join_shell_args(
+        &[
+            get_distrobox_command("upgrade"),
+            vec![String::from("--all")],
+        ]
+        .concat(),
+    )]
+    .join("; ");
 
     if is_flatpak() {
         if term_is_flatpak {
@@ -747,3 +1511,186 @@ pub fn upgrade_all_boxes() {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The value following `flag` in `args`
+    fn arg_after<'a>(args: &[&'a str], flag: &str) -> Option<&'a str> {
+        let idx = args.iter().position(|a| *a == flag)?;
+        args.get(idx + 1).copied()
+    }
+
+    #[test]
+    fn create_args_are_passed_verbatim() {
+        for (name, home_path) in [
+            ("my-box", "/home/me/my boxes/dev"),
+            ("quotes", "/home/me/it's \"quoted\""),
+            ("dollars", "/home/me/$HOME/$(id)"),
+            ("ünïcödé", "/home/me/ünïcödé/箱"),
+        ] {
+            let options = BoxCreateOptions {
+                name: name.to_string(),
+                image: String::from("registry.fedoraproject.org/fedora-toolbox:40"),
+                home_path: Some(home_path.to_string()),
+                volumes: vec![String::from("/mnt/ünïcödé:/mnt/$target")],
+                ..Default::default()
+            };
+
+            let args = get_create_box_args(&options, false);
+            assert_eq!(arg_after(&args, "-n"), Some(name));
+            assert_eq!(arg_after(&args, "--home"), Some(home_path));
+            assert_eq!(
+                arg_after(&args, "--volume"),
+                Some("/mnt/ünïcödé:/mnt/$target")
+            );
+        }
+    }
+
+    #[test]
+    fn unshare_all_replaces_the_others() {
+        let options = BoxCreateOptions {
+            unshare_all: true,
+            unshare_netns: true,
+            unshare_ipc: true,
+            ..Default::default()
+        };
+
+        let args = get_create_box_args(&options, false);
+        assert!(args.contains(&"--unshare-all"));
+        assert!(!args.contains(&"--unshare-netns"));
+        assert!(!args.contains(&"--unshare-ipc"));
+    }
+
+    #[test]
+    fn assemble_nvidia() {
+        let file = "# my boxes\n[dev]\nimage=fedora\nvolume=/a:/a\nvolume=/b:/b\n\n[gpu]\nnvidia = false\nimage=arch\n";
+        let cases = [
+            (
+                true,
+                false,
+                "# my boxes\n[dev]\nnvidia=true\nimage=fedora\nvolume=/a:/a\nvolume=/b:/b\n\n[gpu]\nnvidia = false\nimage=arch\n",
+            ),
+            (
+                true,
+                true,
+                "# my boxes\n[dev]\nnvidia=true\nimage=fedora\nvolume=/a:/a\nvolume=/b:/b\n\n[gpu]\nnvidia=true\nimage=arch\n",
+            ),
+            (
+                false,
+                true,
+                "# my boxes\n[dev]\nnvidia=false\nimage=fedora\nvolume=/a:/a\nvolume=/b:/b\n\n[gpu]\nnvidia=false\nimage=arch\n",
+            ),
+        ];
+
+        for (use_nvidia, replace_existing, expected) in cases {
+            assert_eq!(
+                set_assemble_nvidia(file, use_nvidia, replace_existing),
+                expected,
+                "{use_nvidia} {replace_existing}"
+            );
+        }
+
+        assert_eq!(set_assemble_nvidia("", true, true), "");
+        assert_eq!(set_assemble_nvidia("[a]", true, true), "[a]\nnvidia=true");
+    }
+
+    #[test]
+    fn entrypoint_args_round_trip() {
+        let entrypoint_args: Vec<String> = [
+            "--verbose",
+            "--name",
+            "me",
+            "--home",
+            "/home/me",
+            "--init",
+            "1",
+            "--nvidia",
+            "0",
+            "--pre-init-hooks",
+            "echo before",
+            "--additional-packages",
+            "git vim systemd",
+            "--",
+            "echo \"after\"; touch ~/done",
+        ]
+        .into_iter()
+        .map(String::from)
+        .collect();
+
+        let mut options = BoxCreateOptions::default();
+        apply_entrypoint_args(&mut options, &entrypoint_args);
+
+        assert!(options.use_init);
+        assert_eq!(options.nvidia, NvidiaMode::Off);
+        assert_eq!(options.pre_init_hooks.as_deref(), Some("echo before"));
+        assert_eq!(
+            options.init_hooks.as_deref(),
+            Some("echo \"after\"; touch ~/done")
+        );
+        assert_eq!(options.additional_packages, vec!["git", "vim"]);
+
+        // --init adds systemd back
+        let args = get_create_box_args(&options, false);
+        assert_eq!(
+            args.iter().filter(|a| **a == "systemd").count(),
+            1,
+            "{args:?}"
+        );
+    }
+
+    #[test]
+    fn empty_entrypoint_args_are_left_out() {
+        let entrypoint_args: Vec<String> = [
+            "--init",
+            "0",
+            "--pre-init-hooks",
+            "",
+            "--additional-packages",
+            " ",
+            "--",
+            "",
+        ]
+        .into_iter()
+        .map(String::from)
+        .collect();
+
+        let mut options = BoxCreateOptions::default();
+        apply_entrypoint_args(&mut options, &entrypoint_args);
+
+        assert!(!options.use_init);
+        assert_eq!(options.nvidia, NvidiaMode::Auto);
+        assert_eq!(options.pre_init_hooks, None);
+        assert_eq!(options.init_hooks, None);
+        assert!(options.additional_packages.is_empty());
+    }
+
+    #[test]
+    fn exported_app_commands() {
+        let cases = [
+            ("/usr/bin/distrobox-enter -n my-box -- code", true),
+            ("/usr/bin/distrobox-enter  -n  my-box  --   code  %F", true),
+            ("distrobox-enter --root -n my-box -- code", true),
+            ("distrobox-enter --name my-box -- code", true),
+            ("distrobox-enter --name=my-box -- code", true),
+            ("distrobox enter my-box -- code", true),
+            ("/usr/bin/distrobox enter -a --env=X=1 my-box -- code", true),
+            ("distrobox enter -a my-box other -- code", false),
+            ("/usr/bin/distrobox-enter -n my-box-2 -- code", false),
+            ("/usr/bin/distrobox-enter -n other -- code -n my-box", false),
+            ("/usr/bin/distrobox-enter -- my-box", false),
+            ("code -n my-box", false),
+            ("distrobox list -n my-box", false),
+        ];
+
+        for (exec, expected) in cases {
+            let args: Vec<String> = exec.split_whitespace().map(String::from).collect();
+            assert_eq!(
+                runs_distrobox_enter_for(&args, "my-box"),
+                expected,
+                "{exec:?}"
+            );
+        }
+    }
+}
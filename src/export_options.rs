@@ -0,0 +1,82 @@
+use std::path::PathBuf;
+
+use gtk::glib::{self, KeyFile, KeyFileFlags};
+
+use crate::distrobox_handler::{AppExportOptions, ExportLabel};
+
+/// Written as the label to export an app without one, as `distrobox-export` does
+const NO_LABEL_VALUE: &str = "none";
+
+/// Returns the path of the file remembering each app's export options. It may not exist yet.
+///
+/// Each app is a group named `<box name>/<desktop file ID>`.
+pub fn get_export_options_path() -> PathBuf {
+    glib::user_config_dir()
+        .join("boxbuddy")
+        .join("export-options.ini")
+}
+
+fn get_group_name(box_name: &str, desktop_file: &str) -> String {
+    format!("{box_name}/{desktop_file}")
+}
+
+/// Gets the options an app was last exported with, or the defaults if there are none
+pub fn get_export_options(box_name: &str, desktop_file: &str) -> AppExportOptions {
+    let key_file = load_export_options_file();
+    let group = get_group_name(box_name, desktop_file);
+
+    let string = |key: &str| {
+        key_file
+            .string(&group, key)
+            .ok()
+            .map(|v| v.trim().to_string())
+            .filter(|v| !v.is_empty())
+    };
+
+    AppExportOptions {
+        extra_flags: string("extra_flags"),
+        label: match string("export_label") {
+            Some(label) if label == NO_LABEL_VALUE => ExportLabel::None,
+            Some(label) => ExportLabel::Custom(label),
+            None => ExportLabel::Default,
+        },
+    }
+}
+
+/// Remembers the options to export an app with. Options for other apps are kept.
+pub fn save_export_options(
+    box_name: &str,
+    desktop_file: &str,
+    options: &AppExportOptions,
+) -> Result<(), glib::Error> {
+    let key_file = load_export_options_file();
+    let group = get_group_name(box_name, desktop_file);
+
+    let _ = key_file.remove_group(&group);
+
+    if let Some(extra_flags) = &options.extra_flags {
+        key_file.set_string(&group, "extra_flags", extra_flags);
+    }
+
+    match &options.label {
+        ExportLabel::Default => {}
+        ExportLabel::None => key_file.set_string(&group, "export_label", NO_LABEL_VALUE),
+        ExportLabel::Custom(label) => key_file.set_string(&group, "export_label", label),
+    }
+
+    let path = get_export_options_path();
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .map_err(|e| glib::Error::new(glib::FileError::Failed, &e.to_string()))?;
+    }
+
+    key_file.save_to_file(path)
+}
+
+fn load_export_options_file() -> KeyFile {
+    let key_file = KeyFile::new();
+    // a missing or broken file just means every app uses the defaults
+    let _ = key_file.load_from_file(get_export_options_path(), KeyFileFlags::KEEP_COMMENTS);
+
+    key_file
+}
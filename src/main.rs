@@ -1,8 +1,17 @@
 use gettextrs::gettext;
+use std::cell::{Cell, RefCell};
+use std::collections::{HashMap, VecDeque};
+use std::io::{BufRead, BufReader};
+use std::process::Child;
+use std::rc::Rc;
 use std::thread;
+use std::time::{Duration, Instant};
 
 use adw::{
-    prelude::{ActionRowExt, MessageDialogExt, PreferencesRowExt},
+    prelude::{
+        ActionRowExt, ComboRowExt, EntryRowExt, ExpanderRowExt, MessageDialogExt,
+        PreferencesGroupExt, PreferencesPageExt, PreferencesRowExt, PreferencesWindowExt,
+    },
     ActionRow, Application, StyleManager, ToastOverlay,
 };
 use gtk::{
@@ -14,33 +23,460 @@ use gtk::{
     Align, ApplicationWindow, FileDialog, Notebook, Orientation, PositionType,
 };
 
+mod app_icons;
+mod box_options;
+mod command_args;
+use command_args::{format_volume_arg, VolumeArgError};
+mod create_presets;
+use create_presets::{
+    delete_preset, get_presets_path, read_presets, save_preset, CreatePreset, HOME_NAME_PLACEHOLDER,
+};
+mod desktop_entry;
+mod distrobox_config;
+use distrobox_config::{
+    get_distrobox_config_value, get_distroboxrc_path, get_distroboxrc_values,
+    get_system_config_values, get_user_config_path, parse_config_bool, read_user_config,
+    write_user_config,
+};
+
 mod distrobox_handler;
+mod export_options;
 use distrobox_handler::{
-    assemble_box, clone_box, create_box, delete_box, export_app_from_box, get_all_distroboxes,
-    get_apps_in_box, get_available_images_with_distro_name, get_binaries_exported_from_box,
-    get_number_of_boxes, install_deb_in_box, install_rpm_in_box, open_terminal_in_box,
-    remove_app_from_host, remove_exported_binary_from_box, run_command_in_box, stop_box,
-    upgrade_all_boxes, upgrade_box, DBox, DBoxApp,
+    assemble_box, clone_box, create_box, delete_box, export_app_from_box, export_binary_from_box,
+    get_all_distroboxes, get_apps_in_box, get_available_images_with_distro_name,
+    get_binaries_exported_from_box, get_binaries_on_box_path, get_box_create_options,
+    get_number_of_boxes, install_deb_in_box, install_rpm_in_box, is_app_exported,
+    open_terminal_in_box, recreate_box, remove_app_from_host, remove_exported_binary_from_box,
+    run_command_in_box, run_command_in_box_in_terminal, stop_box, try_parse_distro_name_from_url,
+    unexport_all_apps_from_box, upgrade_all_boxes, upgrade_box, AppExportOptions, BoxCreateOptions,
+    DBox, DBoxApp, ExportLabel, NvidiaMode,
 };
+use export_options::{get_export_options, save_export_options};
 
 mod utils;
 use utils::{
-    get_assemble_icon, get_cpu_and_mem_usage, get_deb_distros, get_distro_img,
-    get_download_dir_path, get_my_deb_boxes, get_my_rpm_boxes, get_rpm_distros,
+    detect_nvidia, format_size, get_all_cpu_and_mem_usage, get_assemble_icon, get_box_processes,
+    get_container_images, get_deb_distros, get_disk_usage, get_distro_img, get_download_dir_path,
+    get_image_tags, get_image_updates, get_my_deb_boxes, get_my_rpm_boxes, get_rpm_distros,
     get_supported_terminals, get_supported_terminals_list, get_terminal_and_separator_arg,
-    has_distrobox_installed, has_file_extension, has_host_access, has_podman_or_docker_installed,
-    set_up_localisation,
+    has_command_installed, has_distrobox_installed, has_file_extension, has_host_access,
+    has_podman_or_docker_installed, host_path_exists, is_image_downloaded,
+    is_valid_image_reference, kill_process_in_box, parse_percentage, prune_images, pull_image,
+    remove_image, set_up_localisation, spawn_box_logs, spawn_image_pull, split_image_reference,
+    stop_spawned_command, validate_box_name, BoxDiskUsage, BoxNameError, BoxProcess,
+    ContainerImage, CpuMemUsage, DiskUsageReport, NvidiaDetection, ProcessSignal, PullProgress,
+    CONTAINER_MANAGERS, MAX_BOX_NAME_LENGTH,
 };
 const APP_ID: &str = "io.github.dvlv.boxbuddyrs";
 
+/// Number of samples kept per box for the CPU and memory graphs
+const STATS_HISTORY_LENGTH: usize = 60;
+
+/// How long the results of checking for newer images are reused for
+const IMAGE_UPDATE_CHECK_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// How long the disk usage shown on the tabs is reused for. The Disk Usage
+/// window always fetches it afresh.
+const DISK_USAGE_REFRESH_INTERVAL: Duration = Duration::from_secs(5 * 60);
+
+thread_local! {
+    /// Rolling CPU and memory history of each box, keyed by box name. Lives
+    /// outside of the tabs so it survives a rerender.
+    static STATS_HISTORY: RefCell<HashMap<String, StatsHistory>> = RefCell::new(HashMap::new());
+
+    /// Whether each box image has a newer version, keyed by image, and when
+    /// that was last checked. Saves asking the registries on every rerender.
+    static IMAGE_UPDATES: RefCell<Option<(Instant, HashMap<String, bool>)>> = const { RefCell::new(None) };
+
+    /// The last disk usage report and when it was fetched. Saves running
+    /// `system df` and `du` on every rerender.
+    static DISK_USAGE: RefCell<Option<(Instant, DiskUsageReport)>> = const { RefCell::new(None) };
+}
+
+/// freedesktop.org main categories which apps are grouped by, in the order the
+/// groups are shown. Apps in Audio or Video are grouped with AudioVideo.
+const APP_CATEGORIES: [&str; 11] = [
+    "AudioVideo",
+    "Development",
+    "Education",
+    "Game",
+    "Graphics",
+    "Network",
+    "Office",
+    "Science",
+    "Settings",
+    "System",
+    "Utility",
+];
+
+/// An app in the View Applications list
+struct AppListItem {
+    app: DBoxApp,
+    /// Index into `APP_CATEGORIES`, or its length for apps in none of them
+    category: usize,
+    /// Whether the app is in the host's menu, kept up to date by its menu button
+    is_on_host: Rc<Cell<bool>>,
+}
+
+impl AppListItem {
+    fn new(app: DBoxApp) -> Self {
+        let category = app
+            .categories
+            .iter()
+            .find_map(|c| {
+                let c = match c.as_str() {
+                    "Audio" | "Video" => "AudioVideo",
+                    c => c,
+                };
+                APP_CATEGORIES.iter().position(|main| *main == c)
+            })
+            .unwrap_or(APP_CATEGORIES.len());
+
+        AppListItem {
+            is_on_host: Rc::new(Cell::new(app.is_on_host)),
+            app,
+            category,
+        }
+    }
+
+    /// Whether the search text is in the app's name, command or desktop file ID
+    fn matches_search(&self, search: &str) -> bool {
+        let search = search.to_lowercase();
+
+        self.app.name.to_lowercase().contains(&search)
+            || self
+                .app
+                .exec_args
+                .join(" ")
+                .to_lowercase()
+                .contains(&search)
+            || self.app.desktop_file.to_lowercase().contains(&search)
+    }
+}
+
 enum AppsFetchMessage {
     AppsFetched(Vec<DBoxApp>, Vec<String>),
 }
 
+enum AppMenuMessage {
+    /// Whether the app is in the host's menu once the export or unexport finished
+    Checked(bool),
+}
+
+enum MenuBatchMessage {
+    /// How many of the apps have been done so far, out of how many
+    Progress(usize, usize),
+    /// Whether each app is in the host's menu afterwards, in the order they were given
+    Finished(Vec<bool>),
+}
+
+enum BoxDeleteMessage {
+    /// Why the box's apps couldn't be removed from the menu, if they couldn't
+    Deleted(Option<String>),
+}
+
+enum ExportOptionsMessage {
+    /// Whether the app was exported again with the new options, if it was in the menu
+    Saved(Option<bool>),
+}
+
+enum BinariesFetchMessage {
+    BinariesFetched(Vec<String>),
+}
+
+enum BinaryExportMessage {
+    /// The export's result, and the binaries now exported from the box
+    Exported(Result<String, String>, Vec<String>),
+}
+
+enum ExportClashMessage {
+    /// Whether the export folder has a file with the command's name, and
+    /// whether the host has a command with its name
+    Checked(bool, bool),
+}
+
+enum StatsFetchMessage {
+    StatsFetched(HashMap<String, CpuMemUsage>),
+}
+
+enum DiskUsageFetchMessage {
+    DiskUsageFetched(DiskUsageReport),
+}
+
+enum ImageUpdatesFetchMessage {
+    UpdatesFetched(HashMap<String, bool>),
+}
+
+enum PullMessage {
+    Line(String),
+}
+
+/// Widgets of the create pop-up which show the progress of an image pull
+#[derive(Clone)]
+struct PullProgressWidgets {
+    container: gtk::Box,
+    progress_bar: gtk::ProgressBar,
+    error_lbl: gtk::Label,
+    /// The running `pull`, taken when it is cancelled
+    pull_process: Rc<RefCell<Option<Child>>>,
+}
+
+enum TagsFetchMessage {
+    TagsFetched(Result<Vec<String>, String>),
+}
+
+enum ImagesFetchMessage {
+    ImagesFetched(Vec<ContainerImage>),
+}
+
+enum ImageActionMessage {
+    Finished(Result<String, String>),
+}
+
+enum ProcessesFetchMessage {
+    ProcessesFetched(Vec<BoxProcess>),
+}
+
+enum ProcessKillMessage {
+    Finished(Result<String, String>),
+}
+
+enum LogsMessage {
+    Line(String),
+}
+
+/// Rolling history of a box's CPU and memory percentages
+#[derive(Default)]
+struct StatsHistory {
+    cpu: VecDeque<f64>,
+    mem: VecDeque<f64>,
+}
+
+impl StatsHistory {
+    fn push(&mut self, cpu: f64, mem: f64) {
+        self.cpu.push_back(cpu);
+        self.mem.push_back(mem);
+
+        while self.cpu.len() > STATS_HISTORY_LENGTH {
+            self.cpu.pop_front();
+        }
+        while self.mem.len() > STATS_HISTORY_LENGTH {
+            self.mem.pop_front();
+        }
+    }
+}
+
+/// Which series of a `StatsHistory` a graph draws
+#[derive(Debug, Clone, Copy)]
+enum StatsGraphKind {
+    Cpu,
+    Mem,
+}
+
+/// Widgets and state of a box's Logs window
+#[derive(Clone)]
+struct LogsViewer {
+    box_name: String,
+    text_view: gtk::TextView,
+    /// Stays at the end of the logs, for scrolling to while following
+    end_mark: gtk::TextMark,
+    search_entry: gtk::SearchEntry,
+    follow_btn: gtk::ToggleButton,
+    timestamps_btn: gtk::ToggleButton,
+    /// The running `logs` command, if any
+    logs_process: Rc<RefCell<Option<Child>>>,
+    /// Bumped each time the logs are restarted, so output from an old
+    /// `logs` command is ignored
+    generation: Rc<Cell<u32>>,
+}
+
+/// Widgets on the box tabs which are filled in after the tabs are built
+#[derive(Default)]
+struct BoxTabWidgets {
+    /// Stats areas of running boxes, keyed by box name
+    stats: HashMap<String, StatsWidgets>,
+    /// Disk usage labels of all boxes, keyed by box name
+    disk_usage: HashMap<String, gtk::Label>,
+    /// Image update notices of all boxes, keyed by box name
+    image_updates: HashMap<String, ImageUpdateWidgets>,
+}
+
+/// Widgets on a box tab which are shown when its image has a newer version
+struct ImageUpdateWidgets {
+    image: String,
+    /// Icon next to the box's name on its tab
+    badge: gtk::Image,
+    banner: adw::Banner,
+}
+
+/// Widgets on a box tab which are updated whenever new stats are fetched
+struct StatsWidgets {
+    container: gtk::Box,
+    cpu_label: gtk::Label,
+    mem_label: gtk::Label,
+    net_label: gtk::Label,
+    block_label: gtk::Label,
+    cpu_graph: gtk::DrawingArea,
+    mem_graph: gtk::DrawingArea,
+}
+
+/// Rows in the Advanced section of the create pop-up
+struct AdvancedCreateRows {
+    nvidia: adw::ComboRow,
+    hostname: adw::EntryRow,
+    additional_packages: adw::EntryRow,
+    additional_flags: adw::EntryRow,
+    init_hooks: adw::EntryRow,
+    pre_init_hooks: adw::EntryRow,
+    unshare_netns: adw::SwitchRow,
+    unshare_ipc: adw::SwitchRow,
+    unshare_all: adw::SwitchRow,
+    pull: adw::SwitchRow,
+    add_to_menu: adw::SwitchRow,
+}
+
+impl AdvancedCreateRows {
+    /// Copies the chosen options into `options`. Empty entries are left unset.
+    fn apply_to(&self, options: &mut BoxCreateOptions) {
+        let optional_text = |row: &adw::EntryRow| {
+            let text = row.text().trim().to_string();
+            (!text.is_empty()).then_some(text)
+        };
+
+        options.hostname = optional_text(&self.hostname);
+        options.additional_flags = optional_text(&self.additional_flags);
+        options.init_hooks = optional_text(&self.init_hooks);
+        options.pre_init_hooks = optional_text(&self.pre_init_hooks);
+        options.additional_packages = self
+            .additional_packages
+            .text()
+            .split_whitespace()
+            .map(String::from)
+            .collect();
+        options.unshare_netns = self.unshare_netns.is_active();
+        options.unshare_ipc = self.unshare_ipc.is_active();
+        options.unshare_all = self.unshare_all.is_active();
+        options.pull = self.pull.is_active();
+        options.no_entry = !self.add_to_menu.is_active();
+        options.nvidia = get_chosen_nvidia_mode(&self.nvidia);
+    }
+
+    /// Sets every row from `options`, the reverse of `apply_to`
+    fn load_from(&self, options: &BoxCreateOptions) {
+        self.hostname
+            .set_text(options.hostname.as_deref().unwrap_or_default());
+        self.additional_flags
+            .set_text(options.additional_flags.as_deref().unwrap_or_default());
+        self.init_hooks
+            .set_text(options.init_hooks.as_deref().unwrap_or_default());
+        self.pre_init_hooks
+            .set_text(options.pre_init_hooks.as_deref().unwrap_or_default());
+        self.additional_packages
+            .set_text(&options.additional_packages.join(" "));
+        self.unshare_all.set_active(options.unshare_all);
+        self.unshare_netns
+            .set_active(options.unshare_netns || options.unshare_all);
+        self.unshare_ipc
+            .set_active(options.unshare_ipc || options.unshare_all);
+        self.pull.set_active(options.pull);
+        self.add_to_menu.set_active(!options.no_entry);
+        self.nvidia.set_selected(match options.nvidia {
+            NvidiaMode::Auto => 0,
+            NvidiaMode::On => 1,
+            NvidiaMode::Off => 2,
+        });
+    }
+}
+
+/// Rows of the create pop-up which make up a box's options, apart from its name
+#[derive(Clone)]
+struct CreateFormWidgets {
+    image_select: gtk::DropDown,
+    images_strlist: gtk::StringList,
+    custom_image_row: adw::EntryRow,
+    tag_select: gtk::DropDown,
+    init_row: adw::SwitchRow,
+    home_entry_row: adw::EntryRow,
+    /// Only shown, and used, with access to the host's files
+    volume_box_list: Option<gtk::ListBox>,
+    advanced_rows: Rc<AdvancedCreateRows>,
+}
+
+impl CreateFormWidgets {
+    /// Reads the options as entered. The home directory is left as typed,
+    /// which may include `HOME_NAME_PLACEHOLDER`. Fails if a volume can't be used.
+    fn collect(&self) -> Result<BoxCreateOptions, String> {
+        let home_path = self.home_entry_row.text().trim().to_string();
+
+        let mut options = BoxCreateOptions {
+            image: get_image_with_chosen_tag(
+                &get_chosen_image(&self.image_select, &self.custom_image_row),
+                &self.tag_select,
+            ),
+            home_path: (!home_path.is_empty()).then_some(home_path),
+            use_init: self.init_row.is_active(),
+            volumes: match &self.volume_box_list {
+                Some(volume_box_list) => get_new_box_volumes(volume_box_list)?,
+                None => vec![],
+            },
+            ..Default::default()
+        };
+        self.advanced_rows.apply_to(&mut options);
+
+        Ok(options)
+    }
+
+    /// Fills in every row from a preset's options
+    fn load(&self, options: &BoxCreateOptions) {
+        match find_listed_image(&self.images_strlist, &options.image) {
+            Some(idx) => {
+                self.custom_image_row.set_text("");
+                self.image_select.set_selected(idx);
+            }
+            None => self.custom_image_row.set_text(&options.image),
+        }
+
+        self.init_row.set_active(options.use_init);
+        self.home_entry_row
+            .set_text(options.home_path.as_deref().unwrap_or_default());
+
+        if let Some(volume_box_list) = &self.volume_box_list {
+            volume_box_list.remove_all();
+            volume_box_list.set_visible(false);
+            for volume in &options.volumes {
+                let (host_path, box_path) = volume.split_once(':').unwrap_or((volume, volume));
+                append_volume_row(volume_box_list, host_path, box_path);
+            }
+        }
+
+        self.advanced_rows.load_from(options);
+    }
+}
+
 enum BoxCreatedMessage {
     Success,
 }
 
+enum BoxCreateMessage {
+    /// distrobox's output, or its error if the box couldn't be created
+    Finished(Result<String, String>),
+}
+
+enum NvidiaDetectMessage {
+    /// What was found on the host, and the `nvidia` setting in distrobox's config
+    Detected(NvidiaDetection, Option<bool>),
+}
+
+enum ImageCheckMessage {
+    /// Whether the image can be used without pulling it first
+    Checked(bool),
+}
+
+enum BoxOptionsFetchMessage {
+    /// The options to recreate a box with, and whether all of them are known
+    OptionsFetched(BoxCreateOptions, bool),
+}
+
 #[derive(Debug, Clone, Copy)]
 enum BinaryPackageType {
     Deb,
@@ -155,7 +591,7 @@ fn make_titlebar(window: &ApplicationWindow) {
     add_btn.set_tooltip_text(Some(&gettext("Create A Distrobox")));
 
     let win_clone = window.clone();
-    add_btn.connect_clicked(move |_btn| create_new_distrobox(&win_clone));
+    add_btn.connect_clicked(move |_btn| create_new_distrobox(&win_clone, None));
 
     let upgrade_btn = gtk::Button::from_icon_name("software-update-available-symbolic");
     // TRANSLATORS: Button tooltip
@@ -226,23 +662,37 @@ fn set_window_actions(window: &ApplicationWindow) {
         })
         .build();
 
+    let action_disk_usage = gio::ActionEntry::builder("disk_usage")
+        .activate(|window: &ApplicationWindow, _, _| {
+            show_disk_usage_popup(window);
+        })
+        .build();
+
+    let action_images = gio::ActionEntry::builder("images")
+        .activate(|window: &ApplicationWindow, _, _| {
+            show_images_popup(window);
+        })
+        .build();
+
     let action_about = gio::ActionEntry::builder("about")
         .activate(|window: &ApplicationWindow, _, _| {
             show_about_popup(window);
         })
         .build();
 
-    let action_set_preferred_terminal = gio::ActionEntry::builder("set_preferred_terminal")
+    let action_preferences = gio::ActionEntry::builder("preferences")
         .activate(|window: &ApplicationWindow, _, _| {
-            show_preferred_terminal_popup(window);
+            show_preferences_window(window);
         })
         .build();
 
     window.add_action_entries([
         action_refresh,
+        action_disk_usage,
+        action_images,
         action_about,
         action_close,
-        action_set_preferred_terminal,
+        action_preferences,
     ]);
 }
 
@@ -257,20 +707,27 @@ fn get_main_menu_model() -> gio::MenuModel {
     );
     menu.insert_item(
         1,
-        &gio::MenuItem::new(
-            //TRANSLATORS: Menu Item
-            Some(&gettext("Set Preferred Terminal")),
-            Some("win.set_preferred_terminal"),
-        ),
+        //TRANSLATORS: Menu Item
+        &gio::MenuItem::new(Some(&gettext("Disk Usage")), Some("win.disk_usage")),
     );
     menu.insert_item(
         2,
         //TRANSLATORS: Menu Item
-        &gio::MenuItem::new(Some(&gettext("About BoxBuddy")), Some("win.about")),
+        &gio::MenuItem::new(Some(&gettext("Images")), Some("win.images")),
     );
     menu.insert_item(
         3,
         //TRANSLATORS: Menu Item
+        &gio::MenuItem::new(Some(&gettext("Preferences")), Some("win.preferences")),
+    );
+    menu.insert_item(
+        4,
+        //TRANSLATORS: Menu Item
+        &gio::MenuItem::new(Some(&gettext("About BoxBuddy")), Some("win.about")),
+    );
+    menu.insert_item(
+        5,
+        //TRANSLATORS: Menu Item
         &gio::MenuItem::new(Some(&gettext("Quit")), Some("win.close")),
     );
 
@@ -309,7 +766,7 @@ fn render_podman_not_installed(scroll_area: &gtk::Box) {
 
 fn load_boxes(scroll_area: &gtk::Box, window: &ApplicationWindow, active_page: Option<u32>) {
     let tabs = Notebook::new();
-    tabs.set_tab_pos(PositionType::Left);
+    tabs.set_tab_pos(get_tab_position());
     tabs.set_hexpand(true);
     tabs.set_vexpand(true);
 
@@ -320,8 +777,10 @@ fn load_boxes(scroll_area: &gtk::Box, window: &ApplicationWindow, active_page: O
         return;
     }
 
+    let mut tab_widgets = BoxTabWidgets::default();
+
     for (box_num, dbox) in boxes.iter().enumerate() {
-        let tab = make_box_tab(dbox, window, box_num as u32);
+        let tab = make_box_tab(dbox, window, box_num as u32, &mut tab_widgets);
         tab.set_hexpand(true);
         tab.set_vexpand(true);
 
@@ -333,6 +792,10 @@ fn load_boxes(scroll_area: &gtk::Box, window: &ApplicationWindow, active_page: O
         tab_title.append(&tab_title_img);
         tab_title.append(&tab_title_lbl);
 
+        if let Some(update_widgets) = tab_widgets.image_updates.get(&dbox.name) {
+            tab_title.append(&update_widgets.badge);
+        }
+
         tabs.append_page(&tab, Some(&tab_title));
     }
 
@@ -345,9 +808,21 @@ fn load_boxes(scroll_area: &gtk::Box, window: &ApplicationWindow, active_page: O
     if active_page.is_some() {
         tabs.set_current_page(active_page);
     }
+
+    if !tab_widgets.stats.is_empty() {
+        start_stats_polling(&tabs, tab_widgets.stats);
+    }
+
+    fetch_disk_usage_for_tabs(tab_widgets.disk_usage);
+    fetch_image_updates_for_tabs(tab_widgets.image_updates);
 }
 
-fn make_box_tab(dbox: &DBox, window: &ApplicationWindow, tab_num: u32) -> gtk::Box {
+fn make_box_tab(
+    dbox: &DBox,
+    window: &ApplicationWindow,
+    tab_num: u32,
+    tab_widgets: &mut BoxTabWidgets,
+) -> gtk::Box {
     let box_name = dbox.name.clone();
 
     let tab_box = gtk::Box::new(Orientation::Vertical, 15);
@@ -375,8 +850,7 @@ fn make_box_tab(dbox: &DBox, window: &ApplicationWindow, tab_num: u32) -> gtk::B
     let box_name_clone = dbox.name.clone();
     let win_clone = window.clone();
     stop_btn.connect_clicked(move |_btn| {
-        stop_box(&box_name_clone);
-        delayed_rerender(&win_clone, Some(tab_num));
+        on_stop_clicked(&win_clone, box_name_clone.clone(), tab_num);
     });
 
     let title_box = gtk::Box::new(Orientation::Horizontal, 10);
@@ -434,6 +908,21 @@ fn make_box_tab(dbox: &DBox, window: &ApplicationWindow, tab_num: u32) -> gtk::B
         on_show_applications_clicked(&win_clone, show_bn_clone.clone());
     });
 
+    // View Logs Icon
+    let view_logs_icon = gtk::Image::from_icon_name("text-x-generic-symbolic");
+
+    let view_logs_row = ActionRow::new();
+    // TRANSLATORS: Row Label
+    view_logs_row.set_title(&gettext("View Logs"));
+    view_logs_row.add_suffix(&view_logs_icon);
+    view_logs_row.set_activatable(true);
+
+    let logs_bn_clone = box_name.clone();
+    let win_clone = window.clone();
+    view_logs_row.connect_activated(move |_row| {
+        on_view_logs_clicked(&win_clone, logs_bn_clone.clone());
+    });
+
     // Install Deb Icon
     let deb_bn_clone = box_name.clone();
     let install_deb_icon = gtk::Image::from_icon_name("system-software-install-symbolic");
@@ -472,6 +961,7 @@ fn make_box_tab(dbox: &DBox, window: &ApplicationWindow, tab_num: u32) -> gtk::B
     boxed_list.append(&open_terminal_row);
     boxed_list.append(&upgrade_row);
     boxed_list.append(&show_applications_row);
+    boxed_list.append(&view_logs_row);
 
     // Make deb / rpm row if applicable
     let deb_distros = get_deb_distros();
@@ -507,464 +997,465 @@ fn make_box_tab(dbox: &DBox, window: &ApplicationWindow, tab_num: u32) -> gtk::B
     boxed_list.append(&clone_row);
     boxed_list.append(&delete_row);
 
+    // Image update notice - shown by fetch_image_updates_for_tabs
+    // TRANSLATORS: Banner text on a box's tab
+    let update_banner =
+        adw::Banner::new(&gettext("A newer version of this box's image is available"));
+    // TRANSLATORS: Button Label - recreates the box from the newer image
+    update_banner.set_button_label(Some(&gettext("Recreate…")));
+
+    let update_bn_clone = box_name.clone();
+    let update_image = dbox.image_url.clone();
+    let win_clone = window.clone();
+    update_banner.connect_button_clicked(move |_banner| {
+        on_recreate_clicked(&win_clone, update_bn_clone.clone(), update_image.clone());
+    });
+
+    let update_badge = gtk::Image::from_icon_name("software-update-available-symbolic");
+    // TRANSLATORS: Tab icon tooltip
+    update_badge.set_tooltip_text(Some(&gettext("Image update available")));
+    update_badge.set_visible(false);
+
+    tab_widgets.image_updates.insert(
+        box_name.clone(),
+        ImageUpdateWidgets {
+            image: dbox.image_url.clone(),
+            badge: update_badge,
+            banner: update_banner.clone(),
+        },
+    );
+
+    tab_box.append(&update_banner);
     tab_box.append(&title_box);
     tab_box.append(&gtk::Separator::new(Orientation::Horizontal));
     tab_box.append(&boxed_list);
 
-    // CPU and Mem Stats
+    // CPU, Mem, Network and Block I/O Stats - filled in by start_stats_polling
     if dbox.is_running {
-        let cpu_mem_stats = get_cpu_and_mem_usage(&box_name);
-        if !cpu_mem_stats.cpu.is_empty() {
-            let stats_box = gtk::Box::new(gtk::Orientation::Horizontal, 10);
-            stats_box.set_hexpand(true);
-            let cpu_label = gtk::Label::new(Some(&format!("CPU: {}", cpu_mem_stats.cpu)));
-            let mem_label = gtk::Label::new(Some(&format!(
-                "Memory: {} ({})",
-                cpu_mem_stats.mem, cpu_mem_stats.mem_percent
-            )));
+        let (stats_box, widgets) = make_stats_box(&box_name, window);
+        tab_box.append(&stats_box);
+        tab_widgets.stats.insert(box_name.clone(), widgets);
+    }
 
-            cpu_label.set_halign(Align::End);
-            cpu_label.set_hexpand(true);
+    // Disk usage - filled in by fetch_disk_usage_for_tabs
+    let disk_usage_label = gtk::Label::new(None);
+    disk_usage_label.set_halign(Align::End);
+    disk_usage_label.set_hexpand(true);
+    disk_usage_label.set_visible(false);
+    tab_box.append(&disk_usage_label);
+    tab_widgets
+        .disk_usage
+        .insert(box_name.clone(), disk_usage_label);
 
-            mem_label.set_halign(Align::End);
+    tab_box
+}
 
-            stats_box.append(&cpu_label);
-            stats_box.append(&mem_label);
+/// Runs `system df -v` in the background and fills in the disk usage
+/// label on each box tab.
+fn fetch_disk_usage_for_tabs(disk_usage_labels: HashMap<String, gtk::Label>) {
+    // reuse a recent report, unless it's missing a box which was created since
+    let cached = DISK_USAGE.with_borrow(|cache| {
+        cache
+            .as_ref()
+            .filter(|(fetched, report)| {
+                fetched.elapsed() < DISK_USAGE_REFRESH_INTERVAL
+                    && disk_usage_labels
+                        .keys()
+                        .all(|name| report.boxes.iter().any(|usage| &usage.name == name))
+            })
+            .map(|(_, report)| report.clone())
+    });
 
-            tab_box.append(&stats_box);
-        }
+    if let Some(report) = cached {
+        show_disk_usage_on_tabs(&disk_usage_labels, &report);
+        return;
     }
 
-    tab_box
-}
-
-fn assemble_new_distrobox(window: &ApplicationWindow, ini_file: String) {
-    let assemble_box_popup = gtk::Window::builder()
-        // TRANSLATORS: Popup Window Title
-        .title(gettext("Create New Distrobox"))
-        .transient_for(window)
-        .default_width(700)
-        .default_height(350)
-        .modal(true)
-        .build();
+    let (sender, receiver) = async_channel::bounded(1);
 
-    let assemble_box_titlebar = adw::HeaderBar::new();
-    assemble_box_titlebar.set_show_end_title_buttons(false);
-    assemble_box_popup.set_titlebar(Some(&assemble_box_titlebar));
+    gio::spawn_blocking(move || {
+        let report = get_disk_usage();
+        sender
+            .send_blocking(DiskUsageFetchMessage::DiskUsageFetched(report))
+            .expect("The channel needs to be open.");
+    });
 
-    // TRANSLATORS: Context label of the application doing something
-    let assemble_lbl = gtk::Label::new(Some(&gettext("Assembling Distroboxes, please wait...")));
+    glib::spawn_future_local(async move {
+        while let Ok(msg) = receiver.recv().await {
+            match msg {
+                DiskUsageFetchMessage::DiskUsageFetched(report) => {
+                    show_disk_usage_on_tabs(&disk_usage_labels, &report);
+                    DISK_USAGE.set(Some((Instant::now(), report)));
+                }
+            }
+        }
+    });
+}
 
-    //Loading spinner
-    let loading_spinner = gtk::Spinner::new();
-    loading_spinner.start();
+fn show_disk_usage_on_tabs(
+    disk_usage_labels: &HashMap<String, gtk::Label>,
+    report: &DiskUsageReport,
+) {
+    for usage in &report.boxes {
+        if let Some(label) = disk_usage_labels.get(&usage.name) {
+            label.set_text(&format!(
+                "{}: {}",
+                // TRANSLATORS: Label before the disk space used by a box
+                gettext("Disk Usage"),
+                get_disk_usage_summary(usage)
+            ));
+            label.set_visible(true);
+        }
+    }
+}
 
-    let main_box = gtk::Box::new(Orientation::Vertical, 10);
-    main_box.set_margin_start(10);
-    main_box.set_margin_end(10);
-    main_box.set_margin_top(10);
-    main_box.set_margin_bottom(10);
+/// Checks the registries for newer versions of the boxes' images in the
+/// background, then shows the update notice on each affected tab. Results
+/// are reused for an hour so rerendering doesn't check again.
+fn fetch_image_updates_for_tabs(update_widgets: HashMap<String, ImageUpdateWidgets>) {
+    let cached = IMAGE_UPDATES.with_borrow(|cache| {
+        cache
+            .as_ref()
+            .filter(|(checked, updates)| {
+                checked.elapsed() < IMAGE_UPDATE_CHECK_INTERVAL
+                    && update_widgets
+                        .values()
+                        .all(|w| updates.contains_key(&w.image))
+            })
+            .map(|(_, updates)| updates.clone())
+    });
 
-    main_box.append(&assemble_lbl);
-    main_box.append(&loading_spinner);
+    if let Some(updates) = cached {
+        show_image_updates(&update_widgets, &updates);
+        return;
+    }
 
-    assemble_box_popup.set_child(Some(&main_box));
-    assemble_box_popup.present();
+    let mut images: Vec<String> = update_widgets.values().map(|w| w.image.clone()).collect();
+    images.sort();
+    images.dedup();
 
     let (sender, receiver) = async_channel::bounded(1);
 
     gio::spawn_blocking(move || {
-        assemble_box(&ini_file);
+        let mut updates = get_image_updates(&images);
+        // remember images which couldn't be checked too, so they aren't retried every rerender
+        for image in images {
+            updates.entry(image).or_insert(false);
+        }
+
         sender
-            .send_blocking(BoxCreatedMessage::Success)
+            .send_blocking(ImageUpdatesFetchMessage::UpdatesFetched(updates))
             .expect("The channel needs to be open.");
     });
 
-    let ls_clone = loading_spinner.clone();
-    let w_clone = window.clone();
-    let popup = assemble_box_popup.clone();
-
-    glib::spawn_future_local(clone!(
-        #[weak]
-        popup,
-        async move {
-            while let Ok(msg) = receiver.recv().await {
-                match msg {
-                    BoxCreatedMessage::Success => {
-                        ls_clone.stop();
-
-                        let num_boxes = get_number_of_boxes();
-                        delayed_rerender(&w_clone, Some(num_boxes - 1));
-                        popup.destroy();
-                    }
+    glib::spawn_future_local(async move {
+        while let Ok(msg) = receiver.recv().await {
+            match msg {
+                ImageUpdatesFetchMessage::UpdatesFetched(updates) => {
+                    show_image_updates(&update_widgets, &updates);
+                    IMAGE_UPDATES.set(Some((Instant::now(), updates)));
                 }
             }
         }
-    ));
+    });
 }
 
-// callbacks
-fn create_new_distrobox(window: &ApplicationWindow) {
-    let new_box_popup = gtk::Window::builder()
-        // TRANSLATORS: Popup Window Title
-        .title(gettext("Create New Distrobox"))
-        .transient_for(window)
-        .default_width(700)
-        .default_height(350)
-        .modal(true)
-        .build();
+fn show_image_updates(
+    update_widgets: &HashMap<String, ImageUpdateWidgets>,
+    updates: &HashMap<String, bool>,
+) {
+    for widgets in update_widgets.values() {
+        let has_update = updates.get(&widgets.image).copied().unwrap_or(false);
+        widgets.badge.set_visible(has_update);
+        widgets.banner.set_revealed(has_update);
+    }
+}
 
-    // TRANSLATORS: Button Label
-    let create_btn = gtk::Button::with_label(&gettext("Create"));
-    create_btn.add_css_class("suggested-action");
-    create_btn.set_sensitive(false);
+/// Describes the writable layer, image and custom home sizes of a box
+fn get_disk_usage_summary(usage: &BoxDiskUsage) -> String {
+    let mut pieces = vec![
+        // TRANSLATORS: Disk usage - size of the box's own files
+        format!(
+            "{} {}",
+            format_size(usage.container_size),
+            gettext("container")
+        ),
+        // TRANSLATORS: Disk usage - size of the box's container image
+        format!("{} {}", format_size(usage.image_size), gettext("image")),
+    ];
+
+    if usage.home_path.is_some() {
+        // TRANSLATORS: Disk usage - size of the box's custom home directory
+        pieces.push(format!(
+            "{} {}",
+            format_size(usage.home_size),
+            gettext("home")
+        ));
+    }
 
-    let info_btn = gtk::Button::from_icon_name("dialog-information-symbolic");
-    // TRANSLATORS: Button Label
-    info_btn.set_tooltip_text(Some(&gettext("Additional Information")));
-    let win_clone = window.clone();
-    info_btn.connect_clicked(move |_btn| show_flatpak_dir_access_popup(&win_clone));
+    pieces.join(", ")
+}
 
-    // TRANSLATORS: Button Label
-    let cancel_btn = gtk::Button::with_label(&gettext("Cancel"));
+/// Builds the stats area of a box tab. It stays hidden until the first
+/// stats for this box arrive.
+fn make_stats_box(box_name: &str, window: &ApplicationWindow) -> (gtk::Box, StatsWidgets) {
+    let stats_box = gtk::Box::new(gtk::Orientation::Vertical, 5);
+    stats_box.set_hexpand(true);
+    stats_box.set_visible(false);
 
-    cancel_btn.connect_clicked(move |btn| {
-        let win = btn.root().and_downcast::<gtk::Window>().unwrap();
-        win.destroy();
-    });
+    let cpu_mem_box = gtk::Box::new(gtk::Orientation::Horizontal, 10);
+    let io_box = gtk::Box::new(gtk::Orientation::Horizontal, 10);
 
-    let new_box_titlebar = adw::HeaderBar::new();
-    new_box_titlebar.set_show_end_title_buttons(false);
+    let cpu_label = gtk::Label::new(None);
+    let mem_label = gtk::Label::new(None);
+    let net_label = gtk::Label::new(None);
+    let block_label = gtk::Label::new(None);
 
-    new_box_titlebar.pack_end(&create_btn);
-    new_box_titlebar.pack_start(&cancel_btn);
+    cpu_label.set_halign(Align::End);
+    cpu_label.set_hexpand(true);
+    mem_label.set_halign(Align::End);
 
-    new_box_titlebar.pack_end(&info_btn);
+    net_label.set_halign(Align::End);
+    net_label.set_hexpand(true);
+    block_label.set_halign(Align::End);
 
-    new_box_popup.set_titlebar(Some(&new_box_titlebar));
+    let cpu_graph = make_stats_graph(box_name, StatsGraphKind::Cpu);
+    let mem_graph = make_stats_graph(box_name, StatsGraphKind::Mem);
 
-    let main_box = gtk::Box::new(Orientation::Vertical, 10);
-    main_box.set_margin_start(10);
-    main_box.set_margin_end(10);
-    main_box.set_margin_top(10);
-    main_box.set_margin_bottom(10);
+    cpu_mem_box.append(&cpu_label);
+    cpu_mem_box.append(&cpu_graph);
+    let processes_btn = gtk::Button::from_icon_name("utilities-system-monitor-symbolic");
+    processes_btn.add_css_class("flat");
+    // TRANSLATORS: Button tooltip
+    processes_btn.set_tooltip_text(Some(&gettext("Processes")));
 
-    let boxed_list = gtk::ListBox::new();
-    boxed_list.set_selection_mode(gtk::SelectionMode::None);
-    boxed_list.add_css_class("boxed-list");
+    let processes_bn = box_name.to_string();
+    let win_clone = window.clone();
+    processes_btn.connect_clicked(move |_btn| {
+        show_processes_popup(&win_clone, processes_bn.clone());
+    });
 
-    // name input
-    let name_entry_row = adw::EntryRow::new();
-    name_entry_row.set_hexpand(true);
-
-    // name input must have text in it to enable the create button
-    let ner_clone = name_entry_row.clone();
-    name_entry_row.connect_changed(clone!(@weak create_btn => move |_row| {
-        if ner_clone.text().to_string().len() > 0 {
-            create_btn.set_sensitive(true);
-        } else {
-            create_btn.set_sensitive(false);
-        }
-    }));
-
-    // TRANSLATORS: Entry Label - Name input for new distrobox
-    name_entry_row.set_title(&gettext("Name"));
+    cpu_mem_box.append(&mem_label);
+    cpu_mem_box.append(&mem_graph);
+    cpu_mem_box.append(&processes_btn);
 
-    // custom home
-    let choose_home_btn = gtk::Button::from_icon_name("document-open-symbolic");
-    choose_home_btn.set_margin_top(10);
-    choose_home_btn.set_margin_bottom(10);
-    let home_select_row = adw::ActionRow::new();
-    home_select_row.set_activatable_widget(Some(&choose_home_btn));
-    home_select_row.add_suffix(&choose_home_btn);
+    io_box.append(&net_label);
+    io_box.append(&block_label);
 
-    //home entry row for manual edit
-    let home_entry_row = adw::EntryRow::new();
-    home_entry_row.set_hexpand(true);
+    stats_box.append(&cpu_mem_box);
+    stats_box.append(&io_box);
 
-    //Additional Volumes - will not be shown without host access
-    let volume_box_list = gtk::ListBox::new();
-    volume_box_list.set_selection_mode(gtk::SelectionMode::None);
-    volume_box_list.add_css_class("boxed-list");
-    volume_box_list.set_visible(false);
+    let widgets = StatsWidgets {
+        container: stats_box.clone(),
+        cpu_label,
+        mem_label,
+        net_label,
+        block_label,
+        cpu_graph,
+        mem_graph,
+    };
 
-    // TRANSLATORS: Entry Label - Select home directory for new distrobox
-    home_entry_row.set_title(&gettext("Home Directory (Leave blank for default)"));
-    home_entry_row.set_width_request(600);
-    home_select_row.add_prefix(&home_entry_row);
-    let home_entry_row_future_clone = home_entry_row.clone();
+    (stats_box, widgets)
+}
 
-    choose_home_btn.connect_clicked(clone!(@weak window => move |_btn| {
-        let home_clone = home_entry_row.clone();
-        let file_dialog = FileDialog::builder().modal(false).build();
-        file_dialog.select_folder(Some(&window), None::<&gio::Cancellable>, clone!(@weak window => move |result| {
-            if let Ok(file) = result {
-                let home_path = file.path().unwrap().into_os_string().into_string().unwrap();
-                home_clone.set_text(&home_path);
-            }
-        }));
-    }));
+/// Makes a small sparkline of a box's CPU or memory history
+fn make_stats_graph(box_name: &str, kind: StatsGraphKind) -> gtk::DrawingArea {
+    let graph = gtk::DrawingArea::new();
+    graph.set_content_width(120);
+    graph.set_content_height(30);
+    graph.set_valign(Align::Center);
+
+    let box_name = box_name.to_string();
+    graph.set_draw_func(move |area, cr, width, height| {
+        STATS_HISTORY.with(|history| {
+            let history = history.borrow();
+            let empty = VecDeque::new();
+            let values = match (history.get(&box_name), kind) {
+                (Some(h), StatsGraphKind::Cpu) => &h.cpu,
+                (Some(h), StatsGraphKind::Mem) => &h.mem,
+                (None, _) => &empty,
+            };
+
+            draw_stats_graph(area, cr, width, height, values);
+        });
+    });
 
-    // Image
-    let available_images = get_available_images_with_distro_name();
-    let avail_images_as_ref: Vec<&str> = available_images.iter().map(|s| s as &str).collect();
-    let imgs_strlist = gtk::StringList::new(avail_images_as_ref.as_slice());
+    graph
+}
 
-    let exp = gtk::PropertyExpression::new(
-        gtk::StringObject::static_type(),
-        None::<gtk::Expression>,
-        "string",
+fn draw_stats_graph(
+    area: &gtk::DrawingArea,
+    cr: &gtk::cairo::Context,
+    width: i32,
+    height: i32,
+    values: &VecDeque<f64>,
+) {
+    let colour = area.color();
+    let (red, green, blue) = (
+        f64::from(colour.red()),
+        f64::from(colour.green()),
+        f64::from(colour.blue()),
     );
+    let width = f64::from(width);
+    let height = f64::from(height);
 
-    let image_select = gtk::DropDown::new(Some(imgs_strlist), Some(exp));
-    image_select.set_enable_search(true);
-    image_select.set_search_match_mode(gtk::StringFilterMatchMode::Substring);
-
-    let image_select_row = adw::ActionRow::new();
-    // TRANSLATORS - Label for Dropdown where the user selects the container image to create
-    image_select_row.set_title(&gettext("Image"));
-    image_select_row.set_activatable_widget(Some(&image_select));
-    image_select_row.add_suffix(&image_select);
+    // faint background so an empty graph is still visible
+    cr.set_source_rgba(red, green, blue, 0.1);
+    cr.rectangle(0.0, 0.0, width, height);
+    let _ = cr.fill();
 
-    // init
-    let init_row = adw::SwitchRow::new();
-    // TRANSLATORS - Label for Toggle when creating box to add systemd support
-    init_row.set_title(&gettext("Use init system"));
-    // TRANSLATORS: Explanation of what the 'use init system' toggle does
-    init_row.set_subtitle(&gettext("Adds systemd support - ignore if you're not sure"));
-    init_row.set_active(false);
+    if values.len() < 2 {
+        return;
+    }
 
-    let loading_spinner = gtk::Spinner::new();
+    // CPU can go above 100% on multi-core machines, so scale to fit
+    let max_value = values.iter().copied().fold(100.0, f64::max);
+    let step = width / (STATS_HISTORY_LENGTH - 1) as f64;
+    // newest value is always on the right-hand edge
+    let offset = (STATS_HISTORY_LENGTH - values.len()) as f64 * step;
 
-    let home_row = home_entry_row_future_clone.clone();
-    let ne_row = name_entry_row.clone();
-    let is_row = image_select_row.clone();
-    let in_row = init_row.clone();
-    let loading_spinner_clone = loading_spinner.clone();
-    let win_clone = window.clone();
-    let volume_box_list_clone = volume_box_list.clone();
-    create_btn.connect_clicked(move |btn| {
-        let mut name = ne_row.text().to_string();
-        let mut home_path = home_row.text().to_string();
-        let use_init = in_row.is_active();
-        let mut image = is_row
-            .activatable_widget()
-            .and_downcast::<gtk::DropDown>()
-            .unwrap()
-            .selected_item()
-            .unwrap()
-            .downcast::<gtk::StringObject>()
-            .unwrap()
-            .string()
-            .to_string();
+    for (idx, value) in values.iter().enumerate() {
+        let x = offset + idx as f64 * step;
+        let y = height - (value / max_value) * height;
 
-        if name.is_empty() || image.is_empty() {
-            return;
+        if idx == 0 {
+            cr.move_to(x, y);
+        } else {
+            cr.line_to(x, y);
         }
+    }
 
-        loading_spinner_clone.start();
+    cr.set_source_rgba(red, green, blue, 0.8);
+    cr.set_line_width(1.5);
+    let _ = cr.stroke();
+}
 
-        let mut volumes: Vec<String> = vec![];
-        if volume_box_list_clone.is_visible() {
-            let mut index = 0;
-            let mut row = volume_box_list_clone.row_at_index(index);
-            while row.is_some() {
-                let entry_row = row
-                    .clone()
-                    .unwrap()
-                    .first_child()
-                    .unwrap()
-                    .first_child()
-                    .unwrap()
-                    .first_child()
-                    .unwrap()
-                    .downcast::<adw::EntryRow>()
-                    .unwrap();
-                let volume_arg = format!("{}:{}", entry_row.title(), entry_row.text());
-                volumes.push(volume_arg);
-                index += 1;
-                row = volume_box_list_clone.row_at_index(index);
-            }
-        }
+/// Fetches stats for all running boxes straight away, then again every
+/// `stats-refresh-interval` seconds until the tabs are rebuilt.
+fn start_stats_polling(tabs: &Notebook, stats_widgets: HashMap<String, StatsWidgets>) {
+    let settings = Settings::new(APP_ID);
+    let interval = settings.uint("stats-refresh-interval").max(1);
 
-        name = name.replace(' ', "-");
-        home_path = home_path.replace(' ', "\\ "); //Escape spaces
-        image = image.split(" - ").last().unwrap().to_string();
-        image = image.replace(" ✦ ", "");
+    let stats_widgets = Rc::new(stats_widgets);
+    let is_fetching = Rc::new(Cell::new(false));
 
-        let name_clone = name.clone();
+    fetch_box_stats(&stats_widgets, &is_fetching);
 
-        let (sender, receiver) = async_channel::bounded(1);
+    let tabs_weak = tabs.downgrade();
+    glib::timeout_add_seconds_local(interval, move || {
+        // load_boxes replaces the tabs on every rerender, so stop once these are gone
+        let Some(tabs) = tabs_weak.upgrade() else {
+            return glib::ControlFlow::Break;
+        };
+        if tabs.parent().is_none() {
+            return glib::ControlFlow::Break;
+        }
 
-        gio::spawn_blocking(move || {
-            create_box(&name, &image, &home_path, use_init, volumes.as_slice());
-            sender
-                .send_blocking(BoxCreatedMessage::Success)
-                .expect("The channel needs to be open.");
-        });
+        fetch_box_stats(&stats_widgets, &is_fetching);
+        glib::ControlFlow::Continue
+    });
+}
 
-        let b_clone = btn.clone();
-        let ls_clone = loading_spinner_clone.clone();
-        let w_clone = win_clone.clone();
+fn fetch_box_stats(
+    stats_widgets: &Rc<HashMap<String, StatsWidgets>>,
+    is_fetching: &Rc<Cell<bool>>,
+) {
+    // a slow runtime shouldn't pile up `stats` calls
+    if is_fetching.replace(true) {
+        return;
+    }
 
-        glib::spawn_future_local(clone!(
-            #[weak]
-            ls_clone,
-            async move {
-                while let Ok(msg) = receiver.recv().await {
-                    match msg {
-                        BoxCreatedMessage::Success => {
-                            ls_clone.stop();
+    let (sender, receiver) = async_channel::bounded(1);
 
-                            let win = b_clone.root().and_downcast::<gtk::Window>().unwrap();
-                            win.destroy();
+    gio::spawn_blocking(move || {
+        let stats = get_all_cpu_and_mem_usage();
+        sender
+            .send_blocking(StatsFetchMessage::StatsFetched(stats))
+            .expect("The channel needs to be open.");
+    });
 
-                            let num_boxes = get_number_of_boxes();
-                            delayed_rerender(&w_clone, Some(num_boxes - 1));
+    let stats_widgets = stats_widgets.clone();
+    let is_fetching = is_fetching.clone();
 
-                            open_terminal_in_box(name_clone.clone());
-                        }
-                    }
+    glib::spawn_future_local(async move {
+        while let Ok(msg) = receiver.recv().await {
+            match msg {
+                StatsFetchMessage::StatsFetched(stats) => {
+                    is_fetching.set(false);
+                    update_stats_widgets(&stats_widgets, &stats);
                 }
             }
-        ));
+        }
     });
-
-    boxed_list.append(&name_entry_row);
-    boxed_list.append(&image_select_row);
-    boxed_list.append(&init_row);
-
-    boxed_list.append(&home_select_row);
-
-    main_box.append(&boxed_list);
-
-    //Volumes
-    if has_host_access() {
-        let volume_box_list_clone = volume_box_list.clone();
-
-        let volume_add_btn = gtk::Button::from_icon_name("list-add-symbolic");
-        volume_add_btn.add_css_class("flat");
-        // TRANSLATORS: Button tooltip
-        volume_add_btn.set_tooltip_text(Some(&gettext("Add a volume")));
-        volume_add_btn.connect_clicked(clone!(@weak window, @weak volume_box_list_clone => move |_btn| {
-            let file_dialog = FileDialog::builder().modal(false).build();
-            file_dialog.select_folder(Some(&window), None::<&gio::Cancellable>, clone!(@weak window, @weak volume_box_list_clone => move |result| {
-                if let Ok(file) = result {
-                    let volume_path = file.path().unwrap().into_os_string().into_string().unwrap();
-
-                    // /var/home is Silverblue and pals
-                    if volume_path.starts_with("/home/") || volume_path.starts_with("/var/home/") {
-                        show_volume_is_in_user_home_popup(&window);
-                    } else {
-                        let volume_remove_btn = gtk::Button::from_icon_name("list-remove-symbolic");
-                        // TRANSLATORS: Button tooltip
-                        volume_remove_btn.set_tooltip_text(Some(&gettext("Remove volume")));
-                        volume_remove_btn.add_css_class("flat");
-                        volume_remove_btn.set_margin_top(10);
-                        volume_remove_btn.set_margin_bottom(10);
-
-                        let volume_action_row = adw::ActionRow::new();
-                        volume_action_row.add_suffix(&volume_remove_btn);
-                        volume_action_row.set_selectable(false);
-
-                        let volume_path_title = volume_path.clone().to_string();
-                        let volume_entry_row = adw::EntryRow::new();
-                        volume_entry_row.set_title(&volume_path_title);
-                        // TRANSLATORS: Help text for volume input
-                        volume_entry_row.set_tooltip_text(Some(&gettext("Enter the location to mount this folder inside your new box")));
-                        volume_entry_row.set_hexpand(true);
-                        volume_entry_row.set_width_request(600);
-                        volume_entry_row.set_text(&volume_path);
-
-                        let volume_action_row_clone = volume_action_row.clone();
-                        let volume_box_list_button_clone = volume_box_list_clone.clone();
-                        volume_remove_btn.connect_clicked(move |_btn| {
-                            volume_box_list_button_clone.remove(&volume_action_row_clone);
-                            if volume_box_list_button_clone.last_child().is_none() {
-                                volume_box_list_button_clone.set_visible(false);
-                            }
-                        });
-
-                        volume_action_row.add_prefix(&volume_entry_row);
-                        volume_box_list_clone.append(&volume_action_row);
-                        volume_box_list_clone.set_visible(true);
-                    }
-                }
-            }));
-        }));
-
-        let volume_preference_group = adw::PreferencesGroup::builder()
-            // TRANSLATORS: Subheading
-            .title(gettext("Additional Volumes:"))
-            // TRANSLATORS: Context for the Additional Volumes subheading
-            .description(gettext(
-                "Additional directories the new box should be able to access",
-            ))
-            .header_suffix(&volume_add_btn)
-            .build();
-
-        main_box.append(&volume_preference_group);
-        main_box.append(&volume_box_list);
-    }
-
-    main_box.append(&loading_spinner);
-
-    new_box_popup.set_child(Some(&main_box));
-    new_box_popup.present();
-}
-
-fn show_about_popup(window: &ApplicationWindow) {
-    let d = adw::AboutWindow::new();
-    d.set_transient_for(Some(window));
-    d.set_application_name("BoxBuddy");
-    d.set_version("2.5.6");
-    d.set_developer_name("Dvlv");
-    d.set_license_type(gtk::License::MitX11);
-    // TRANSLATORS: Description of the application
-    d.set_comments(&gettext(
-        "A Graphical Manager for your Distroboxes.
-    \nBoxBuddy is not partnered with or endorsed by any linux distributions or companies.
-    \nTrademarks, service marks, and logos are the property of their respective owners.",
-    ));
-    d.set_website("https://github.com/Dvlv/BoxBuddyRS");
-    d.set_issue_url("https://github.com/Dvlv/BoxBuddyRS/issues");
-    d.set_support_url("https://dvlv.github.io/BoxBuddyRS");
-    d.set_developers(&["Dvlv", "VortexAcherontic"]);
-    d.set_application_icon("io.github.dvlv.boxbuddyrs");
-    d.set_translator_credits(
-        "MLSci - CN
-VortexAcherontic - DE
-Pyrofanis - EL
-Sebrice - ES
-fonskip - fr_FR
-Scrambled777 - Hi
-nalbanobattistella - IT
-Luiz-C-Lima - pt_BR
-Murat-Karakaya - TR
-Vovkiv - RU and UK",
-    );
-    d.present();
 }
 
-fn on_open_terminal_clicked(box_name: String) {
-    open_terminal_in_box(box_name);
-}
+fn update_stats_widgets(
+    stats_widgets: &HashMap<String, StatsWidgets>,
+    stats: &HashMap<String, CpuMemUsage>,
+) {
+    for (box_name, widgets) in stats_widgets {
+        let Some(usage) = stats.get(box_name) else {
+            continue;
+        };
+
+        STATS_HISTORY.with(|history| {
+            history
+                .borrow_mut()
+                .entry(box_name.clone())
+                .or_default()
+                .push(parse_percentage(&usage.cpu), parse_percentage(&usage.mem));
+        });
 
-fn on_upgrade_clicked(box_name: &str) {
-    upgrade_box(box_name);
+        // TRANSLATORS: Stats Label - has the box's CPU usage appended
+        let cpu_prefix = gettext("CPU:");
+        widgets
+            .cpu_label
+            .set_text(&format!("{} {}", cpu_prefix, usage.cpu));
+        // TRANSLATORS: Stats Label - has the box's memory usage appended
+        let mem_prefix = gettext("Memory:");
+        widgets.mem_label.set_text(&format!(
+            "{} {} ({})",
+            mem_prefix, usage.mem, usage.mem_percent
+        ));
+        // TRANSLATORS: Stats Label - has data received and sent appended, e.g. "1.2MB / 300kB"
+        let net_prefix = gettext("Network:");
+        widgets
+            .net_label
+            .set_text(&format!("{} {}", net_prefix, usage.net_io));
+        // TRANSLATORS: Stats Label - has data read from and written to disk appended
+        let block_prefix = gettext("Block I/O:");
+        widgets
+            .block_label
+            .set_text(&format!("{} {}", block_prefix, usage.block_io));
+
+        widgets.cpu_graph.queue_draw();
+        widgets.mem_graph.queue_draw();
+        widgets.container.set_visible(true);
+    }
 }
 
-fn on_show_applications_clicked(window: &ApplicationWindow, box_name: String) {
-    let apps_popup = gtk::Window::builder()
-        // TRANSLATORS: Window Title - shows list of installed applications in distrobox
-        .title(gettext("Installed Applications"))
+fn show_processes_popup(window: &ApplicationWindow, box_name: String) {
+    let processes_popup = gtk::Window::builder()
+        // TRANSLATORS: Window Title - has box name appended
+        .title(format!("{} - {}", gettext("Processes"), box_name))
         .transient_for(window)
         .default_width(700)
-        .default_height(350)
+        .default_height(450)
         .modal(true)
         .build();
 
+    // TRANSLATORS: Button Label - asks the selected process to exit (SIGTERM)
+    let terminate_btn = gtk::Button::with_label(&gettext("End Process"));
+    terminate_btn.set_sensitive(false);
+
+    // TRANSLATORS: Button Label - forces the selected process to exit (SIGKILL)
+    let kill_btn = gtk::Button::with_label(&gettext("Kill Process"));
+    kill_btn.add_css_class("destructive-action");
+    kill_btn.set_sensitive(false);
+
     let titlebar = adw::HeaderBar::new();
+    titlebar.pack_start(&terminate_btn);
+    titlebar.pack_start(&kill_btn);
 
     let main_box = gtk::Box::new(Orientation::Vertical, 10);
     main_box.set_margin_start(10);
@@ -972,165 +1463,223 @@ fn on_show_applications_clicked(window: &ApplicationWindow, box_name: String) {
     main_box.set_margin_top(10);
     main_box.set_margin_bottom(10);
 
-    let loading_spinner = gtk::Spinner::new();
-
     // TRANSLATORS: Loading Message
-    let loading_lbl = gtk::Label::new(Some(&gettext("Loading...")));
-    loading_lbl.add_css_class("title-2");
+    let placeholder_lbl = gtk::Label::new(Some(&gettext("Loading...")));
+    placeholder_lbl.add_css_class("title-2");
+    placeholder_lbl.set_margin_top(10);
+    placeholder_lbl.set_margin_bottom(10);
+
+    let processes_list = gtk::ListBox::new();
+    processes_list.set_selection_mode(gtk::SelectionMode::Single);
+    processes_list.add_css_class("boxed-list");
+    processes_list.set_valign(Align::Start);
+    processes_list.set_placeholder(Some(&placeholder_lbl));
 
     let scrolled_win = gtk::ScrolledWindow::new();
     scrolled_win.set_vexpand(true);
     scrolled_win.set_hexpand(true);
+    scrolled_win.set_child(Some(&processes_list));
 
-    let scroll_area = gtk::Box::new(gtk::Orientation::Vertical, 15);
-    scroll_area.set_vexpand(true);
-    scroll_area.set_hexpand(true);
-
-    scroll_area.append(&loading_lbl);
-    scroll_area.append(&loading_spinner);
+    main_box.append(&scrolled_win);
 
-    scrolled_win.set_child(Some(&scroll_area));
+    let toast_overlay = ToastOverlay::new();
+    toast_overlay.set_child(Some(&main_box));
 
-    main_box.append(&scrolled_win);
+    processes_popup.set_child(Some(&toast_overlay));
+    processes_popup.set_titlebar(Some(&titlebar));
+    processes_popup.present();
 
-    apps_popup.set_child(Some(&main_box));
-    apps_popup.set_titlebar(Some(&titlebar));
-    loading_spinner.start();
-    apps_popup.present();
-    apps_popup.queue_draw();
+    processes_list.connect_row_selected(clone!(
+        #[weak]
+        terminate_btn,
+        #[weak]
+        kill_btn,
+        move |_list, row| {
+            terminate_btn.set_sensitive(row.is_some());
+            kill_btn.set_sensitive(row.is_some());
+        }
+    ));
 
-    let (sender, receiver) = async_channel::bounded(1);
-    let box_name_clone = box_name.clone();
+    let processes: Rc<RefCell<Vec<BoxProcess>>> = Rc::default();
+    let is_fetching = Rc::new(Cell::new(false));
 
-    gio::spawn_blocking(move || {
-        let apps = get_apps_in_box(&box_name_clone);
-        let binaries = get_binaries_exported_from_box(&box_name_clone);
+    for (btn, signal) in [
+        (&terminate_btn, ProcessSignal::Term),
+        (&kill_btn, ProcessSignal::Kill),
+    ] {
+        btn.connect_clicked(clone!(
+            #[weak]
+            processes_list,
+            #[weak]
+            placeholder_lbl,
+            #[strong]
+            processes,
+            #[strong]
+            is_fetching,
+            #[weak]
+            toast_overlay,
+            #[strong]
+            box_name,
+            move |_btn| {
+                let Some(row) = processes_list.selected_row() else {
+                    return;
+                };
+                let Ok(row_idx) = usize::try_from(row.index()) else {
+                    return;
+                };
+                let Some(process) = processes.borrow().get(row_idx).cloned() else {
+                    return;
+                };
+
+                let (sender, receiver) = async_channel::bounded(1);
+                let kill_box_name = box_name.clone();
+
+                gio::spawn_blocking(move || {
+                    let result = kill_process_in_box(&kill_box_name, &process.pid, signal);
+                    sender
+                        .send_blocking(ProcessKillMessage::Finished(result))
+                        .expect("The channel needs to be open.");
+                });
+
+                glib::spawn_future_local(clone!(
+                    #[weak]
+                    processes_list,
+                    #[weak]
+                    placeholder_lbl,
+                    #[weak]
+                    toast_overlay,
+                    #[strong]
+                    processes,
+                    #[strong]
+                    is_fetching,
+                    #[strong]
+                    box_name,
+                    async move {
+                        while let Ok(msg) = receiver.recv().await {
+                            match msg {
+                                ProcessKillMessage::Finished(result) => {
+                                    if let Err(error) = result {
+                                        // the runtime's actual complaint is on the last line
+                                        let error = error.lines().last().unwrap_or_default();
+                                        let toast = adw::Toast::new(&markup_escape_text(error));
+                                        toast.set_timeout(0);
+                                        toast_overlay.add_toast(toast);
+                                    }
+
+                                    fetch_box_processes(
+                                        &box_name,
+                                        &processes_list,
+                                        &placeholder_lbl,
+                                        &processes,
+                                        &is_fetching,
+                                    );
+                                }
+                            }
+                        }
+                    }
+                ));
+            }
+        ));
+    }
+
+    fetch_box_processes(
+        &box_name,
+        &processes_list,
+        &placeholder_lbl,
+        &processes,
+        &is_fetching,
+    );
+
+    let settings = Settings::new(APP_ID);
+    let interval = settings.uint("stats-refresh-interval").max(1);
+    let popup_weak = processes_popup.downgrade();
+
+    glib::timeout_add_seconds_local(interval, move || {
+        let Some(popup) = popup_weak.upgrade() else {
+            return glib::ControlFlow::Break;
+        };
+        if !popup.is_visible() {
+            return glib::ControlFlow::Break;
+        }
+
+        fetch_box_processes(
+            &box_name,
+            &processes_list,
+            &placeholder_lbl,
+            &processes,
+            &is_fetching,
+        );
+        glib::ControlFlow::Continue
+    });
+}
+
+/// Fetches the processes in a box in the background and refills the list,
+/// keeping the same process selected if it is still running.
+fn fetch_box_processes(
+    box_name: &str,
+    processes_list: &gtk::ListBox,
+    placeholder_lbl: &gtk::Label,
+    processes: &Rc<RefCell<Vec<BoxProcess>>>,
+    is_fetching: &Rc<Cell<bool>>,
+) {
+    if is_fetching.replace(true) {
+        return;
+    }
+
+    let (sender, receiver) = async_channel::bounded(1);
+    let box_name = box_name.to_string();
+
+    gio::spawn_blocking(move || {
+        let box_processes = get_box_processes(&box_name);
         sender
-            .send_blocking(AppsFetchMessage::AppsFetched(apps, binaries))
+            .send_blocking(ProcessesFetchMessage::ProcessesFetched(box_processes))
             .expect("The channel needs to be open.");
     });
 
+    let processes = processes.clone();
+    let is_fetching = is_fetching.clone();
+
     glib::spawn_future_local(clone!(
         #[weak]
-        scroll_area,
+        processes_list,
+        #[weak]
+        placeholder_lbl,
         async move {
             while let Ok(msg) = receiver.recv().await {
                 match msg {
-                    AppsFetchMessage::AppsFetched(apps, binaries) => {
-                        loading_spinner.stop();
-                        scroll_area.remove(&loading_spinner);
-
-                        if apps.is_empty() {
-                            //TRANSLATORS: Error Message
-                            let no_apps_lbl =
-                                gtk::Label::new(Some(&gettext("No Applications Installed")));
-                            no_apps_lbl.add_css_class("title-2");
-                            scroll_area.append(&no_apps_lbl);
-                        } else {
-                            //TRANSLATORS: Window Title
-                            loading_lbl.set_text(&gettext("Available Applications"));
-
-                            let boxed_list = gtk::ListBox::new();
-                            boxed_list.set_selection_mode(gtk::SelectionMode::None);
-                            boxed_list.add_css_class("boxed-list");
-
-                            for app in apps {
-                                let row = adw::ActionRow::new();
-                                row.set_title(&markup_escape_text(&app.name.to_string()));
-
-                                let img = gtk::Image::from_icon_name(&app.icon);
-
-                                //TRANSLATORS: Button Label
-                                let run_btn = gtk::Button::with_label(&gettext("Run"));
-                                run_btn.add_css_class("pill");
-                                run_btn.set_width_request(100);
-                                let box_name_clone = box_name.clone();
-                                let app_clone = app.clone();
-                                run_btn.connect_clicked(move |_btn| {
-                                    run_app_in_box(&app_clone, &box_name_clone);
-                                });
-
-                                row.add_prefix(&img);
-                                row.add_suffix(&run_btn);
-                                row.add_suffix(&gtk::Separator::new(gtk::Orientation::Horizontal));
-
-                                if app.is_on_host {
-                                    let remove_from_menu_btn =
-                                //TRANSLATORS: Button Label
-                                gtk::Button::with_label(&gettext("Remove From Menu"));
-                                    remove_from_menu_btn.add_css_class("pill");
-                                    remove_from_menu_btn.set_width_request(200);
-
-                                    let box_name_clone = box_name.clone();
-                                    let loading_lbl_clone = loading_lbl.clone();
-                                    let app_clone = app.clone();
-                                    remove_from_menu_btn.connect_clicked(move |_btn| {
-                                        remove_app_from_menu(
-                                            &app_clone,
-                                            &box_name_clone,
-                                            &loading_lbl_clone.clone(),
-                                        );
-                                    });
-                                    row.add_suffix(&remove_from_menu_btn);
-                                } else {
-                                    //TRANSLATORS: Button Label
-                                    let add_menu_btn =
-                                        gtk::Button::with_label(&gettext("Add To Menu"));
-                                    add_menu_btn.add_css_class("pill");
-                                    add_menu_btn.set_width_request(200);
-
-                                    let box_name_clone = box_name.clone();
-                                    let loading_lbl_clone = loading_lbl.clone();
-                                    let app_clone = app.clone();
-                                    add_menu_btn.connect_clicked(move |_btn| {
-                                        add_app_to_menu(
-                                            &app_clone,
-                                            &box_name_clone,
-                                            &loading_lbl_clone.clone(),
-                                        );
-                                    });
-                                    row.add_suffix(&add_menu_btn);
-                                }
-
-                                boxed_list.append(&row);
-                                scroll_area.append(&boxed_list);
+                    ProcessesFetchMessage::ProcessesFetched(box_processes) => {
+                        is_fetching.set(false);
+
+                        let selected_pid = processes_list
+                            .selected_row()
+                            .and_then(|r| usize::try_from(r.index()).ok())
+                            .and_then(|idx| processes.borrow().get(idx).map(|p| p.pid.clone()));
+
+                        processes_list.remove_all();
+
+                        for process in &box_processes {
+                            let row = adw::ActionRow::new();
+                            row.set_title(&markup_escape_text(&process.command));
+                            row.set_subtitle(&markup_escape_text(&format!(
+                                "PID {} · {} · CPU {}% · {} {}%",
+                                process.pid,
+                                process.user,
+                                process.cpu,
+                                // TRANSLATORS: Label before a process's memory usage
+                                gettext("Memory"),
+                                process.mem
+                            )));
+                            processes_list.append(&row);
+
+                            if selected_pid.as_ref() == Some(&process.pid) {
+                                processes_list.select_row(Some(&row));
                             }
                         }
-                        if binaries.is_empty() {
-                            //TRANSLATORS: Error Message
-                            let no_binaries_lbl =
-                                gtk::Label::new(Some(&gettext("No Binaries Exported")));
-                            no_binaries_lbl.add_css_class("title-2");
-                            scroll_area.append(&no_binaries_lbl);
-                        } else {
-                            let bin_boxed_list = gtk::ListBox::new();
-                            bin_boxed_list.set_selection_mode(gtk::SelectionMode::None);
-                            bin_boxed_list.add_css_class("boxed-list");
 
-                            let bin_title = gtk::Label::new(Some(&gettext("Exported Binaries")));
-                            bin_title.add_css_class("title-2");
+                        //TRANSLATORS: Info Message
+                        placeholder_lbl.set_text(&gettext("No Processes Running"));
 
-                            for binary in binaries {
-                                let row = adw::ActionRow::new();
-                                row.set_title(&markup_escape_text(&binary.to_string()));
-                                bin_boxed_list.append(&row);
-
-                                // TRANSLATORS: Button Text
-                                let remove_btn = gtk::Button::with_label(&gettext("Remove"));
-                                remove_btn.add_css_class("pill");
-                                //remove_btn.set_width_request(200);
-
-                                let box_name_clone = box_name.clone();
-                                let row_clone = row.clone();
-                                remove_btn.connect_clicked(move |btn| {
-                                    remove_exported_binary(&box_name_clone, &binary, &row_clone);
-                                    btn.set_sensitive(false);
-                                });
-                                row.add_suffix(&remove_btn);
-                            }
-                            scroll_area.append(&bin_title);
-                            scroll_area.append(&bin_boxed_list);
-                        }
+                        *processes.borrow_mut() = box_processes;
                     }
                 }
             }
@@ -1138,195 +1687,3810 @@ fn on_show_applications_clicked(window: &ApplicationWindow, box_name: String) {
     ));
 }
 
-fn add_app_to_menu(app: &DBoxApp, box_name: &str, success_lbl: &gtk::Label) {
-    let _ = export_app_from_box(&app.name, box_name);
-    //TRANSLATORS: Success Message
-    success_lbl.set_text(&gettext("App Exported!"));
-}
-
-fn remove_app_from_menu(app: &DBoxApp, box_name: &str, success_lbl: &gtk::Label) {
-    let _ = remove_app_from_host(&app.name, box_name);
-    //TRANSLATORS: Success Message
-    success_lbl.set_text(&gettext("App Removed!"));
-}
-
-fn remove_exported_binary(box_name: &str, binary: &str, row: &adw::ActionRow) {
-    remove_exported_binary_from_box(&box_name, &binary);
-    row.set_title("Removed!");
-}
-
-fn run_app_in_box(app: &DBoxApp, box_name: &str) {
-    run_command_in_box(&app.exec_name, box_name);
-}
-
-fn on_delete_clicked(window: &ApplicationWindow, box_name: String) {
-    let d = adw::MessageDialog::new(
-        Some(window),
-        //TRANSLATORS: Confirmation Dialogue
-        Some(&gettext("Really Delete?")),
-        //TRANSLATORS: Confirmation Dialogue - {} replaced with the name of the Distrobox
-        Some(&gettext(&format!(
-            "Are you sure you want to delete {}?",
-            box_name
-        ))),
-    );
-    d.set_transient_for(Some(window));
-    //TRANSLATORS: Button Label
-    d.add_response("cancel", &gettext("Cancel"));
-    //TRANSLATORS: Button Label
-    d.add_response("delete", &gettext("Delete"));
-    d.set_default_response(Some("cancel"));
-    d.set_close_response("cancel");
-    d.set_response_appearance("delete", adw::ResponseAppearance::Destructive);
-
-    let win_clone = window.clone();
-
-    d.connect_response(None, move |d, res| {
-        if res == "delete" {
-            delete_box(&box_name);
-            d.destroy();
-
-            //TRANSLATORS: Success Text
-            let toast = adw::Toast::new(&gettext("Box Deleted!"));
-            if let Some(child) = win_clone.clone().child() {
-                let toast_area = child.downcast::<ToastOverlay>();
-                toast_area.unwrap().add_toast(toast);
-            }
-
-            delayed_rerender(&win_clone, None);
-        }
-    });
-
-    d.present();
-}
-
-fn on_clone_clicked(window: &ApplicationWindow, box_name: String) {
-    let name_input_popup = gtk::Window::builder()
+fn assemble_new_distrobox(window: &ApplicationWindow, ini_file: String) {
+    let assemble_box_popup = gtk::Window::builder()
+        // TRANSLATORS: Popup Window Title
+        .title(gettext("Create New Distrobox"))
         .transient_for(window)
         .default_width(700)
-        .default_height(250)
+        .default_height(350)
         .modal(true)
         .build();
 
-    // TRANSLATORS: Heading Label - has box name appended
-    let clone_prefix = &gettext("Clone");
-    name_input_popup.set_title(Some(&format!("{} {}", clone_prefix, box_name.clone())));
-
     // TRANSLATORS: Button Label
-    let create_btn = gtk::Button::with_label(&gettext("Clone"));
-    create_btn.add_css_class("suggested-action");
+    let assemble_btn = gtk::Button::with_label(&gettext("Assemble"));
+    assemble_btn.add_css_class("suggested-action");
 
     // TRANSLATORS: Button Label
     let cancel_btn = gtk::Button::with_label(&gettext("Cancel"));
+    cancel_btn.connect_clicked(clone!(
+        #[weak]
+        assemble_box_popup,
+        move |_btn| assemble_box_popup.destroy()
+    ));
 
-    cancel_btn.connect_clicked(move |btn| {
-        let win = btn.root().and_downcast::<gtk::Window>().unwrap();
-        win.destroy();
-    });
+    let assemble_box_titlebar = adw::HeaderBar::new();
+    assemble_box_titlebar.set_show_end_title_buttons(false);
+    assemble_box_titlebar.pack_start(&cancel_btn);
+    assemble_box_titlebar.pack_end(&assemble_btn);
+    assemble_box_popup.set_titlebar(Some(&assemble_box_titlebar));
 
-    let new_box_titlebar = adw::HeaderBar::new();
-    new_box_titlebar.set_show_end_title_buttons(false);
+    // TRANSLATORS: Explanation in the Assemble pop-up, followed by a file path
+    let file_lbl = gtk::Label::new(Some(&format!(
+        "{} {}",
+        gettext("Create every box described in"),
+        ini_file
+    )));
+    file_lbl.set_wrap(true);
 
-    new_box_titlebar.pack_end(&create_btn);
-    new_box_titlebar.pack_start(&cancel_btn);
+    let boxed_list = gtk::ListBox::new();
+    boxed_list.set_selection_mode(gtk::SelectionMode::None);
+    boxed_list.add_css_class("boxed-list");
 
-    name_input_popup.set_titlebar(Some(&new_box_titlebar));
+    let nvidia_row = make_nvidia_row();
+    boxed_list.append(&nvidia_row);
 
-    let main_box = gtk::Box::new(Orientation::Vertical, 20);
+    // TRANSLATORS: Explanation of the NVIDIA Drivers dropdown in the Assemble pop-up
+    let nvidia_lbl = gtk::Label::new(Some(&gettext(
+        "On and Off apply to every box in the file. Automatic leaves boxes which set nvidia alone.",
+    )));
+    nvidia_lbl.add_css_class("dim-label");
+    nvidia_lbl.set_wrap(true);
+
+    // TRANSLATORS: Context label of the application doing something
+    let assemble_lbl = gtk::Label::new(Some(&gettext("Assembling Distroboxes, please wait...")));
+    assemble_lbl.set_visible(false);
+
+    //Loading spinner
+    let loading_spinner = gtk::Spinner::new();
+
+    let main_box = gtk::Box::new(Orientation::Vertical, 10);
     main_box.set_margin_start(10);
     main_box.set_margin_end(10);
     main_box.set_margin_top(10);
     main_box.set_margin_bottom(10);
 
-    //TRANSLATORS: Title / Instruction label
-    let title_label = gtk::Label::new(Some(&gettext("Enter the name of your new box")));
-    title_label.add_css_class("title-2");
+    main_box.append(&file_lbl);
+    main_box.append(&boxed_list);
+    main_box.append(&nvidia_lbl);
+    main_box.append(&assemble_lbl);
+    main_box.append(&loading_spinner);
 
-    let notice_label = gtk::Label::new(Some(&gettext(
-        "Note: Cloning can take a long time, please be patient",
-    )));
+    assemble_box_popup.set_child(Some(&main_box));
+    assemble_box_popup.present();
 
-    let boxed_list = gtk::ListBox::new();
-    boxed_list.set_selection_mode(gtk::SelectionMode::None);
-    boxed_list.add_css_class("boxed-list");
+    let w_clone = window.clone();
+    assemble_btn.connect_clicked(clone!(
+        #[weak]
+        assemble_box_popup,
+        #[weak]
+        boxed_list,
+        #[weak]
+        nvidia_lbl,
+        #[weak]
+        assemble_lbl,
+        #[weak]
+        loading_spinner,
+        #[weak]
+        cancel_btn,
+        move |btn| {
+            btn.set_sensitive(false);
+            cancel_btn.set_sensitive(false);
+            boxed_list.set_sensitive(false);
+            nvidia_lbl.set_visible(false);
+            assemble_lbl.set_visible(true);
+            loading_spinner.start();
+
+            let nvidia = get_chosen_nvidia_mode(&nvidia_row);
+            let ini_file = ini_file.clone();
+            let (sender, receiver) = async_channel::bounded(1);
+
+            gio::spawn_blocking(move || {
+                assemble_box(&ini_file, nvidia);
+                sender
+                    .send_blocking(BoxCreatedMessage::Success)
+                    .expect("The channel needs to be open.");
+            });
+
+            let w_clone = w_clone.clone();
+            glib::spawn_future_local(clone!(
+                #[weak]
+                assemble_box_popup,
+                async move {
+                    while let Ok(msg) = receiver.recv().await {
+                        match msg {
+                            BoxCreatedMessage::Success => {
+                                loading_spinner.stop();
+
+                                let num_boxes = get_number_of_boxes();
+                                delayed_rerender(&w_clone, Some(num_boxes - 1));
+                                assemble_box_popup.destroy();
+                            }
+                        }
+                    }
+                }
+            ));
+        }
+    ));
+}
 
-    // name input
-    let name_entry_row = adw::EntryRow::new();
-    name_entry_row.set_hexpand(true);
+// callbacks
+/// Adds a volume to the Additional Volumes list of the create pop-up.
+/// The row's title is the host path and its text is where it goes inside the box.
+fn append_volume_row(volume_box_list: &gtk::ListBox, host_path: &str, box_path: &str) {
+    let volume_remove_btn = gtk::Button::from_icon_name("list-remove-symbolic");
+    // TRANSLATORS: Button tooltip
+    volume_remove_btn.set_tooltip_text(Some(&gettext("Remove volume")));
+    volume_remove_btn.add_css_class("flat");
+    volume_remove_btn.set_margin_top(10);
+    volume_remove_btn.set_margin_bottom(10);
+
+    let volume_action_row = adw::ActionRow::new();
+    volume_action_row.add_suffix(&volume_remove_btn);
+    volume_action_row.set_selectable(false);
+
+    let volume_entry_row = adw::EntryRow::new();
+    volume_entry_row.set_title(host_path);
+    // TRANSLATORS: Help text for volume input
+    volume_entry_row.set_tooltip_text(Some(&gettext(
+        "Enter the location to mount this folder inside your new box",
+    )));
+    volume_entry_row.set_hexpand(true);
+    volume_entry_row.set_width_request(600);
+
+    let volume_error_icon = gtk::Image::from_icon_name("dialog-warning-symbolic");
+    volume_error_icon.add_css_class("error");
+    volume_error_icon.set_visible(false);
+    volume_entry_row.add_suffix(&volume_error_icon);
+
+    let host_path_clone = host_path.to_string();
+    volume_entry_row.connect_changed(move |row| {
+        match format_volume_arg(&host_path_clone, row.text().trim()) {
+            Ok(_) => {
+                row.remove_css_class("error");
+                volume_error_icon.set_visible(false);
+            }
+            Err(e) => {
+                row.add_css_class("error");
+                volume_error_icon.set_tooltip_text(Some(&describe_volume_arg_error(e)));
+                volume_error_icon.set_visible(true);
+            }
+        }
+    });
+    volume_entry_row.set_text(box_path);
 
-    // TRANSLATORS: Entry Label - Name input for new distrobox
-    name_entry_row.set_title(&gettext("Name"));
+    let volume_action_row_clone = volume_action_row.clone();
+    let volume_box_list_clone = volume_box_list.clone();
+    volume_remove_btn.connect_clicked(move |_btn| {
+        volume_box_list_clone.remove(&volume_action_row_clone);
+        if volume_box_list_clone.last_child().is_none() {
+            volume_box_list_clone.set_visible(false);
+        }
+    });
 
-    let loading_spinner = gtk::Spinner::new();
+    volume_action_row.add_prefix(&volume_entry_row);
+    volume_box_list.append(&volume_action_row);
+    volume_box_list.set_visible(true);
+}
 
-    let loading_spinner_clone = loading_spinner.clone();
-    let win_clone = window.clone();
-    let ne_row = name_entry_row.clone();
-    create_btn.connect_clicked(move |btn| {
-        loading_spinner_clone.start();
-        let mut name = ne_row.text().to_string();
+/// Builds the Preset row of the create pop-up, for filling in the form from
+/// a saved preset and saving the current form as one
+fn make_preset_row(popup: &gtk::Window, form: &CreateFormWidgets) -> adw::ActionRow {
+    let presets = Rc::new(RefCell::new(read_presets()));
 
-        if name.is_empty() {
-            return;
-        }
+    let preset_strlist = gtk::StringList::new(&[]);
+    fill_preset_list(&preset_strlist, &presets.borrow());
 
-        name = name.replace(' ', "-");
-        let name_clone = name.clone();
-        let bn = box_name.clone();
+    let preset_select = gtk::DropDown::new(Some(preset_strlist.clone()), None::<gtk::Expression>);
+    preset_select.set_valign(Align::Center);
 
-        let (sender, receiver) = async_channel::bounded(1);
+    let save_btn = gtk::Button::from_icon_name("document-save-symbolic");
+    save_btn.set_valign(Align::Center);
+    // TRANSLATORS: Button tooltip
+    save_btn.set_tooltip_text(Some(&gettext("Save as Preset")));
 
-        gio::spawn_blocking(move || {
-            clone_box(&bn, &name);
-            sender
-                .send_blocking(BoxCreatedMessage::Success)
-                .expect("The channel needs to be open.");
-        });
+    let delete_btn = gtk::Button::from_icon_name("user-trash-symbolic");
+    delete_btn.set_valign(Align::Center);
+    // TRANSLATORS: Button tooltip
+    delete_btn.set_tooltip_text(Some(&gettext("Delete Preset")));
+    delete_btn.set_sensitive(false);
+
+    let preset_row = adw::ActionRow::new();
+    // TRANSLATORS: Label for Dropdown of saved sets of options for a new box
+    preset_row.set_title(&gettext("Preset"));
+    preset_row.set_subtitle(&markup_escape_text(&format!(
+        "{} {}",
+        // TRANSLATORS: Followed by the path of the presets file
+        gettext("Saved in"),
+        get_presets_path().display()
+    )));
+    preset_row.add_suffix(&preset_select);
+    preset_row.add_suffix(&save_btn);
+    preset_row.add_suffix(&delete_btn);
+
+    preset_select.connect_selected_notify(clone!(
+        #[strong]
+        presets,
+        #[strong]
+        form,
+        #[weak]
+        delete_btn,
+        move |dd| {
+            let preset_idx = dd.selected() as usize;
+            delete_btn.set_sensitive(preset_idx > 0);
+
+            // the first item is "None"
+            if let Some(preset) = presets.borrow().get(preset_idx.wrapping_sub(1)) {
+                form.load(&preset.options);
+            }
+        }
+    ));
 
-        let b_clone = btn.clone();
-        let ls_clone = loading_spinner_clone.clone();
-        let w_clone = win_clone.clone();
+    save_btn.connect_clicked(clone!(
+        #[weak]
+        popup,
+        #[strong]
+        presets,
+        #[strong]
+        form,
+        #[weak]
+        preset_strlist,
+        #[weak]
+        preset_select,
+        move |_btn| {
+            let current_name = preset_select
+                .selected_item()
+                .and_downcast::<gtk::StringObject>()
+                .filter(|_| preset_select.selected() > 0)
+                .map(|s| s.string().to_string())
+                .unwrap_or_default();
+
+            let d = adw::MessageDialog::new(
+                Some(&popup),
+                // TRANSLATORS: Dialogue heading
+                Some(&gettext("Save as Preset")),
+                // TRANSLATORS: Dialogue body
+                Some(&gettext(
+                    "Everything in this window apart from the box's name is saved. A preset with the same name is replaced.",
+                )),
+            );
+
+            let name_entry = gtk::Entry::new();
+            // TRANSLATORS: Placeholder text - the name of the new preset
+            name_entry.set_placeholder_text(Some(&gettext("Preset Name")));
+            name_entry.set_text(&current_name);
+            d.set_extra_child(Some(&name_entry));
+
+            //TRANSLATORS: Button Label
+            d.add_response("cancel", &gettext("Cancel"));
+            //TRANSLATORS: Button Label
+            d.add_response("save", &gettext("Save"));
+            d.set_default_response(Some("save"));
+            d.set_close_response("cancel");
+            d.set_response_appearance("save", adw::ResponseAppearance::Suggested);
+            d.set_response_enabled("save", !current_name.is_empty());
+
+            name_entry.connect_changed(clone!(
+                #[weak]
+                d,
+                move |entry| {
+                    d.set_response_enabled("save", !entry.text().trim().is_empty());
+                }
+            ));
 
-        glib::spawn_future_local(clone!(
-            #[weak]
-            ls_clone,
-            async move {
-                while let Ok(msg) = receiver.recv().await {
-                    match msg {
-                        BoxCreatedMessage::Success => {
-                            ls_clone.stop();
+            let presets = presets.clone();
+            let form = form.clone();
+            d.connect_response(None, move |d, res| {
+                if res != "save" {
+                    return;
+                }
 
-                            let win = b_clone.root().and_downcast::<gtk::Window>().unwrap();
-                            win.destroy();
+                let options = match form.collect() {
+                    Ok(options) => options,
+                    Err(e) => {
+                        show_preset_error_popup(&popup, &e);
+                        return;
+                    }
+                };
 
-                            let num_boxes = get_number_of_boxes();
-                            delayed_rerender(&w_clone, Some(num_boxes - 1));
+                let preset = CreatePreset {
+                    name: name_entry.text().trim().to_string(),
+                    options,
+                };
 
-                            open_terminal_in_box(name_clone.clone());
-                        }
-                    }
+                if let Err(e) = save_preset(&preset) {
+                    show_preset_error_popup(&popup, &e.to_string());
+                    return;
                 }
-            }
-        ));
-    });
 
-    boxed_list.append(&name_entry_row);
-    main_box.append(&title_label);
-    main_box.append(&boxed_list);
-    main_box.append(&notice_label);
-    main_box.append(&loading_spinner);
+                *presets.borrow_mut() = read_presets();
+                fill_preset_list(&preset_strlist, &presets.borrow());
 
-    name_input_popup.set_child(Some(&main_box));
+                let saved_idx = presets.borrow().iter().position(|p| p.name == preset.name);
+                if let Some(idx) = saved_idx {
+                    preset_select.set_selected(idx as u32 + 1);
+                }
 
-    name_input_popup.present();
-}
+                d.destroy();
+            });
 
-fn delayed_rerender(window: &ApplicationWindow, active_page: Option<u32>) {
-    let main_box = window.child().unwrap().first_child().unwrap();
-    let main_box_as_box = main_box.downcast::<gtk::Box>().unwrap();
+            d.present();
+        }
+    ));
 
-    load_boxes(&main_box_as_box, window, active_page);
-}
+    delete_btn.connect_clicked(clone!(
+        #[weak]
+        popup,
+        #[strong]
+        presets,
+        #[weak]
+        preset_strlist,
+        #[weak]
+        preset_select,
+        move |_btn| {
+            let preset_idx = preset_select.selected() as usize;
+            let Some(preset_name) = presets
+                .borrow()
+                .get(preset_idx.wrapping_sub(1))
+                .map(|p| p.name.clone())
+            else {
+                return;
+            };
+
+            let d = adw::MessageDialog::new(
+                Some(&popup),
+                //TRANSLATORS: Confirmation Dialogue
+                Some(&gettext("Really Delete?")),
+                //TRANSLATORS: Confirmation Dialogue - followed by the name of the preset
+                Some(&format!(
+                    "{} {}",
+                    gettext("Are you sure you want to delete the preset"),
+                    preset_name
+                )),
+            );
+            //TRANSLATORS: Button Label
+            d.add_response("cancel", &gettext("Cancel"));
+            //TRANSLATORS: Button Label
+            d.add_response("delete", &gettext("Delete"));
+            d.set_default_response(Some("cancel"));
+            d.set_close_response("cancel");
+            d.set_response_appearance("delete", adw::ResponseAppearance::Destructive);
+
+            let presets = presets.clone();
+            d.connect_response(None, move |_d, res| {
+                if res != "delete" {
+                    return;
+                }
+
+                if let Err(e) = delete_preset(&preset_name) {
+                    show_preset_error_popup(&popup, &e.to_string());
+                    return;
+                }
+
+                *presets.borrow_mut() = read_presets();
+                fill_preset_list(&preset_strlist, &presets.borrow());
+                preset_select.set_selected(0);
+            });
+
+            d.present();
+        }
+    ));
+
+    preset_row
+}
+
+/// Lists the presets' names after a "None" item, for the Preset dropdown
+fn fill_preset_list(preset_strlist: &gtk::StringList, presets: &[CreatePreset]) {
+    // TRANSLATORS: Dropdown option - don't use a preset
+    let none = gettext("None");
+    let mut names: Vec<&str> = vec![&none];
+    names.extend(presets.iter().map(|p| p.name.as_str()));
+
+    preset_strlist.splice(0, preset_strlist.n_items(), &names);
+}
+
+fn show_preset_error_popup(popup: &gtk::Window, error: &str) {
+    let d = adw::MessageDialog::new(
+        Some(popup),
+        //TRANSLATORS: Error Message
+        Some(&gettext("Couldn't update the presets file")),
+        Some(error),
+    );
+    //TRANSLATORS: Button Label
+    d.add_response("ok", &gettext("Ok"));
+    d.present();
+}
+
+/// Builds the Advanced section of the create pop-up, collapsed
+fn make_advanced_create_rows() -> (adw::ExpanderRow, AdvancedCreateRows) {
+    let advanced_row = adw::ExpanderRow::new();
+    // TRANSLATORS: Expander Label - less common options for a new box
+    advanced_row.set_title(&gettext("Advanced"));
+
+    let nvidia = make_nvidia_row();
+
+    let hostname = adw::EntryRow::new();
+    // TRANSLATORS: Entry Label
+    hostname.set_title(&gettext("Hostname (Leave blank for default)"));
+
+    let additional_packages = adw::EntryRow::new();
+    // TRANSLATORS: Entry Label
+    additional_packages.set_title(&gettext(
+        "Additional Packages to Install (separated by spaces)",
+    ));
+
+    let additional_flags = adw::EntryRow::new();
+    // TRANSLATORS: Entry Label - flags such as --env passed to podman or docker
+    additional_flags.set_title(&gettext("Additional Container Manager Flags"));
+
+    let pre_init_hooks = adw::EntryRow::new();
+    // TRANSLATORS: Entry Label - shell commands
+    pre_init_hooks.set_title(&gettext("Commands to Run Before Setting Up the Box"));
+
+    let init_hooks = adw::EntryRow::new();
+    // TRANSLATORS: Entry Label - shell commands
+    init_hooks.set_title(&gettext("Commands to Run After Setting Up the Box"));
+
+    let unshare_netns = adw::SwitchRow::new();
+    // TRANSLATORS: Toggle Label
+    unshare_netns.set_title(&gettext("Separate Network"));
+    // TRANSLATORS: Explanation of the Separate Network toggle
+    unshare_netns.set_subtitle(&gettext(
+        "Give the box its own network instead of sharing the host's",
+    ));
+
+    let unshare_ipc = adw::SwitchRow::new();
+    // TRANSLATORS: Toggle Label - IPC is inter-process communication
+    unshare_ipc.set_title(&gettext("Separate IPC"));
+    // TRANSLATORS: Explanation of the Separate IPC toggle
+    unshare_ipc.set_subtitle(&gettext(
+        "Stop programs in the box sharing memory and message queues with the host",
+    ));
+
+    let unshare_all = adw::SwitchRow::new();
+    // TRANSLATORS: Toggle Label
+    unshare_all.set_title(&gettext("Separate Everything"));
+    // TRANSLATORS: Explanation of the Separate Everything toggle
+    unshare_all.set_subtitle(&gettext(
+        "Also separate devices, processes and groups from the host",
+    ));
+
+    // separating everything includes the network and IPC
+    unshare_all.connect_active_notify(clone!(
+        #[weak]
+        unshare_netns,
+        #[weak]
+        unshare_ipc,
+        move |row| {
+            let is_all = row.is_active();
+            for unshare_row in [&unshare_netns, &unshare_ipc] {
+                if is_all {
+                    unshare_row.set_active(true);
+                }
+                unshare_row.set_sensitive(!is_all);
+            }
+        }
+    ));
+
+    let pull = adw::SwitchRow::new();
+    // TRANSLATORS: Toggle Label
+    pull.set_title(&gettext("Always Pull Image"));
+    // TRANSLATORS: Explanation of the Always Pull Image toggle
+    pull.set_subtitle(&gettext(
+        "Download the newest version of the image even if it has already been downloaded",
+    ));
+
+    let add_to_menu = adw::SwitchRow::new();
+    // TRANSLATORS: Toggle Label
+    add_to_menu.set_title(&gettext("Add Box to Applications Menu"));
+    add_to_menu.set_active(true);
+
+    advanced_row.add_row(&nvidia);
+    advanced_row.add_row(&hostname);
+    advanced_row.add_row(&additional_packages);
+    advanced_row.add_row(&additional_flags);
+    advanced_row.add_row(&pre_init_hooks);
+    advanced_row.add_row(&init_hooks);
+    advanced_row.add_row(&unshare_netns);
+    advanced_row.add_row(&unshare_ipc);
+    advanced_row.add_row(&unshare_all);
+    advanced_row.add_row(&pull);
+    advanced_row.add_row(&add_to_menu);
+
+    (
+        advanced_row,
+        AdvancedCreateRows {
+            nvidia,
+            hostname,
+            additional_packages,
+            additional_flags,
+            init_hooks,
+            pre_init_hooks,
+            unshare_netns,
+            unshare_ipc,
+            unshare_all,
+            pull,
+            add_to_menu,
+        },
+    )
+}
+
+/// Builds the Automatic/On/Off dropdown for sharing the NVIDIA driver with
+/// new boxes. What was detected is explained once the host has been checked
+/// in the background.
+fn make_nvidia_row() -> adw::ComboRow {
+    let nvidia = adw::ComboRow::new();
+    // TRANSLATORS: Dropdown Label - whether to share the host's NVIDIA driver with the box
+    nvidia.set_title(&gettext("NVIDIA Drivers"));
+    nvidia.set_model(Some(&gtk::StringList::new(&[
+        // TRANSLATORS: Dropdown option - let BoxBuddy decide
+        &gettext("Automatic"),
+        // TRANSLATORS: Dropdown option
+        &gettext("On"),
+        // TRANSLATORS: Dropdown option
+        &gettext("Off"),
+    ])));
+    // TRANSLATORS: Explanation of the NVIDIA Drivers dropdown, while the GPU is looked for
+    nvidia.set_subtitle(&gettext("Looking for an NVIDIA GPU..."));
+
+    let (sender, receiver) = async_channel::bounded(1);
+
+    gio::spawn_blocking(move || {
+        let config_nvidia =
+            get_distrobox_config_value("nvidia").and_then(|v| parse_config_bool(&v));
+        sender
+            .send_blocking(NvidiaDetectMessage::Detected(
+                detect_nvidia(),
+                config_nvidia,
+            ))
+            .expect("The channel needs to be open.");
+    });
+
+    glib::spawn_future_local(clone!(
+        #[weak]
+        nvidia,
+        async move {
+            while let Ok(msg) = receiver.recv().await {
+                match msg {
+                    NvidiaDetectMessage::Detected(detection, config_nvidia) => {
+                        nvidia.set_subtitle(&markup_escape_text(&describe_nvidia_detection(
+                            &detection,
+                            config_nvidia,
+                        )));
+                    }
+                }
+            }
+        }
+    ));
+
+    nvidia
+}
+
+/// The option chosen in a row from `make_nvidia_row`
+fn get_chosen_nvidia_mode(nvidia: &adw::ComboRow) -> NvidiaMode {
+    match nvidia.selected() {
+        1 => NvidiaMode::On,
+        2 => NvidiaMode::Off,
+        _ => NvidiaMode::Auto,
+    }
+}
+
+/// Explains what Automatic will do with the NVIDIA flag, and why
+fn describe_nvidia_detection(detection: &NvidiaDetection, config_nvidia: Option<bool>) -> String {
+    let found = if detection.has_driver() {
+        // TRANSLATORS: Explanation of the NVIDIA Drivers dropdown
+        gettext("The NVIDIA driver is loaded, so Automatic shares it with the box.")
+    } else if detection.has_pci_device {
+        // TRANSLATORS: Explanation of the NVIDIA Drivers dropdown
+        gettext("An NVIDIA GPU was found, but its driver isn't loaded, so Automatic leaves it out.")
+    } else {
+        // TRANSLATORS: Explanation of the NVIDIA Drivers dropdown
+        gettext("No NVIDIA GPU was found, so Automatic leaves it out.")
+    };
+
+    let config_note = match config_nvidia {
+        // TRANSLATORS: Explanation of the NVIDIA Drivers dropdown - Off can't override distrobox.conf
+        Some(true) => {
+            gettext("Your distrobox.conf turns it on for every box, even when Off is chosen.")
+        }
+        // TRANSLATORS: Explanation of the NVIDIA Drivers dropdown
+        Some(false) => gettext("Your distrobox.conf turns it off unless On is chosen."),
+        None => return found,
+    };
+
+    format!("{found} {config_note}")
+}
+
+fn make_pull_progress_widgets() -> PullProgressWidgets {
+    let progress_bar = gtk::ProgressBar::new();
+    progress_bar.set_show_text(true);
+    progress_bar.set_hexpand(true);
+    progress_bar.set_valign(Align::Center);
+
+    // TRANSLATORS: Button Label - stops downloading the image
+    let cancel_btn = gtk::Button::with_label(&gettext("Cancel Pull"));
+
+    let container = gtk::Box::new(Orientation::Horizontal, 10);
+    container.append(&progress_bar);
+    container.append(&cancel_btn);
+    container.set_visible(false);
+
+    let error_lbl = gtk::Label::new(None);
+    error_lbl.add_css_class("error");
+    error_lbl.set_wrap(true);
+    error_lbl.set_visible(false);
+
+    let pull_process: Rc<RefCell<Option<Child>>> = Rc::new(RefCell::new(None));
+
+    let pull_process_clone = pull_process.clone();
+    cancel_btn.connect_clicked(move |_btn| {
+        if let Some(mut child) = pull_process_clone.borrow_mut().take() {
+            gio::spawn_blocking(move || stop_spawned_command(&mut child));
+        }
+    });
+
+    PullProgressWidgets {
+        container,
+        progress_bar,
+        error_lbl,
+        pull_process,
+    }
+}
+
+/// Pulls an image for the create pop-up, showing each layer's progress as it
+/// goes. Calls `on_pulled` once it has finished, or `on_stopped` if it fails
+/// or is cancelled.
+fn pull_image_with_progress<P, S>(
+    image: &str,
+    widgets: &PullProgressWidgets,
+    on_pulled: P,
+    on_stopped: S,
+) where
+    P: FnOnce() + 'static,
+    S: FnOnce() + 'static,
+{
+    widgets.error_lbl.set_visible(false);
+
+    let mut child = match spawn_image_pull(image) {
+        Ok(c) => c,
+        Err(e) => {
+            widgets.error_lbl.set_text(&e.to_string());
+            widgets.error_lbl.set_visible(true);
+            on_stopped();
+            return;
+        }
+    };
+
+    // TRANSLATORS: Progress bar text - followed by the image name
+    let pulling_text = format!("{} {}", gettext("Pulling"), image);
+    widgets.progress_bar.set_fraction(0.0);
+    widgets.progress_bar.set_text(Some(&pulling_text));
+    widgets.container.set_visible(true);
+
+    let (sender, receiver) = async_channel::unbounded();
+
+    // podman writes its progress to stderr, docker to stdout
+    if let Some(stdout) = child.stdout.take() {
+        let sender = sender.clone();
+        thread::spawn(move || {
+            for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+                if sender.send_blocking(PullMessage::Line(line)).is_err() {
+                    break;
+                }
+            }
+        });
+    }
+
+    if let Some(stderr) = child.stderr.take() {
+        thread::spawn(move || {
+            for line in BufReader::new(stderr).lines().map_while(Result::ok) {
+                if sender.send_blocking(PullMessage::Line(line)).is_err() {
+                    break;
+                }
+            }
+        });
+    }
+
+    *widgets.pull_process.borrow_mut() = Some(child);
+
+    let widgets = widgets.clone();
+    glib::spawn_future_local(async move {
+        let mut progress = PullProgress::default();
+        let mut last_message = String::new();
+
+        while let Ok(msg) = receiver.recv().await {
+            match msg {
+                PullMessage::Line(line) => {
+                    if progress.update(&line) {
+                        widgets.progress_bar.set_fraction(progress.fraction());
+                        widgets.progress_bar.set_text(Some(&format!(
+                            "{} - {}/{} {}",
+                            pulling_text,
+                            progress.layers_done(),
+                            progress.layers_total(),
+                            // TRANSLATORS: Progress bar text - follows a count such as 3/7
+                            gettext("layers")
+                        )));
+                    } else if !line.trim().is_empty() {
+                        last_message = line;
+                    }
+                }
+            }
+        }
+
+        widgets.container.set_visible(false);
+
+        // the output has ended, so has the pull - unless it was cancelled,
+        // in which case the process has already been taken and stopped
+        let pull_process = widgets.pull_process.borrow_mut().take();
+        let Some(mut child) = pull_process else {
+            on_stopped();
+            return;
+        };
+
+        if child.wait().is_ok_and(|status| status.success()) {
+            on_pulled();
+        } else {
+            widgets.error_lbl.set_text(&last_message);
+            widgets.error_lbl.set_visible(true);
+            on_stopped();
+        }
+    });
+}
+
+/// Reads the volumes entered in the create pop-up, as `--volume` arguments.
+/// Fails with a message naming the first volume which can't be used.
+fn get_new_box_volumes(volume_box_list: &gtk::ListBox) -> Result<Vec<String>, String> {
+    let mut volumes: Vec<String> = vec![];
+
+    let mut index = 0;
+    let mut row = volume_box_list.row_at_index(index);
+    while row.is_some() {
+        let entry_row = row
+            .clone()
+            .unwrap()
+            .first_child()
+            .unwrap()
+            .first_child()
+            .unwrap()
+            .first_child()
+            .unwrap()
+            .downcast::<adw::EntryRow>()
+            .unwrap();
+        let host_path = entry_row.title();
+        let volume_arg = format_volume_arg(&host_path, entry_row.text().trim())
+            .map_err(|e| format!("{host_path}: {}", describe_volume_arg_error(e)))?;
+        volumes.push(volume_arg);
+        index += 1;
+        row = volume_box_list.row_at_index(index);
+    }
+
+    Ok(volumes)
+}
+
+fn describe_volume_arg_error(error: VolumeArgError) -> String {
+    match error {
+        // TRANSLATORS: Error message for a volume
+        VolumeArgError::NotAbsolute => gettext("The location must start with /"),
+        // TRANSLATORS: Error message for a volume
+        VolumeArgError::ContainsColon => gettext("Folders containing : can't be used as volumes"),
+        // TRANSLATORS: Error message for a volume
+        VolumeArgError::ContainsWhitespace => {
+            gettext("Folders containing spaces can't be used as volumes")
+        }
+    }
+}
+
+/// Finds an image in the create pop-up's Image dropdown, whose items look
+/// like "Fedora - registry.fedoraproject.org/fedora-toolbox:40"
+fn find_listed_image(images_strlist: &gtk::StringList, image: &str) -> Option<u32> {
+    (0..images_strlist.n_items()).find(|idx| {
+        images_strlist
+            .string(*idx)
+            .is_some_and(|item| item.replace(" ✦ ", "").split(" - ").last() == Some(image))
+    })
+}
+
+/// Whether the create pop-up can go ahead - it needs a name, and the custom
+/// image has to look valid if there is one
+fn is_new_box_input_valid(
+    name_entry_row: &adw::EntryRow,
+    custom_image_row: &adw::EntryRow,
+    existing_names: &[String],
+) -> bool {
+    let custom_image = custom_image_row.text();
+    let custom_image = custom_image.trim();
+
+    validate_box_name(&get_new_box_name(name_entry_row), existing_names).is_ok()
+        && (custom_image.is_empty() || is_valid_image_reference(custom_image))
+}
+
+/// The name typed into a name row, with spaces swapped for dashes as
+/// distrobox doesn't allow them
+fn get_new_box_name(name_entry_row: &adw::EntryRow) -> String {
+    name_entry_row.text().trim().replace(' ', "-")
+}
+
+/// Names of all of the user's boxes, which new ones can't reuse
+fn get_existing_box_names() -> Vec<String> {
+    get_all_distroboxes().into_iter().map(|b| b.name).collect()
+}
+
+/// Makes the icon which a name row shows when its name can't be used
+fn make_box_name_error_icon(name_entry_row: &adw::EntryRow) -> gtk::Image {
+    let error_icon = gtk::Image::from_icon_name("dialog-warning-symbolic");
+    error_icon.add_css_class("error");
+    error_icon.set_visible(false);
+    name_entry_row.add_suffix(&error_icon);
+
+    error_icon
+}
+
+/// Turns a name row red, with the reason on its warning icon, if the name
+/// typed into it can't be used. Returns whether it can.
+fn check_box_name_row(
+    name_entry_row: &adw::EntryRow,
+    error_icon: &gtk::Image,
+    existing_names: &[String],
+) -> bool {
+    let result = validate_box_name(&get_new_box_name(name_entry_row), existing_names);
+
+    // an empty name just disables the button, it isn't worth a warning
+    let error = match result {
+        Ok(()) | Err(BoxNameError::Empty) => None,
+        Err(e) => Some(describe_box_name_error(e)),
+    };
+
+    match &error {
+        Some(error) => {
+            name_entry_row.add_css_class("error");
+            error_icon.set_tooltip_text(Some(error));
+        }
+        None => name_entry_row.remove_css_class("error"),
+    }
+    error_icon.set_visible(error.is_some());
+
+    result.is_ok()
+}
+
+fn describe_box_name_error(error: BoxNameError) -> String {
+    match error {
+        // TRANSLATORS: Error message for a box name
+        BoxNameError::Empty => gettext("Please enter a name"),
+        BoxNameError::TooLong => format!(
+            "{} {}",
+            // TRANSLATORS: Error message for a box name - followed by a number
+            gettext("Names can be at most this many characters long:"),
+            MAX_BOX_NAME_LENGTH
+        ),
+        // TRANSLATORS: Error message for a box name
+        BoxNameError::InvalidCharacters => gettext(
+            "Names can only contain letters, numbers, underscores, dots and dashes, and must start with a letter or number",
+        ),
+        // TRANSLATORS: Error message for a box name
+        BoxNameError::AlreadyExists => gettext("You already have a box with this name"),
+    }
+}
+
+/// The image chosen in the create pop-up - the custom image if one was typed,
+/// otherwise the one picked in the dropdown
+fn get_chosen_image(image_select: &gtk::DropDown, custom_image_row: &adw::EntryRow) -> String {
+    let custom_image = custom_image_row.text().trim().to_string();
+    if !custom_image.is_empty() {
+        return custom_image;
+    }
+
+    image_select
+        .selected_item()
+        .and_downcast::<gtk::StringObject>()
+        .map(|s| {
+            s.string()
+                .split(" - ")
+                .last()
+                .unwrap_or_default()
+                .replace(" ✦ ", "")
+        })
+        .unwrap_or_default()
+}
+
+/// Swaps the tag of an image for the one picked from the tag dropdown, if tags have been found
+fn get_image_with_chosen_tag(image: &str, tag_select: &gtk::DropDown) -> String {
+    if !tag_select.is_sensitive() {
+        return image.to_string();
+    }
+
+    match tag_select
+        .selected_item()
+        .and_downcast::<gtk::StringObject>()
+    {
+        Some(tag) => format!("{}:{}", split_image_reference(image).0, tag.string()),
+        None => image.to_string(),
+    }
+}
+
+/// Empties the tag dropdown of the create pop-up, for when the image changes
+fn clear_image_tags(
+    tag_row: &adw::ActionRow,
+    tag_select: &gtk::DropDown,
+    tags_strlist: &gtk::StringList,
+) {
+    tags_strlist.splice(0, tags_strlist.n_items(), &[]);
+    tag_select.set_sensitive(false);
+    // TRANSLATORS: Subtitle of the Tag row, before any tags have been searched for
+    tag_row.set_subtitle(&gettext(
+        "Search the registry for other versions of the image",
+    ));
+}
+
+/// Opens the create pop-up. `initial_image` is selected instead of the default image if given.
+fn create_new_distrobox(window: &ApplicationWindow, initial_image: Option<String>) {
+    let settings = Settings::new(APP_ID);
+
+    let new_box_popup = gtk::Window::builder()
+        // TRANSLATORS: Popup Window Title
+        .title(gettext("Create New Distrobox"))
+        .transient_for(window)
+        .default_width(700)
+        .default_height(350)
+        .modal(true)
+        .build();
+
+    // TRANSLATORS: Button Label
+    let create_btn = gtk::Button::with_label(&gettext("Create"));
+    create_btn.add_css_class("suggested-action");
+    create_btn.set_sensitive(false);
+
+    let info_btn = gtk::Button::from_icon_name("dialog-information-symbolic");
+    // TRANSLATORS: Button Label
+    info_btn.set_tooltip_text(Some(&gettext("Additional Information")));
+    let win_clone = window.clone();
+    info_btn.connect_clicked(move |_btn| show_flatpak_dir_access_popup(&win_clone));
+
+    // TRANSLATORS: Button Label
+    let cancel_btn = gtk::Button::with_label(&gettext("Cancel"));
+
+    cancel_btn.connect_clicked(move |btn| {
+        let win = btn.root().and_downcast::<gtk::Window>().unwrap();
+        win.destroy();
+    });
+
+    let new_box_titlebar = adw::HeaderBar::new();
+    new_box_titlebar.set_show_end_title_buttons(false);
+
+    new_box_titlebar.pack_end(&create_btn);
+    new_box_titlebar.pack_start(&cancel_btn);
+
+    new_box_titlebar.pack_end(&info_btn);
+
+    new_box_popup.set_titlebar(Some(&new_box_titlebar));
+
+    let main_box = gtk::Box::new(Orientation::Vertical, 10);
+    main_box.set_margin_start(10);
+    main_box.set_margin_end(10);
+    main_box.set_margin_top(10);
+    main_box.set_margin_bottom(10);
+
+    let boxed_list = gtk::ListBox::new();
+    boxed_list.set_selection_mode(gtk::SelectionMode::None);
+    boxed_list.add_css_class("boxed-list");
+
+    // name input
+    let name_entry_row = adw::EntryRow::new();
+    name_entry_row.set_hexpand(true);
+
+    // custom image, for anything not in distrobox's list
+    let custom_image_row = adw::EntryRow::new();
+    custom_image_row.set_hexpand(true);
+
+    let existing_names = Rc::new(get_existing_box_names());
+    let name_error_icon = make_box_name_error_icon(&name_entry_row);
+
+    // name input must have a usable name in it to enable the create button
+    name_entry_row.connect_changed(clone!(
+        #[weak]
+        create_btn,
+        #[weak]
+        custom_image_row,
+        #[weak]
+        name_error_icon,
+        #[strong]
+        existing_names,
+        move |row| {
+            check_box_name_row(row, &name_error_icon, &existing_names);
+            create_btn.set_sensitive(is_new_box_input_valid(
+                row,
+                &custom_image_row,
+                &existing_names,
+            ));
+        }
+    ));
+
+    // TRANSLATORS: Entry Label - Name input for new distrobox
+    name_entry_row.set_title(&gettext("Name"));
+
+    // start from distrobox.conf's defaults, if it has any
+    if let Some(default_name) = get_distrobox_config_value("container_name_default") {
+        name_entry_row.set_text(&default_name);
+    }
+
+    // custom home
+    let choose_home_btn = gtk::Button::from_icon_name("document-open-symbolic");
+    choose_home_btn.set_margin_top(10);
+    choose_home_btn.set_margin_bottom(10);
+    let home_select_row = adw::ActionRow::new();
+    home_select_row.set_activatable_widget(Some(&choose_home_btn));
+    home_select_row.add_suffix(&choose_home_btn);
+
+    //home entry row for manual edit
+    let home_entry_row = adw::EntryRow::new();
+    home_entry_row.set_hexpand(true);
+
+    //Additional Volumes - will not be shown without host access
+    let volume_box_list = gtk::ListBox::new();
+    volume_box_list.set_selection_mode(gtk::SelectionMode::None);
+    volume_box_list.add_css_class("boxed-list");
+    volume_box_list.set_visible(false);
+
+    // TRANSLATORS: Entry Label - Select home directory for new distrobox
+    home_entry_row.set_title(&gettext("Home Directory (Leave blank for default)"));
+    home_entry_row.set_width_request(600);
+    home_select_row.add_prefix(&home_entry_row);
+    let home_entry_row_future_clone = home_entry_row.clone();
+
+    choose_home_btn.connect_clicked(clone!(@weak window => move |_btn| {
+        let home_clone = home_entry_row.clone();
+        let file_dialog = FileDialog::builder().modal(false).build();
+        file_dialog.select_folder(Some(&window), None::<&gio::Cancellable>, clone!(@weak window => move |result| {
+            if let Ok(file) = result {
+                let home_path = file.path().unwrap().into_os_string().into_string().unwrap();
+                home_clone.set_text(&home_path);
+            }
+        }));
+    }));
+
+    // Image
+    let available_images = get_available_images_with_distro_name();
+    let avail_images_as_ref: Vec<&str> = available_images.iter().map(|s| s as &str).collect();
+    let imgs_strlist = gtk::StringList::new(avail_images_as_ref.as_slice());
+
+    // our own setting first, then distrobox.conf's
+    let default_image = initial_image
+        .or_else(|| Some(settings.string("default-image").to_string()))
+        .filter(|img| !img.is_empty())
+        .or_else(|| get_distrobox_config_value("container_image_default"));
+
+    let mut default_image_idx = 0;
+    if let Some(default_image) = default_image {
+        let listed_idx = available_images.iter().position(|img| {
+            img.replace(" ✦ ", "").split(" - ").last() == Some(default_image.as_str())
+        });
+
+        default_image_idx = match listed_idx {
+            Some(idx) => idx as u32,
+            None => {
+                // not one of distrobox's known images, so add it to the top
+                let distro =
+                    try_parse_distro_name_from_url(&default_image).replace("zunknown", "unknown");
+                imgs_strlist.splice(0, 0, &[&format!("{distro} - {default_image}")]);
+                0
+            }
+        };
+    }
+
+    let exp = gtk::PropertyExpression::new(
+        gtk::StringObject::static_type(),
+        None::<gtk::Expression>,
+        "string",
+    );
+
+    let image_select = gtk::DropDown::new(Some(imgs_strlist.clone()), Some(exp));
+    image_select.set_enable_search(true);
+    image_select.set_search_match_mode(gtk::StringFilterMatchMode::Substring);
+    image_select.set_selected(default_image_idx);
+
+    let image_select_row = adw::ActionRow::new();
+    // TRANSLATORS - Label for Dropdown where the user selects the container image to create
+    image_select_row.set_title(&gettext("Image"));
+    image_select_row.set_activatable_widget(Some(&image_select));
+    image_select_row.add_suffix(&image_select);
+
+    // TRANSLATORS: Entry Label - optional image name which is used instead of the Image dropdown
+    custom_image_row.set_title(&gettext(
+        "Custom Image (such as registry.example.com/team/image:tag)",
+    ));
+
+    let custom_image_error_icon = gtk::Image::from_icon_name("dialog-warning-symbolic");
+    custom_image_error_icon.add_css_class("error");
+    // TRANSLATORS: Error message for an image name which isn't valid
+    custom_image_error_icon.set_tooltip_text(Some(&gettext(
+        "Not a valid image name - it should look like registry.example.com/team/image:tag",
+    )));
+    custom_image_error_icon.set_visible(false);
+    custom_image_row.add_suffix(&custom_image_error_icon);
+
+    // tags
+    let tags_strlist = gtk::StringList::new(&[]);
+    let tag_exp = gtk::PropertyExpression::new(
+        gtk::StringObject::static_type(),
+        None::<gtk::Expression>,
+        "string",
+    );
+    let tag_select = gtk::DropDown::new(Some(tags_strlist.clone()), Some(tag_exp));
+    tag_select.set_enable_search(true);
+    tag_select.set_search_match_mode(gtk::StringFilterMatchMode::Substring);
+    tag_select.set_valign(Align::Center);
+    tag_select.set_sensitive(false);
+
+    let find_tags_btn = gtk::Button::from_icon_name("system-search-symbolic");
+    find_tags_btn.set_valign(Align::Center);
+    // TRANSLATORS: Button tooltip
+    find_tags_btn.set_tooltip_text(Some(&gettext("Find Tags")));
+
+    let tags_spinner = gtk::Spinner::new();
+
+    let tag_row = adw::ActionRow::new();
+    // TRANSLATORS: Label for Dropdown where the user picks which version (tag) of the image to use
+    tag_row.set_title(&gettext("Tag"));
+    tag_row.add_suffix(&tags_spinner);
+    tag_row.add_suffix(&tag_select);
+    tag_row.add_suffix(&find_tags_btn);
+    clear_image_tags(&tag_row, &tag_select, &tags_strlist);
+
+    image_select.connect_selected_notify(clone!(
+        #[weak]
+        tag_row,
+        #[weak]
+        tag_select,
+        #[weak]
+        tags_strlist,
+        move |_dd| {
+            clear_image_tags(&tag_row, &tag_select, &tags_strlist);
+        }
+    ));
+
+    custom_image_row.connect_changed(clone!(
+        #[weak]
+        create_btn,
+        #[weak]
+        name_entry_row,
+        #[strong]
+        existing_names,
+        #[weak]
+        custom_image_error_icon,
+        #[weak]
+        tag_row,
+        #[weak]
+        tag_select,
+        #[weak]
+        tags_strlist,
+        move |row| {
+            let custom_image = row.text();
+            let is_valid =
+                custom_image.trim().is_empty() || is_valid_image_reference(custom_image.trim());
+
+            if is_valid {
+                row.remove_css_class("error");
+            } else {
+                row.add_css_class("error");
+            }
+            custom_image_error_icon.set_visible(!is_valid);
+
+            create_btn.set_sensitive(is_new_box_input_valid(
+                &name_entry_row,
+                row,
+                &existing_names,
+            ));
+            clear_image_tags(&tag_row, &tag_select, &tags_strlist);
+        }
+    ));
+
+    find_tags_btn.connect_clicked(clone!(
+        #[weak]
+        image_select,
+        #[weak]
+        custom_image_row,
+        #[weak]
+        tag_row,
+        #[weak]
+        tag_select,
+        #[weak]
+        tags_strlist,
+        #[weak]
+        tags_spinner,
+        move |btn| {
+            let image = get_chosen_image(&image_select, &custom_image_row);
+            if !is_valid_image_reference(&image) {
+                return;
+            }
+
+            let (repository, current_tag) = split_image_reference(&image);
+            let repository = repository.to_string();
+            let current_tag = current_tag.unwrap_or("latest").to_string();
+
+            btn.set_sensitive(false);
+            tags_spinner.start();
+
+            let (sender, receiver) = async_channel::bounded(1);
+
+            let repo_clone = repository.clone();
+            gio::spawn_blocking(move || {
+                let tags = get_image_tags(&repo_clone);
+                sender
+                    .send_blocking(TagsFetchMessage::TagsFetched(tags))
+                    .expect("The channel needs to be open.");
+            });
+
+            glib::spawn_future_local(clone!(
+                #[weak]
+                btn,
+                #[weak]
+                image_select,
+                #[weak]
+                custom_image_row,
+                #[weak]
+                tag_row,
+                #[weak]
+                tag_select,
+                #[weak]
+                tags_strlist,
+                #[weak]
+                tags_spinner,
+                async move {
+                    while let Ok(msg) = receiver.recv().await {
+                        match msg {
+                            TagsFetchMessage::TagsFetched(result) => {
+                                tags_spinner.stop();
+                                btn.set_sensitive(true);
+
+                                // the image was changed while we were looking
+                                let image = get_chosen_image(&image_select, &custom_image_row);
+                                if split_image_reference(&image).0 != repository {
+                                    continue;
+                                }
+
+                                match result {
+                                    Ok(tags) if !tags.is_empty() => {
+                                        let tags_as_refs: Vec<&str> =
+                                            tags.iter().map(String::as_str).collect();
+                                        tags_strlist.splice(
+                                            0,
+                                            tags_strlist.n_items(),
+                                            &tags_as_refs,
+                                        );
+                                        let current_tag_idx = tags
+                                            .iter()
+                                            .position(|t| *t == current_tag)
+                                            .unwrap_or(0);
+                                        tag_select.set_selected(current_tag_idx as u32);
+                                        tag_select.set_sensitive(true);
+                                        tag_row.set_subtitle(&markup_escape_text(&repository));
+                                    }
+                                    Ok(_) => {
+                                        // TRANSLATORS: Info Message
+                                        tag_row.set_subtitle(&gettext("No tags found"));
+                                    }
+                                    Err(error) => {
+                                        let error = error.lines().last().unwrap_or_default();
+                                        tag_row.set_subtitle(&markup_escape_text(error));
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            ));
+        }
+    ));
+
+    // init
+    let init_row = adw::SwitchRow::new();
+    // TRANSLATORS - Label for Toggle when creating box to add systemd support
+    init_row.set_title(&gettext("Use init system"));
+    // TRANSLATORS: Explanation of what the 'use init system' toggle does
+    init_row.set_subtitle(&gettext("Adds systemd support - ignore if you're not sure"));
+    init_row.set_active(settings.boolean("default-init"));
+
+    let loading_spinner = gtk::Spinner::new();
+
+    let pull_widgets = make_pull_progress_widgets();
+
+    // closing the pop-up shouldn't leave a pull running in the background
+    let pull_process = pull_widgets.pull_process.clone();
+    new_box_popup.connect_destroy(move |_popup| {
+        if let Some(mut child) = pull_process.borrow_mut().take() {
+            gio::spawn_blocking(move || stop_spawned_command(&mut child));
+        }
+    });
+
+    let (advanced_row, advanced_rows) = make_advanced_create_rows();
+
+    let form = CreateFormWidgets {
+        image_select: image_select.clone(),
+        images_strlist: imgs_strlist.clone(),
+        custom_image_row: custom_image_row.clone(),
+        tag_select: tag_select.clone(),
+        init_row: init_row.clone(),
+        home_entry_row: home_entry_row_future_clone.clone(),
+        volume_box_list: has_host_access().then(|| volume_box_list.clone()),
+        advanced_rows: Rc::new(advanced_rows),
+    };
+
+    let preset_row = make_preset_row(&new_box_popup, &form);
+
+    let pw_clone = pull_widgets.clone();
+    let ne_row = name_entry_row.clone();
+    let loading_spinner_clone = loading_spinner.clone();
+    let win_clone = window.clone();
+    let form_clone = form.clone();
+    create_btn.connect_clicked(move |btn| {
+        let name = get_new_box_name(&ne_row);
+        let mut options = match form_clone.collect() {
+            Ok(options) => options,
+            Err(e) => {
+                pw_clone.error_lbl.set_text(&e);
+                pw_clone.error_lbl.set_visible(true);
+                return;
+            }
+        };
+        pw_clone.error_lbl.set_visible(false);
+
+        if validate_box_name(&name, &existing_names).is_err() || options.image.is_empty() {
+            return;
+        }
+
+        options.name = name.clone();
+        options.home_path = options
+            .home_path
+            .map(|home_path| home_path.replace(HOME_NAME_PLACEHOLDER, &name));
+
+        // the pull happens here, with a progress bar, rather than in distrobox
+        let always_pull = options.pull;
+        options.pull = false;
+
+        let name_clone = name.clone();
+        let image_to_pull = options.image.clone();
+        let options_image = options.image.clone();
+
+        let b_clone = btn.clone();
+        let ls_clone = loading_spinner_clone.clone();
+        let w_clone = win_clone.clone();
+        let error_lbl = pw_clone.error_lbl.clone();
+
+        let create_phase = move || {
+            ls_clone.start();
+
+            let (sender, receiver) = async_channel::bounded(1);
+
+            gio::spawn_blocking(move || {
+                sender
+                    .send_blocking(BoxCreateMessage::Finished(create_box(&options)))
+                    .expect("The channel needs to be open.");
+            });
+
+            glib::spawn_future_local(clone!(
+                #[weak]
+                ls_clone,
+                async move {
+                    while let Ok(msg) = receiver.recv().await {
+                        match msg {
+                            BoxCreateMessage::Finished(Err(error)) => {
+                                ls_clone.stop();
+
+                                // the runtime's actual complaint is on the last line
+                                error_lbl.set_text(error.lines().last().unwrap_or_default());
+                                error_lbl.set_visible(true);
+                                b_clone.set_sensitive(true);
+                            }
+                            BoxCreateMessage::Finished(Ok(_)) => {
+                                ls_clone.stop();
+
+                                let win = b_clone.root().and_downcast::<gtk::Window>().unwrap();
+                                win.destroy();
+
+                                let num_boxes = get_number_of_boxes();
+                                delayed_rerender(&w_clone, Some(num_boxes - 1));
+
+                                let settings = Settings::new(APP_ID);
+                                if settings.boolean("open-terminal-after-create") {
+                                    open_terminal_in_box(name_clone.clone());
+                                }
+                            }
+                        }
+                    }
+                }
+            ));
+        };
+
+        btn.set_sensitive(false);
+        loading_spinner_clone.start();
+
+        let pw_clone = pw_clone.clone();
+        let ls_clone = loading_spinner_clone.clone();
+        let mut start = Some(clone!(
+            #[weak]
+            btn,
+            move |is_downloaded: bool| {
+                if is_downloaded {
+                    create_phase();
+                } else {
+                    ls_clone.stop();
+                    pull_image_with_progress(
+                        &image_to_pull,
+                        &pw_clone,
+                        create_phase,
+                        clone!(
+                            #[weak]
+                            btn,
+                            move || btn.set_sensitive(true)
+                        ),
+                    );
+                }
+            }
+        ));
+
+        let (sender, receiver) = async_channel::bounded(1);
+        let image_clone = options_image.clone();
+
+        gio::spawn_blocking(move || {
+            // images with a ✦ skip straight to creating
+            let is_downloaded = !always_pull && is_image_downloaded(&image_clone);
+            sender
+                .send_blocking(ImageCheckMessage::Checked(is_downloaded))
+                .expect("The channel needs to be open.");
+        });
+
+        glib::spawn_future_local(async move {
+            while let Ok(msg) = receiver.recv().await {
+                match msg {
+                    ImageCheckMessage::Checked(is_downloaded) => {
+                        if let Some(start) = start.take() {
+                            start(is_downloaded);
+                        }
+                    }
+                }
+            }
+        });
+    });
+
+    boxed_list.append(&preset_row);
+    boxed_list.append(&name_entry_row);
+    boxed_list.append(&image_select_row);
+    boxed_list.append(&custom_image_row);
+    boxed_list.append(&tag_row);
+    boxed_list.append(&init_row);
+
+    boxed_list.append(&home_select_row);
+    boxed_list.append(&advanced_row);
+
+    main_box.append(&boxed_list);
+
+    //Volumes
+    if has_host_access() {
+        let volume_box_list_clone = volume_box_list.clone();
+
+        let volume_add_btn = gtk::Button::from_icon_name("list-add-symbolic");
+        volume_add_btn.add_css_class("flat");
+        // TRANSLATORS: Button tooltip
+        volume_add_btn.set_tooltip_text(Some(&gettext("Add a volume")));
+        volume_add_btn.connect_clicked(clone!(@weak window, @weak volume_box_list_clone => move |_btn| {
+            let file_dialog = FileDialog::builder().modal(false).build();
+            file_dialog.select_folder(Some(&window), None::<&gio::Cancellable>, clone!(@weak window, @weak volume_box_list_clone => move |result| {
+                if let Ok(file) = result {
+                    let volume_path = file.path().unwrap().into_os_string().into_string().unwrap();
+
+                    // /var/home is Silverblue and pals
+                    if volume_path.starts_with("/home/") || volume_path.starts_with("/var/home/") {
+                        show_volume_is_in_user_home_popup(&window);
+                    } else {
+                        append_volume_row(&volume_box_list_clone, &volume_path, &volume_path);
+                    }
+                }
+            }));
+        }));
+
+        let volume_preference_group = adw::PreferencesGroup::builder()
+            // TRANSLATORS: Subheading
+            .title(gettext("Additional Volumes:"))
+            // TRANSLATORS: Context for the Additional Volumes subheading
+            .description(gettext(
+                "Additional directories the new box should be able to access",
+            ))
+            .header_suffix(&volume_add_btn)
+            .build();
+
+        main_box.append(&volume_preference_group);
+        main_box.append(&volume_box_list);
+
+        for volume in settings.strv("default-volumes") {
+            let volume = volume.as_str();
+            let (host_path, box_path) = volume.split_once(':').unwrap_or((volume, volume));
+            append_volume_row(&volume_box_list, host_path, box_path);
+        }
+    }
+
+    main_box.append(&pull_widgets.container);
+    main_box.append(&pull_widgets.error_lbl);
+    main_box.append(&loading_spinner);
+
+    new_box_popup.set_child(Some(&main_box));
+    new_box_popup.present();
+}
+
+fn show_about_popup(window: &ApplicationWindow) {
+    let d = adw::AboutWindow::new();
+    d.set_transient_for(Some(window));
+    d.set_application_name("BoxBuddy");
+    d.set_version("2.5.6");
+    d.set_developer_name("Dvlv");
+    d.set_license_type(gtk::License::MitX11);
+    // TRANSLATORS: Description of the application
+    d.set_comments(&gettext(
+        "A Graphical Manager for your Distroboxes.
+    \nBoxBuddy is not partnered with or endorsed by any linux distributions or companies.
+    \nTrademarks, service marks, and logos are the property of their respective owners.",
+    ));
+    d.set_website("https://github.com/Dvlv/BoxBuddyRS");
+    d.set_issue_url("https://github.com/Dvlv/BoxBuddyRS/issues");
+    d.set_support_url("https://dvlv.github.io/BoxBuddyRS");
+    d.set_developers(&["Dvlv", "VortexAcherontic"]);
+    d.set_application_icon("io.github.dvlv.boxbuddyrs");
+    d.set_translator_credits(
+        "MLSci - CN
+VortexAcherontic - DE
+Pyrofanis - EL
+Sebrice - ES
+fonskip - fr_FR
+Scrambled777 - Hi
+nalbanobattistella - IT
+Luiz-C-Lima - pt_BR
+Murat-Karakaya - TR
+Vovkiv - RU and UK",
+    );
+    d.present();
+}
+
+fn on_open_terminal_clicked(box_name: String) {
+    open_terminal_in_box(box_name);
+}
+
+fn on_upgrade_clicked(box_name: &str) {
+    upgrade_box(box_name);
+}
+
+fn on_show_applications_clicked(window: &ApplicationWindow, box_name: String) {
+    let apps_popup = gtk::Window::builder()
+        // TRANSLATORS: Window Title - shows list of installed applications in distrobox
+        .title(gettext("Installed Applications"))
+        .transient_for(window)
+        .default_width(750)
+        .default_height(600)
+        .modal(true)
+        .build();
+
+    let titlebar = adw::HeaderBar::new();
+
+    // TRANSLATORS: Button Label - export a command from the box so it can be run from the host
+    let export_binary_btn = gtk::Button::with_label(&gettext("Export Binary"));
+    titlebar.pack_start(&export_binary_btn);
+
+    let main_box = gtk::Box::new(Orientation::Vertical, 10);
+    main_box.set_margin_start(10);
+    main_box.set_margin_end(10);
+    main_box.set_margin_top(10);
+    main_box.set_margin_bottom(10);
+
+    let loading_spinner = gtk::Spinner::new();
+
+    // TRANSLATORS: Loading Message
+    let loading_lbl = gtk::Label::new(Some(&gettext("Loading...")));
+    loading_lbl.add_css_class("title-2");
+
+    // search and filters, shown once the apps have loaded
+    let filter_box = gtk::Box::new(Orientation::Horizontal, 10);
+    filter_box.set_visible(false);
+
+    let search_entry = gtk::SearchEntry::new();
+    search_entry.set_hexpand(true);
+    // TRANSLATORS: Search placeholder
+    search_entry.set_placeholder_text(Some(&gettext("Search by name, command or ID")));
+
+    // TRANSLATORS: Filter Button - show every app
+    let all_toggle = gtk::ToggleButton::with_label(&gettext("All"));
+    all_toggle.set_active(true);
+    // TRANSLATORS: Filter Button - only show apps which are in the host's menu
+    let exported_toggle = gtk::ToggleButton::with_label(&gettext("Exported"));
+    exported_toggle.set_group(Some(&all_toggle));
+    // TRANSLATORS: Filter Button - only show apps which aren't in the host's menu
+    let not_exported_toggle = gtk::ToggleButton::with_label(&gettext("Not Exported"));
+    not_exported_toggle.set_group(Some(&all_toggle));
+
+    let toggles_box = gtk::Box::new(Orientation::Horizontal, 0);
+    toggles_box.add_css_class("linked");
+    toggles_box.append(&all_toggle);
+    toggles_box.append(&exported_toggle);
+    toggles_box.append(&not_exported_toggle);
+
+    filter_box.append(&search_entry);
+    filter_box.append(&toggles_box);
+
+    let apps_scrolled_win = gtk::ScrolledWindow::new();
+    apps_scrolled_win.set_vexpand(true);
+    apps_scrolled_win.set_hexpand(true);
+    apps_scrolled_win.set_visible(false);
+
+    // TRANSLATORS: Message shown when the search and filters hide every app
+    let no_matches_lbl = gtk::Label::new(Some(&gettext("No Matching Applications")));
+    no_matches_lbl.add_css_class("dim-label");
+    no_matches_lbl.set_visible(false);
+
+    let scrolled_win = gtk::ScrolledWindow::new();
+    scrolled_win.set_hexpand(true);
+    scrolled_win.set_propagate_natural_height(true);
+    scrolled_win.set_max_content_height(200);
+
+    let scroll_area = gtk::Box::new(gtk::Orientation::Vertical, 15);
+    scroll_area.set_hexpand(true);
+
+    scrolled_win.set_child(Some(&scroll_area));
+
+    let box_name_clone = box_name.clone();
+    let apps_popup_clone = apps_popup.clone();
+    let loading_lbl_clone = loading_lbl.clone();
+    let scroll_area_clone = scroll_area.clone();
+    export_binary_btn.connect_clicked(move |_btn| {
+        on_export_binary_clicked(
+            &apps_popup_clone,
+            box_name_clone.clone(),
+            loading_lbl_clone.clone(),
+            scroll_area_clone.clone(),
+        );
+    });
+
+    main_box.append(&loading_lbl);
+    main_box.append(&loading_spinner);
+    main_box.append(&filter_box);
+    main_box.append(&apps_scrolled_win);
+    main_box.append(&no_matches_lbl);
+    main_box.append(&scrolled_win);
+
+    apps_popup.set_child(Some(&main_box));
+    apps_popup.set_titlebar(Some(&titlebar));
+    loading_spinner.start();
+    apps_popup.present();
+    apps_popup.queue_draw();
+
+    let (sender, receiver) = async_channel::bounded(1);
+    let box_name_clone = box_name.clone();
+
+    gio::spawn_blocking(move || {
+        let apps = get_apps_in_box(&box_name_clone);
+        let binaries = get_binaries_exported_from_box(&box_name_clone);
+        sender
+            .send_blocking(AppsFetchMessage::AppsFetched(apps, binaries))
+            .expect("The channel needs to be open.");
+    });
+
+    glib::spawn_future_local(clone!(
+        #[weak]
+        main_box,
+        #[weak]
+        scroll_area,
+        #[weak]
+        apps_popup,
+        async move {
+            while let Ok(msg) = receiver.recv().await {
+                match msg {
+                    AppsFetchMessage::AppsFetched(apps, binaries) => {
+                        loading_spinner.stop();
+                        main_box.remove(&loading_spinner);
+
+                        if apps.is_empty() {
+                            //TRANSLATORS: Error Message
+                            loading_lbl.set_text(&gettext("No Applications Installed"));
+                        } else {
+                            //TRANSLATORS: Window Title
+                            loading_lbl.set_text(&gettext("Available Applications"));
+
+                            let apps_store = gio::ListStore::new::<glib::BoxedAnyObject>();
+                            for app in apps {
+                                apps_store
+                                    .append(&glib::BoxedAnyObject::new(AppListItem::new(app)));
+                            }
+
+                            let list_view = make_apps_list_view(
+                                &apps_store,
+                                &search_entry,
+                                &exported_toggle,
+                                &not_exported_toggle,
+                                &box_name,
+                                &loading_lbl,
+                                &apps_popup,
+                            );
+
+                            if let Some(selection) =
+                                list_view.model().and_downcast::<gtk::MultiSelection>()
+                            {
+                                selection.connect_items_changed(clone!(
+                                    #[weak]
+                                    no_matches_lbl,
+                                    move |model, _, _, _| {
+                                        no_matches_lbl.set_visible(model.n_items() == 0);
+                                    }
+                                ));
+
+                                let action_bar = make_bulk_menu_action_bar(
+                                    &selection,
+                                    &apps_store,
+                                    &box_name,
+                                    &loading_lbl,
+                                );
+                                main_box.insert_child_after(&action_bar, Some(&no_matches_lbl));
+                            }
+
+                            apps_scrolled_win.set_child(Some(&list_view));
+                            apps_scrolled_win.set_visible(true);
+                            filter_box.set_visible(true);
+                        }
+                        fill_exported_binaries(&scroll_area, &box_name, binaries);
+                    }
+                }
+            }
+        }
+    ));
+}
+
+/// Lists the binaries exported from a box in the View Applications pop-up,
+/// replacing anything already listed
+fn fill_exported_binaries(scroll_area: &gtk::Box, box_name: &str, binaries: Vec<String>) {
+    while let Some(child) = scroll_area.first_child() {
+        scroll_area.remove(&child);
+    }
+
+    if binaries.is_empty() {
+        //TRANSLATORS: Error Message
+        let no_binaries_lbl = gtk::Label::new(Some(&gettext("No Binaries Exported")));
+        no_binaries_lbl.add_css_class("title-2");
+        scroll_area.append(&no_binaries_lbl);
+        return;
+    }
+
+    let bin_boxed_list = gtk::ListBox::new();
+    bin_boxed_list.set_selection_mode(gtk::SelectionMode::None);
+    bin_boxed_list.add_css_class("boxed-list");
+
+    let bin_title = gtk::Label::new(Some(&gettext("Exported Binaries")));
+    bin_title.add_css_class("title-2");
+
+    for binary in binaries {
+        let row = adw::ActionRow::new();
+        row.set_title(&markup_escape_text(&binary.to_string()));
+        bin_boxed_list.append(&row);
+
+        // TRANSLATORS: Button Text
+        let remove_btn = gtk::Button::with_label(&gettext("Remove"));
+        remove_btn.add_css_class("pill");
+        //remove_btn.set_width_request(200);
+
+        let box_name_clone = box_name.to_string();
+        let row_clone = row.clone();
+        remove_btn.connect_clicked(move |btn| {
+            remove_exported_binary(&box_name_clone, &binary, &row_clone);
+            btn.set_sensitive(false);
+        });
+        row.add_suffix(&remove_btn);
+    }
+    scroll_area.append(&bin_title);
+    scroll_area.append(&bin_boxed_list);
+}
+
+/// Builds the list of apps for the View Applications pop-up, filtered by the
+/// search entry and toggles, and grouped by category. Rows are only built
+/// for apps which are scrolled into view, so boxes with hundreds of apps stay responsive.
+fn make_apps_list_view(
+    apps_store: &gio::ListStore,
+    search_entry: &gtk::SearchEntry,
+    exported_toggle: &gtk::ToggleButton,
+    not_exported_toggle: &gtk::ToggleButton,
+    box_name: &str,
+    status_lbl: &gtk::Label,
+    apps_popup: &gtk::Window,
+) -> gtk::ListView {
+    let filter = gtk::CustomFilter::new(clone!(
+        #[weak]
+        search_entry,
+        #[weak]
+        exported_toggle,
+        #[weak]
+        not_exported_toggle,
+        #[upgrade_or]
+        false,
+        move |obj| {
+            let Some(obj) = obj.downcast_ref::<glib::BoxedAnyObject>() else {
+                return false;
+            };
+            let item = obj.borrow::<AppListItem>();
+
+            let is_on_host = item.is_on_host.get();
+            if (exported_toggle.is_active() && !is_on_host)
+                || (not_exported_toggle.is_active() && is_on_host)
+            {
+                return false;
+            }
+
+            item.matches_search(search_entry.text().trim())
+        }
+    ));
+
+    search_entry.connect_search_changed(clone!(
+        #[weak]
+        filter,
+        move |_entry| {
+            filter.changed(gtk::FilterChange::Different);
+        }
+    ));
+    for toggle in [exported_toggle, not_exported_toggle] {
+        toggle.connect_toggled(clone!(
+            #[weak]
+            filter,
+            move |_toggle| {
+                filter.changed(gtk::FilterChange::Different);
+            }
+        ));
+    }
+
+    let filter_model = gtk::FilterListModel::new(Some(apps_store.clone()), Some(filter));
+
+    let category_sorter = gtk::CustomSorter::new(|a, b| {
+        let a = a.downcast_ref::<glib::BoxedAnyObject>().unwrap();
+        let b = b.downcast_ref::<glib::BoxedAnyObject>().unwrap();
+        let a_category = a.borrow::<AppListItem>().category;
+        let b_category = b.borrow::<AppListItem>().category;
+
+        a_category.cmp(&b_category).into()
+    });
+    let name_sorter = gtk::CustomSorter::new(|a, b| {
+        let a = a.downcast_ref::<glib::BoxedAnyObject>().unwrap();
+        let b = b.downcast_ref::<glib::BoxedAnyObject>().unwrap();
+        let a_name = a.borrow::<AppListItem>().app.name.to_lowercase();
+        let b_name = b.borrow::<AppListItem>().app.name.to_lowercase();
+
+        a_name.cmp(&b_name).into()
+    });
+
+    let sort_model = gtk::SortListModel::new(Some(filter_model), Some(name_sorter));
+    sort_model.set_section_sorter(Some(&category_sorter));
+
+    let selection = gtk::MultiSelection::new(Some(sort_model));
+
+    let factory = gtk::SignalListItemFactory::new();
+    factory.connect_setup(|_factory, obj| {
+        let list_item = obj.downcast_ref::<gtk::ListItem>().unwrap();
+        list_item.set_activatable(false);
+    });
+
+    let box_name = box_name.to_string();
+    factory.connect_bind(clone!(
+        #[weak]
+        status_lbl,
+        #[weak]
+        apps_popup,
+        #[weak]
+        selection,
+        move |_factory, obj| {
+            let list_item = obj.downcast_ref::<gtk::ListItem>().unwrap();
+            let Some(item) = list_item.item().and_downcast::<glib::BoxedAnyObject>() else {
+                return;
+            };
+
+            // ticking the box adds the app to the selection, without clearing it
+            let select_check = gtk::CheckButton::new();
+            select_check.set_valign(gtk::Align::Center);
+            list_item
+                .bind_property("selected", &select_check, "active")
+                .sync_create()
+                .build();
+            select_check.connect_toggled(clone!(
+                #[weak]
+                list_item,
+                #[weak]
+                selection,
+                move |check| {
+                    let position = list_item.position();
+                    if check.is_active() == selection.is_selected(position) {
+                        return;
+                    }
+
+                    if check.is_active() {
+                        selection.select_item(position, false);
+                    } else {
+                        selection.unselect_item(position);
+                    }
+                }
+            ));
+
+            // a fresh row each time, so no buttons are left wired to the previous app
+            let row = make_app_row(
+                &item.borrow::<AppListItem>(),
+                &select_check,
+                &box_name,
+                &status_lbl,
+                &apps_popup,
+            );
+            list_item.set_child(Some(&row));
+        }
+    ));
+
+    let header_factory = gtk::SignalListItemFactory::new();
+    header_factory.connect_setup(|_factory, obj| {
+        let header = obj.downcast_ref::<gtk::ListHeader>().unwrap();
+        let lbl = gtk::Label::new(None);
+        lbl.add_css_class("heading");
+        lbl.set_xalign(0.0);
+        lbl.set_margin_top(10);
+        lbl.set_margin_bottom(5);
+        header.set_child(Some(&lbl));
+    });
+    header_factory.connect_bind(|_factory, obj| {
+        let header = obj.downcast_ref::<gtk::ListHeader>().unwrap();
+        let Some(lbl) = header.child().and_downcast::<gtk::Label>() else {
+            return;
+        };
+        if let Some(item) = header.item().and_downcast::<glib::BoxedAnyObject>() {
+            lbl.set_text(&describe_app_category(
+                item.borrow::<AppListItem>().category,
+            ));
+        }
+    });
+
+    let list_view = gtk::ListView::new(Some(selection), Some(factory));
+    list_view.set_header_factory(Some(&header_factory));
+
+    list_view
+}
+
+/// Builds the bar under the apps list for adding or removing many apps at once.
+/// Each batch runs in the background, one app after another, showing its progress.
+fn make_bulk_menu_action_bar(
+    selection: &gtk::MultiSelection,
+    apps_store: &gio::ListStore,
+    box_name: &str,
+    status_lbl: &gtk::Label,
+) -> gtk::ActionBar {
+    let action_bar = gtk::ActionBar::new();
+
+    let selected_lbl = gtk::Label::new(None);
+    selected_lbl.add_css_class("dim-label");
+
+    // TRANSLATORS: Button Label - add every selected app to the host's menu
+    let export_selected_btn = gtk::Button::with_label(&gettext("Export Selected"));
+    export_selected_btn.add_css_class("suggested-action");
+    // TRANSLATORS: Button Label - remove every selected app from the host's menu
+    let remove_selected_btn = gtk::Button::with_label(&gettext("Remove Selected"));
+    // TRANSLATORS: Button Label - remove every app from this box from the host's menu
+    let remove_all_btn = gtk::Button::with_label(&gettext("Remove All From Menu"));
+    remove_all_btn.add_css_class("destructive-action");
+
+    let progress_bar = gtk::ProgressBar::new();
+    progress_bar.set_show_text(true);
+    progress_bar.set_hexpand(true);
+    progress_bar.set_valign(gtk::Align::Center);
+    progress_bar.set_visible(false);
+
+    action_bar.pack_start(&selected_lbl);
+    action_bar.pack_start(&progress_bar);
+    action_bar.pack_end(&remove_all_btn);
+    action_bar.pack_end(&remove_selected_btn);
+    action_bar.pack_end(&export_selected_btn);
+
+    let box_name = box_name.to_string();
+    let is_busy = Rc::new(Cell::new(false));
+
+    let update_selected = Rc::new(clone!(
+        #[weak]
+        selection,
+        #[weak]
+        selected_lbl,
+        #[weak]
+        export_selected_btn,
+        #[weak]
+        remove_selected_btn,
+        #[weak]
+        remove_all_btn,
+        #[strong]
+        is_busy,
+        move || {
+            let count = selection.selection().size();
+            // TRANSLATORS: Label - has the number of selected apps appended
+            let selected_prefix = &gettext("Selected:");
+            selected_lbl.set_text(&format!("{} {}", selected_prefix, count));
+
+            let is_busy = is_busy.get();
+            export_selected_btn.set_sensitive(!is_busy && count > 0);
+            remove_selected_btn.set_sensitive(!is_busy && count > 0);
+            remove_all_btn.set_sensitive(!is_busy);
+        }
+    ));
+    update_selected();
+
+    let update_selected_clone = update_selected.clone();
+    selection.connect_selection_changed(move |_selection, _position, _n_items| {
+        update_selected_clone();
+    });
+    let update_selected_clone = update_selected.clone();
+    selection.connect_items_changed(move |_selection, _position, _removed, _added| {
+        update_selected_clone();
+    });
+
+    // adds (or removes) each of the items, then refreshes their rows
+    let run_batch = Rc::new(clone!(
+        #[weak]
+        apps_store,
+        #[weak]
+        progress_bar,
+        #[weak]
+        selected_lbl,
+        #[weak]
+        status_lbl,
+        #[strong]
+        is_busy,
+        #[strong]
+        update_selected,
+        move |items: Vec<glib::BoxedAnyObject>, export: bool| {
+            // only the apps which would change
+            let items: Vec<glib::BoxedAnyObject> = items
+                .into_iter()
+                .filter(|item| item.borrow::<AppListItem>().is_on_host.get() != export)
+                .collect();
+            if items.is_empty() {
+                return;
+            }
+
+            let apps: Vec<DBoxApp> = items
+                .iter()
+                .map(|item| item.borrow::<AppListItem>().app.clone())
+                .collect();
+
+            is_busy.set(true);
+            update_selected();
+            selected_lbl.set_visible(false);
+            progress_bar.set_fraction(0.0);
+            progress_bar.set_visible(true);
+
+            let (sender, receiver) = async_channel::bounded(1);
+            let box_name = box_name.clone();
+
+            gio::spawn_blocking(move || {
+                let total = apps.len();
+                let mut results = vec![];
+
+                for (done, app) in apps.iter().enumerate() {
+                    sender
+                        .send_blocking(MenuBatchMessage::Progress(done, total))
+                        .expect("The channel needs to be open.");
+
+                    results.push(if export {
+                        add_app_to_menu(app, &box_name)
+                    } else {
+                        remove_app_from_menu(app, &box_name)
+                    });
+                }
+
+                sender
+                    .send_blocking(MenuBatchMessage::Finished(results))
+                    .expect("The channel needs to be open.");
+            });
+
+            glib::spawn_future_local(clone!(
+                #[weak]
+                apps_store,
+                #[weak]
+                progress_bar,
+                #[weak]
+                selected_lbl,
+                #[weak]
+                status_lbl,
+                #[strong]
+                is_busy,
+                #[strong]
+                update_selected,
+                async move {
+                    while let Ok(msg) = receiver.recv().await {
+                        match msg {
+                            MenuBatchMessage::Progress(done, total) => {
+                                let progress_prefix = if export {
+                                    // TRANSLATORS: Progress Label - has e.g. "3 / 12" appended
+                                    gettext("Exporting")
+                                } else {
+                                    // TRANSLATORS: Progress Label - has e.g. "3 / 12" appended
+                                    gettext("Removing")
+                                };
+                                progress_bar.set_text(Some(&format!(
+                                    "{} {} / {}",
+                                    progress_prefix,
+                                    done + 1,
+                                    total
+                                )));
+                                progress_bar.set_fraction(done as f64 / total as f64);
+                            }
+                            MenuBatchMessage::Finished(results) => {
+                                let mut failed = 0;
+                                for (item, now_on_host) in items.iter().zip(&results) {
+                                    item.borrow::<AppListItem>().is_on_host.set(*now_on_host);
+                                    if *now_on_host != export {
+                                        failed += 1;
+                                    }
+                                }
+
+                                let status = if failed > 0 {
+                                    // TRANSLATORS: Error Message - has the number of apps appended
+                                    let failed_prefix = &gettext("Apps which couldn't be changed:");
+                                    format!("{} {}", failed_prefix, failed)
+                                } else if export {
+                                    //TRANSLATORS: Success Message
+                                    gettext("Apps Exported!")
+                                } else {
+                                    //TRANSLATORS: Success Message
+                                    gettext("Apps Removed!")
+                                };
+                                status_lbl.set_text(&status);
+
+                                // rebuild the rows, which also clears the selection
+                                let n_items = apps_store.n_items();
+                                apps_store.items_changed(0, n_items, n_items);
+
+                                progress_bar.set_visible(false);
+                                selected_lbl.set_visible(true);
+                                is_busy.set(false);
+                                update_selected();
+                            }
+                        }
+                    }
+                }
+            ));
+        }
+    ));
+
+    let get_selected_items = clone!(
+        #[weak]
+        selection,
+        #[upgrade_or_default]
+        move || -> Vec<glib::BoxedAnyObject> {
+            (0..selection.n_items())
+                .filter(|i| selection.is_selected(*i))
+                .filter_map(|i| selection.item(i).and_downcast::<glib::BoxedAnyObject>())
+                .collect()
+        }
+    );
+    let get_selected_items = Rc::new(get_selected_items);
+
+    let run_batch_clone = run_batch.clone();
+    let get_selected_items_clone = get_selected_items.clone();
+    export_selected_btn.connect_clicked(move |_btn| {
+        run_batch_clone(get_selected_items_clone(), true);
+    });
+
+    let run_batch_clone = run_batch.clone();
+    remove_selected_btn.connect_clicked(move |_btn| {
+        run_batch_clone(get_selected_items(), false);
+    });
+
+    // every app, including any hidden by the search or filters
+    remove_all_btn.connect_clicked(clone!(
+        #[weak]
+        apps_store,
+        move |btn| {
+            let d = adw::MessageDialog::new(
+                btn.root().and_downcast::<gtk::Window>().as_ref(),
+                //TRANSLATORS: Confirmation Dialogue
+                Some(&gettext("Remove All From Menu?")),
+                //TRANSLATORS: Confirmation Dialogue
+                Some(&gettext(
+                    "Every app from this box will be removed from your menu. Apps which aren't in your menu are left alone.",
+                )),
+            );
+            //TRANSLATORS: Button Label
+            d.add_response("cancel", &gettext("Cancel"));
+            //TRANSLATORS: Button Label
+            d.add_response("remove", &gettext("Remove All"));
+            d.set_default_response(Some("cancel"));
+            d.set_close_response("cancel");
+            d.set_response_appearance("remove", adw::ResponseAppearance::Destructive);
+
+            let run_batch = run_batch.clone();
+            d.connect_response(
+                None,
+                clone!(
+                    #[weak]
+                    apps_store,
+                    move |_d, res| {
+                        if res != "remove" {
+                            return;
+                        }
+
+                        let items = (0..apps_store.n_items())
+                            .filter_map(|i| {
+                                apps_store.item(i).and_downcast::<glib::BoxedAnyObject>()
+                            })
+                            .collect();
+                        run_batch(items, false);
+                    }
+                ),
+            );
+            d.present();
+        }
+    ));
+
+    action_bar
+}
+
+/// Gets the heading for a group of apps, from its index into `APP_CATEGORIES`
+fn describe_app_category(category: usize) -> String {
+    match APP_CATEGORIES.get(category).copied() {
+        // TRANSLATORS: App Category Heading
+        Some("AudioVideo") => gettext("Sound & Video"),
+        // TRANSLATORS: App Category Heading
+        Some("Development") => gettext("Development"),
+        // TRANSLATORS: App Category Heading
+        Some("Education") => gettext("Education"),
+        // TRANSLATORS: App Category Heading
+        Some("Game") => gettext("Games"),
+        // TRANSLATORS: App Category Heading
+        Some("Graphics") => gettext("Graphics"),
+        // TRANSLATORS: App Category Heading
+        Some("Network") => gettext("Internet"),
+        // TRANSLATORS: App Category Heading
+        Some("Office") => gettext("Office"),
+        // TRANSLATORS: App Category Heading
+        Some("Science") => gettext("Science"),
+        // TRANSLATORS: App Category Heading
+        Some("Settings") => gettext("Settings"),
+        // TRANSLATORS: App Category Heading
+        Some("System") => gettext("System"),
+        // TRANSLATORS: App Category Heading
+        Some("Utility") => gettext("Accessories"),
+        // TRANSLATORS: App Category Heading - apps in none of the other categories
+        _ => gettext("Other"),
+    }
+}
+
+/// Builds an app's row in the View Applications list
+fn make_app_row(
+    item: &AppListItem,
+    select_check: &gtk::CheckButton,
+    box_name: &str,
+    status_lbl: &gtk::Label,
+    apps_popup: &gtk::Window,
+) -> adw::ActionRow {
+    let app = &item.app;
+
+    let row = adw::ActionRow::new();
+    row.set_title(&markup_escape_text(&app.name.to_string()));
+    // TRANSLATORS: Tooltip - has the folder the app was found in appended
+    let found_in_prefix = &gettext("Found in");
+    row.set_tooltip_text(Some(&format!("{} {}", found_in_prefix, app.source_dir)));
+
+    let img = match &app.icon_file {
+        Some(icon_file) => gtk::Image::from_file(icon_file),
+        None => gtk::Image::from_icon_name(&app.icon),
+    };
+
+    //TRANSLATORS: Button Label
+    let run_btn = gtk::Button::with_label(&gettext("Run"));
+    run_btn.add_css_class("pill");
+    run_btn.set_width_request(100);
+    let box_name_clone = box_name.to_string();
+    let app_clone = app.clone();
+    run_btn.connect_clicked(move |_btn| {
+        run_app_in_box(&app_clone, &box_name_clone);
+    });
+
+    let options_btn = gtk::Button::from_icon_name("emblem-system-symbolic");
+    options_btn.add_css_class("flat");
+    options_btn.set_valign(gtk::Align::Center);
+    //TRANSLATORS: Button tooltip
+    options_btn.set_tooltip_text(Some(&gettext("Export Options")));
+    let box_name_clone = box_name.to_string();
+    let status_lbl_clone = status_lbl.clone();
+    let app_clone = app.clone();
+    let apps_popup_clone = apps_popup.clone();
+    options_btn.connect_clicked(move |_btn| {
+        on_export_options_clicked(
+            &apps_popup_clone,
+            app_clone.clone(),
+            box_name_clone.clone(),
+            status_lbl_clone.clone(),
+        );
+    });
+
+    row.add_prefix(select_check);
+    row.add_prefix(&img);
+    row.add_suffix(&run_btn);
+    row.add_suffix(&gtk::Separator::new(gtk::Orientation::Horizontal));
+    row.add_suffix(&options_btn);
+    row.add_suffix(&make_app_menu_button(
+        app,
+        &item.is_on_host,
+        box_name,
+        status_lbl,
+    ));
+
+    row
+}
+
+fn on_view_logs_clicked(window: &ApplicationWindow, box_name: String) {
+    let logs_popup = gtk::Window::builder()
+        // TRANSLATORS: Window Title - has box name appended
+        .title(format!("{} - {}", gettext("Logs"), box_name))
+        .transient_for(window)
+        .default_width(800)
+        .default_height(500)
+        .modal(true)
+        .build();
+
+    let follow_btn = gtk::ToggleButton::new();
+    follow_btn.set_icon_name("go-bottom-symbolic");
+    // TRANSLATORS: Button tooltip - keep showing new log lines as they arrive
+    follow_btn.set_tooltip_text(Some(&gettext("Follow Logs")));
+    follow_btn.set_active(true);
+
+    let timestamps_btn = gtk::ToggleButton::new();
+    timestamps_btn.set_icon_name("preferences-system-time-symbolic");
+    // TRANSLATORS: Button tooltip
+    timestamps_btn.set_tooltip_text(Some(&gettext("Show Timestamps")));
+
+    let save_btn = gtk::Button::from_icon_name("document-save-symbolic");
+    // TRANSLATORS: Button tooltip
+    save_btn.set_tooltip_text(Some(&gettext("Save To File")));
+
+    let titlebar = adw::HeaderBar::new();
+    titlebar.pack_start(&follow_btn);
+    titlebar.pack_start(&timestamps_btn);
+    titlebar.pack_end(&save_btn);
+
+    let main_box = gtk::Box::new(Orientation::Vertical, 10);
+    main_box.set_margin_start(10);
+    main_box.set_margin_end(10);
+    main_box.set_margin_top(10);
+    main_box.set_margin_bottom(10);
+
+    let search_entry = gtk::SearchEntry::new();
+    // TRANSLATORS: Placeholder text of the search input on the Logs window
+    search_entry.set_placeholder_text(Some(&gettext("Search logs")));
+
+    let text_view = gtk::TextView::new();
+    text_view.set_editable(false);
+    text_view.set_cursor_visible(false);
+    text_view.set_monospace(true);
+    text_view.set_wrap_mode(gtk::WrapMode::WordChar);
+
+    let buffer = text_view.buffer();
+    let match_tag = gtk::TextTag::builder()
+        .name("search-match")
+        .background("yellow")
+        .foreground("black")
+        .build();
+    buffer.tag_table().add(&match_tag);
+    let end_mark = buffer.create_mark(Some("logs-end"), &buffer.end_iter(), false);
+
+    let scrolled_win = gtk::ScrolledWindow::new();
+    scrolled_win.set_vexpand(true);
+    scrolled_win.set_hexpand(true);
+    scrolled_win.set_child(Some(&text_view));
+
+    main_box.append(&search_entry);
+    main_box.append(&scrolled_win);
+
+    let toast_overlay = ToastOverlay::new();
+    toast_overlay.set_child(Some(&main_box));
+
+    logs_popup.set_child(Some(&toast_overlay));
+    logs_popup.set_titlebar(Some(&titlebar));
+
+    let viewer = LogsViewer {
+        box_name: box_name.clone(),
+        text_view: text_view.clone(),
+        end_mark,
+        search_entry: search_entry.clone(),
+        follow_btn: follow_btn.clone(),
+        timestamps_btn: timestamps_btn.clone(),
+        logs_process: Rc::default(),
+        generation: Rc::default(),
+    };
+
+    // changing either toggle restarts `logs` with the new arguments
+    follow_btn.connect_toggled(clone!(
+        #[strong]
+        viewer,
+        move |_btn| start_box_logs(&viewer)
+    ));
+    timestamps_btn.connect_toggled(clone!(
+        #[strong]
+        viewer,
+        move |_btn| start_box_logs(&viewer)
+    ));
+
+    search_entry.connect_search_changed(clone!(
+        #[weak]
+        text_view,
+        move |entry| {
+            let buffer = text_view.buffer();
+            buffer.remove_tag_by_name("search-match", &buffer.start_iter(), &buffer.end_iter());
+
+            let search_text = entry.text();
+            if search_text.is_empty() {
+                return;
+            }
+
+            if let Some((mut first_match, _)) =
+                highlight_log_matches(&buffer, &buffer.start_iter(), &search_text)
+            {
+                buffer.place_cursor(&first_match);
+                text_view.scroll_to_iter(&mut first_match, 0.1, false, 0.0, 0.0);
+            }
+        }
+    ));
+
+    // Enter jumps to the next match, wrapping back to the start
+    search_entry.connect_activate(clone!(
+        #[weak]
+        text_view,
+        move |entry| {
+            let search_text = entry.text();
+            if search_text.is_empty() {
+                return;
+            }
+
+            let buffer = text_view.buffer();
+            let mut from = buffer.iter_at_mark(&buffer.get_insert());
+            from.forward_char();
+
+            let next_match = from
+                .forward_search(&search_text, gtk::TextSearchFlags::CASE_INSENSITIVE, None)
+                .or_else(|| {
+                    buffer.start_iter().forward_search(
+                        &search_text,
+                        gtk::TextSearchFlags::CASE_INSENSITIVE,
+                        None,
+                    )
+                });
+
+            if let Some((mut match_start, match_end)) = next_match {
+                buffer.select_range(&match_start, &match_end);
+                text_view.scroll_to_iter(&mut match_start, 0.1, false, 0.0, 0.0);
+            }
+        }
+    ));
+
+    save_btn.connect_clicked(clone!(
+        #[weak]
+        logs_popup,
+        #[weak]
+        text_view,
+        #[weak]
+        toast_overlay,
+        #[strong]
+        box_name,
+        move |_btn| {
+            let file_dialog = FileDialog::builder()
+                .initial_name(format!("{box_name}.log"))
+                .modal(true)
+                .build();
+
+            file_dialog.save(
+                Some(&logs_popup),
+                None::<&gio::Cancellable>,
+                clone!(
+                    #[weak]
+                    text_view,
+                    #[weak]
+                    toast_overlay,
+                    move |result| {
+                        if let Ok(file) = result {
+                            let buffer = text_view.buffer();
+                            let contents =
+                                buffer.text(&buffer.start_iter(), &buffer.end_iter(), false);
+
+                            let saved = file.path().is_some_and(|path| {
+                                std::fs::write(path, contents.as_str()).is_ok()
+                            });
+
+                            let toast = if saved {
+                                // TRANSLATORS: Success Message
+                                adw::Toast::new(&gettext("Logs Saved!"))
+                            } else {
+                                // TRANSLATORS: Error Message
+                                adw::Toast::new(&gettext("Sorry, Logs Could Not Be Saved"))
+                            };
+                            toast_overlay.add_toast(toast);
+                        }
+                    }
+                ),
+            );
+        }
+    ));
+
+    logs_popup.connect_close_request(clone!(
+        #[strong]
+        viewer,
+        move |_popup| {
+            stop_box_logs(&viewer);
+            glib::Propagation::Proceed
+        }
+    ));
+
+    logs_popup.present();
+
+    start_box_logs(&viewer);
+}
+
+/// (Re)starts streaming a box's logs into the Logs window, using the current
+/// state of the Follow and Timestamps toggles.
+fn start_box_logs(viewer: &LogsViewer) {
+    stop_box_logs(viewer);
+
+    let generation = viewer.generation.get() + 1;
+    viewer.generation.set(generation);
+
+    let buffer = viewer.text_view.buffer();
+    buffer.set_text("");
+
+    let mut child = match spawn_box_logs(
+        &viewer.box_name,
+        viewer.follow_btn.is_active(),
+        viewer.timestamps_btn.is_active(),
+    ) {
+        Ok(c) => c,
+        Err(e) => {
+            buffer.set_text(&e.to_string());
+            return;
+        }
+    };
+
+    let (sender, receiver) = async_channel::unbounded();
+
+    // the runtime writes container output to both stdout and stderr
+    if let Some(stdout) = child.stdout.take() {
+        let sender = sender.clone();
+        thread::spawn(move || {
+            for line in BufReader::new(stdout).lines().map_while(Result::ok) {
+                if sender.send_blocking(LogsMessage::Line(line)).is_err() {
+                    break;
+                }
+            }
+        });
+    }
+
+    if let Some(stderr) = child.stderr.take() {
+        thread::spawn(move || {
+            for line in BufReader::new(stderr).lines().map_while(Result::ok) {
+                if sender.send_blocking(LogsMessage::Line(line)).is_err() {
+                    break;
+                }
+            }
+        });
+    }
+
+    *viewer.logs_process.borrow_mut() = Some(child);
+
+    let viewer = viewer.clone();
+    glib::spawn_future_local(async move {
+        while let Ok(msg) = receiver.recv().await {
+            if viewer.generation.get() != generation {
+                break;
+            }
+
+            match msg {
+                LogsMessage::Line(line) => append_log_line(&viewer, &line),
+            }
+        }
+    });
+}
+
+fn stop_box_logs(viewer: &LogsViewer) {
+    if let Some(mut child) = viewer.logs_process.borrow_mut().take() {
+        gio::spawn_blocking(move || stop_spawned_command(&mut child));
+    }
+}
+
+fn append_log_line(viewer: &LogsViewer, line: &str) {
+    let buffer = viewer.text_view.buffer();
+    let line_start = buffer.end_iter().offset();
+
+    buffer.insert(&mut buffer.end_iter(), &format!("{line}\n"));
+
+    let search_text = viewer.search_entry.text();
+    if !search_text.is_empty() {
+        highlight_log_matches(&buffer, &buffer.iter_at_offset(line_start), &search_text);
+    }
+
+    if viewer.follow_btn.is_active() {
+        viewer.text_view.scroll_mark_onscreen(&viewer.end_mark);
+    }
+}
+
+/// Highlights every match of `search_text` after `from`, returning the first one.
+fn highlight_log_matches(
+    buffer: &gtk::TextBuffer,
+    from: &gtk::TextIter,
+    search_text: &str,
+) -> Option<(gtk::TextIter, gtk::TextIter)> {
+    let mut first_match = None;
+    let mut iter = *from;
+
+    while let Some((match_start, match_end)) =
+        iter.forward_search(search_text, gtk::TextSearchFlags::CASE_INSENSITIVE, None)
+    {
+        buffer.apply_tag_by_name("search-match", &match_start, &match_end);
+        if first_match.is_none() {
+            first_match = Some((match_start, match_end));
+        }
+        iter = match_end;
+    }
+
+    first_match
+}
+
+/// Exports an app with its saved options, then returns whether it's now in the host's menu
+fn add_app_to_menu(app: &DBoxApp, box_name: &str) -> bool {
+    let options = get_export_options(box_name, &app.desktop_file);
+    let _ = export_app_from_box(&app.desktop_file_path, box_name, &options);
+
+    is_app_exported(app, box_name)
+}
+
+/// Unexports an app, then returns whether it's still in the host's menu
+fn remove_app_from_menu(app: &DBoxApp, box_name: &str) -> bool {
+    let _ = remove_app_from_host(&app.desktop_file_path, box_name);
+
+    is_app_exported(app, box_name)
+}
+
+fn set_app_menu_button_label(btn: &gtk::Button, is_on_host: bool) {
+    if is_on_host {
+        //TRANSLATORS: Button Label
+        btn.set_label(&gettext("Remove From Menu"));
+    } else {
+        //TRANSLATORS: Button Label
+        btn.set_label(&gettext("Add To Menu"));
+    }
+}
+
+/// Builds an app's "Add To Menu" or "Remove From Menu" button. After each click
+/// the host's menu is checked again, and the button switches to match it.
+fn make_app_menu_button(
+    app: &DBoxApp,
+    is_on_host: &Rc<Cell<bool>>,
+    box_name: &str,
+    status_lbl: &gtk::Label,
+) -> gtk::Button {
+    let menu_btn = gtk::Button::new();
+    menu_btn.add_css_class("pill");
+    menu_btn.set_width_request(200);
+    menu_btn.set_valign(gtk::Align::Center);
+    set_app_menu_button_label(&menu_btn, is_on_host.get());
+
+    let is_on_host = is_on_host.clone();
+    let app = app.clone();
+    let box_name = box_name.to_string();
+    let status_lbl = status_lbl.clone();
+
+    menu_btn.connect_clicked(move |btn| {
+        btn.set_sensitive(false);
+
+        let removing = is_on_host.get();
+        let app = app.clone();
+        let box_name = box_name.clone();
+        let (sender, receiver) = async_channel::bounded(1);
+
+        gio::spawn_blocking(move || {
+            let now_on_host = if removing {
+                remove_app_from_menu(&app, &box_name)
+            } else {
+                add_app_to_menu(&app, &box_name)
+            };
+            sender
+                .send_blocking(AppMenuMessage::Checked(now_on_host))
+                .expect("The channel needs to be open.");
+        });
+
+        glib::spawn_future_local(clone!(
+            #[weak]
+            btn,
+            #[weak]
+            status_lbl,
+            #[strong]
+            is_on_host,
+            async move {
+                while let Ok(msg) = receiver.recv().await {
+                    match msg {
+                        AppMenuMessage::Checked(now_on_host) => {
+                            let status = match (removing, now_on_host) {
+                                //TRANSLATORS: Success Message
+                                (false, true) => gettext("App Exported!"),
+                                //TRANSLATORS: Success Message
+                                (true, false) => gettext("App Removed!"),
+                                //TRANSLATORS: Error Message
+                                (false, false) => gettext("Couldn't add the app to your menu"),
+                                //TRANSLATORS: Error Message
+                                (true, true) => gettext("Couldn't remove the app from your menu"),
+                            };
+                            status_lbl.set_text(&status);
+
+                            is_on_host.set(now_on_host);
+                            set_app_menu_button_label(&btn, now_on_host);
+                            btn.set_sensitive(true);
+                        }
+                    }
+                }
+            }
+        ));
+    });
+
+    menu_btn
+}
+
+fn on_export_options_clicked(
+    parent: &gtk::Window,
+    app: DBoxApp,
+    box_name: String,
+    success_lbl: gtk::Label,
+) {
+    let options_popup = gtk::Window::builder()
+        .transient_for(parent)
+        .default_width(600)
+        .default_height(250)
+        .modal(true)
+        .build();
+
+    // TRANSLATORS: Window Title - has app name appended
+    let title_prefix = &gettext("Export Options");
+    options_popup.set_title(Some(&format!("{} - {}", title_prefix, app.name)));
+
+    // TRANSLATORS: Button Label
+    let save_btn = gtk::Button::with_label(&gettext("Save"));
+    save_btn.add_css_class("suggested-action");
+
+    // TRANSLATORS: Button Label
+    let cancel_btn = gtk::Button::with_label(&gettext("Cancel"));
+
+    cancel_btn.connect_clicked(move |btn| {
+        let win = btn.root().and_downcast::<gtk::Window>().unwrap();
+        win.destroy();
+    });
+
+    let titlebar = adw::HeaderBar::new();
+    titlebar.set_show_end_title_buttons(false);
+    titlebar.pack_end(&save_btn);
+    titlebar.pack_start(&cancel_btn);
+
+    options_popup.set_titlebar(Some(&titlebar));
+
+    let main_box = gtk::Box::new(Orientation::Vertical, 20);
+    main_box.set_margin_start(10);
+    main_box.set_margin_end(10);
+    main_box.set_margin_top(10);
+    main_box.set_margin_bottom(10);
+
+    // TRANSLATORS: Explanation in the Export Options pop-up
+    let explanation_lbl = gtk::Label::new(Some(&gettext(
+        "These options are used whenever this app is added to your menu.",
+    )));
+    explanation_lbl.set_wrap(true);
+
+    let options = get_export_options(&box_name, &app.desktop_file);
+
+    let boxed_list = gtk::ListBox::new();
+    boxed_list.set_selection_mode(gtk::SelectionMode::None);
+    boxed_list.add_css_class("boxed-list");
+
+    let extra_flags_row = adw::EntryRow::new();
+    // TRANSLATORS: Entry Label - flags added to the app's command, e.g. --ozone-platform=wayland
+    extra_flags_row.set_title(&gettext("Extra Launch Flags"));
+    extra_flags_row.set_text(options.extra_flags.as_deref().unwrap_or_default());
+
+    let label_row = adw::ComboRow::new();
+    // TRANSLATORS: Dropdown Label - text added after the app's name in the menu
+    label_row.set_title(&gettext("Menu Label"));
+    // TRANSLATORS: Dropdown subtitle - has distrobox's own label appended, e.g. "(on my-box)"
+    let default_label_prefix = &gettext("By default, apps are labelled");
+    label_row.set_subtitle(&markup_escape_text(&format!(
+        "{} \"(on {})\"",
+        default_label_prefix, box_name
+    )));
+    label_row.set_model(Some(&gtk::StringList::new(&[
+        // TRANSLATORS: Dropdown option
+        &gettext("Default"),
+        // TRANSLATORS: Dropdown option - don't label the app at all
+        &gettext("No Label"),
+        // TRANSLATORS: Dropdown option
+        &gettext("Custom"),
+    ])));
+
+    let custom_label_row = adw::EntryRow::new();
+    // TRANSLATORS: Entry Label
+    custom_label_row.set_title(&gettext("Custom Label"));
+
+    match &options.label {
+        ExportLabel::Default => label_row.set_selected(0),
+        ExportLabel::None => label_row.set_selected(1),
+        ExportLabel::Custom(label) => {
+            label_row.set_selected(2);
+            custom_label_row.set_text(label);
+        }
+    }
+    custom_label_row.set_sensitive(label_row.selected() == 2);
+
+    let custom_label_row_clone = custom_label_row.clone();
+    label_row.connect_selected_notify(move |row| {
+        custom_label_row_clone.set_sensitive(row.selected() == 2);
+    });
+
+    boxed_list.append(&extra_flags_row);
+    boxed_list.append(&label_row);
+    boxed_list.append(&custom_label_row);
+
+    save_btn.connect_clicked(move |btn| {
+        let win = btn.root().and_downcast::<gtk::Window>().unwrap();
+
+        let extra_flags = extra_flags_row.text().trim().to_string();
+        let custom_label = custom_label_row.text().trim().to_string();
+
+        let options = AppExportOptions {
+            extra_flags: (!extra_flags.is_empty()).then_some(extra_flags),
+            label: match label_row.selected() {
+                1 => ExportLabel::None,
+                2 if !custom_label.is_empty() => ExportLabel::Custom(custom_label),
+                _ => ExportLabel::Default,
+            },
+        };
+
+        if let Err(e) = save_export_options(&box_name, &app.desktop_file, &options) {
+            let d = adw::MessageDialog::new(
+                Some(&win),
+                //TRANSLATORS: Error Message
+                Some(&gettext("Couldn't save the export options")),
+                Some(e.message()),
+            );
+            //TRANSLATORS: Button Label
+            d.add_response("ok", &gettext("Ok"));
+            d.present();
+            return;
+        }
+
+        btn.set_sensitive(false);
+
+        let app = app.clone();
+        let box_name = box_name.clone();
+        let success_lbl = success_lbl.clone();
+        let (sender, receiver) = async_channel::bounded(1);
+
+        // export again, so the menu entry picks up the new options
+        gio::spawn_blocking(move || {
+            let reexported =
+                is_app_exported(&app, &box_name).then(|| add_app_to_menu(&app, &box_name));
+            sender
+                .send_blocking(ExportOptionsMessage::Saved(reexported))
+                .expect("The channel needs to be open.");
+        });
+
+        glib::spawn_future_local(async move {
+            while let Ok(msg) = receiver.recv().await {
+                match msg {
+                    ExportOptionsMessage::Saved(reexported) => {
+                        let status = match reexported {
+                            //TRANSLATORS: Success Message
+                            Some(true) => gettext("App Exported!"),
+                            //TRANSLATORS: Error Message
+                            Some(false) => gettext("Couldn't add the app to your menu"),
+                            None => String::new(),
+                        };
+                        if !status.is_empty() {
+                            success_lbl.set_text(&status);
+                        }
+                        win.destroy();
+                    }
+                }
+            }
+        });
+    });
+
+    main_box.append(&explanation_lbl);
+    main_box.append(&boxed_list);
+
+    options_popup.set_child(Some(&main_box));
+    options_popup.present();
+}
+
+/// Shows the Export Binary pop-up. Once a binary is exported, `success_lbl` says
+/// so and the exported binaries in `exported_binaries_area` are listed again.
+fn on_export_binary_clicked(
+    parent: &gtk::Window,
+    box_name: String,
+    success_lbl: gtk::Label,
+    exported_binaries_area: gtk::Box,
+) {
+    let export_popup = gtk::Window::builder()
+        .transient_for(parent)
+        .default_width(600)
+        .default_height(550)
+        .modal(true)
+        .build();
+
+    // TRANSLATORS: Window Title - has box name appended
+    let title_prefix = &gettext("Export Binary");
+    export_popup.set_title(Some(&format!("{} - {}", title_prefix, box_name)));
+
+    // TRANSLATORS: Button Label
+    let export_btn = gtk::Button::with_label(&gettext("Export"));
+    export_btn.add_css_class("suggested-action");
+    export_btn.set_sensitive(false);
+
+    // TRANSLATORS: Button Label
+    let cancel_btn = gtk::Button::with_label(&gettext("Cancel"));
+
+    cancel_btn.connect_clicked(move |btn| {
+        let win = btn.root().and_downcast::<gtk::Window>().unwrap();
+        win.destroy();
+    });
+
+    let titlebar = adw::HeaderBar::new();
+    titlebar.set_show_end_title_buttons(false);
+    titlebar.pack_end(&export_btn);
+    titlebar.pack_start(&cancel_btn);
+
+    export_popup.set_titlebar(Some(&titlebar));
+
+    let main_box = gtk::Box::new(Orientation::Vertical, 10);
+    main_box.set_margin_start(10);
+    main_box.set_margin_end(10);
+    main_box.set_margin_top(10);
+    main_box.set_margin_bottom(10);
+
+    // TRANSLATORS: Explanation in the Export Binary pop-up
+    let explanation_lbl = gtk::Label::new(Some(&gettext(
+        "Choose a command from the box to make it available on your host.",
+    )));
+    explanation_lbl.set_wrap(true);
+
+    let search_entry = gtk::SearchEntry::new();
+    // TRANSLATORS: Search placeholder
+    search_entry.set_placeholder_text(Some(&gettext("Search commands in the box")));
+
+    let loading_spinner = gtk::Spinner::new();
+    loading_spinner.start();
+
+    let binaries_list = gtk::ListBox::new();
+    binaries_list.set_selection_mode(gtk::SelectionMode::Single);
+    binaries_list.add_css_class("boxed-list");
+
+    let scrolled_win = gtk::ScrolledWindow::new();
+    scrolled_win.set_vexpand(true);
+    scrolled_win.set_child(Some(&loading_spinner));
+
+    let search_entry_clone = search_entry.clone();
+    binaries_list.set_filter_func(move |row| {
+        let search = search_entry_clone.text().to_lowercase();
+        search.is_empty()
+            || row
+                .downcast_ref::<adw::ActionRow>()
+                .is_some_and(|r| r.title().to_lowercase().contains(&search))
+    });
+
+    let binaries_list_clone = binaries_list.clone();
+    search_entry.connect_search_changed(move |_entry| {
+        binaries_list_clone.invalidate_filter();
+    });
+
+    let path_list = gtk::ListBox::new();
+    path_list.set_selection_mode(gtk::SelectionMode::None);
+    path_list.add_css_class("boxed-list");
+
+    let export_path_row = adw::EntryRow::new();
+    // TRANSLATORS: Entry Label - the folder on the host the command is exported to
+    export_path_row.set_title(&gettext("Export To"));
+    export_path_row.set_text(
+        &glib::home_dir()
+            .join(".local")
+            .join("bin")
+            .to_string_lossy(),
+    );
+    path_list.append(&export_path_row);
+
+    let warning_lbl = gtk::Label::new(None);
+    warning_lbl.add_css_class("warning");
+    warning_lbl.set_wrap(true);
+    warning_lbl.set_visible(false);
+
+    let error_lbl = gtk::Label::new(None);
+    error_lbl.add_css_class("error");
+    error_lbl.set_wrap(true);
+    error_lbl.set_visible(false);
+
+    main_box.append(&explanation_lbl);
+    main_box.append(&search_entry);
+    main_box.append(&scrolled_win);
+    main_box.append(&path_list);
+    main_box.append(&warning_lbl);
+    main_box.append(&error_lbl);
+
+    export_popup.set_child(Some(&main_box));
+    export_popup.present();
+
+    // full paths, in the same order as the rows
+    let binaries: Rc<RefCell<Vec<String>>> = Rc::new(RefCell::new(vec![]));
+
+    let get_selected_binary = Rc::new(clone!(
+        #[weak]
+        binaries_list,
+        #[strong]
+        binaries,
+        #[upgrade_or]
+        None,
+        move || -> Option<String> {
+            let index = usize::try_from(binaries_list.selected_row()?.index()).ok()?;
+            binaries.borrow().get(index).cloned()
+        }
+    ));
+
+    // bumped on each change, so a check of an earlier choice is ignored
+    let clash_check_generation: Rc<Cell<u32>> = Rc::default();
+
+    // checks the choices, warning about anything which would clash on the host.
+    // The host is only looked at once typing pauses, and off the UI thread.
+    let get_selected_binary_clone = get_selected_binary.clone();
+    let update_warning = Rc::new(clone!(
+        #[weak]
+        export_path_row,
+        #[weak]
+        warning_lbl,
+        #[weak]
+        export_btn,
+        move || {
+            let generation = clash_check_generation.get() + 1;
+            clash_check_generation.set(generation);
+
+            let Some(binary) = get_selected_binary_clone() else {
+                warning_lbl.set_visible(false);
+                export_btn.set_sensitive(false);
+                return;
+            };
+            let name = binary.rsplit('/').next().unwrap_or_default().to_string();
+            let export_path = expand_home_path(export_path_row.text().trim());
+
+            export_btn.set_sensitive(export_path.starts_with('/'));
+            if !export_path.starts_with('/') {
+                // TRANSLATORS: Error Message
+                warning_lbl.set_text(&gettext("The export folder must be a full path"));
+                warning_lbl.set_visible(true);
+                return;
+            }
+            warning_lbl.set_visible(false);
+
+            let clash_check_generation = clash_check_generation.clone();
+            glib::timeout_add_local_once(
+                Duration::from_millis(300),
+                clone!(
+                    #[weak]
+                    warning_lbl,
+                    move || {
+                        if clash_check_generation.get() != generation {
+                            return;
+                        }
+
+                        let (sender, receiver) = async_channel::bounded(1);
+                        let name_clone = name.clone();
+
+                        gio::spawn_blocking(move || {
+                            let path =
+                                format!("{}/{}", export_path.trim_end_matches('/'), name_clone);
+                            sender
+                                .send_blocking(ExportClashMessage::Checked(
+                                    host_path_exists(&path),
+                                    has_command_installed(&name_clone),
+                                ))
+                                .expect("The channel needs to be open.");
+                        });
+
+                        glib::spawn_future_local(async move {
+                            while let Ok(msg) = receiver.recv().await {
+                                match msg {
+                                    ExportClashMessage::Checked(path_exists, command_installed) => {
+                                        if clash_check_generation.get() != generation {
+                                            continue;
+                                        }
+
+                                        let warning = if path_exists {
+                                            // TRANSLATORS: Warning Message - has the command's name appended
+                                            let prefix = &gettext(
+                                                "The export folder already contains a file called",
+                                            );
+                                            Some(format!("{} {}", prefix, name))
+                                        } else if command_installed {
+                                            // TRANSLATORS: Warning Message - has the command's name appended
+                                            let prefix =
+                                                &gettext("Your host already has a command called");
+                                            Some(format!(
+                                                "{} {}. {}",
+                                                prefix,
+                                                name,
+                                                // TRANSLATORS: Warning Message
+                                                gettext(
+                                                    "Whichever comes first in your PATH will be run."
+                                                )
+                                            ))
+                                        } else {
+                                            None
+                                        };
+
+                                        warning_lbl.set_visible(warning.is_some());
+                                        warning_lbl.set_text(&warning.unwrap_or_default());
+                                    }
+                                }
+                            }
+                        });
+                    }
+                ),
+            );
+        }
+    ));
+
+    let update_warning_clone = update_warning.clone();
+    binaries_list.connect_row_selected(move |_list, _row| update_warning_clone());
+    let update_warning_clone = update_warning.clone();
+    export_path_row.connect_changed(move |_row| update_warning_clone());
+
+    let (sender, receiver) = async_channel::bounded(1);
+    let box_name_clone = box_name.clone();
+
+    gio::spawn_blocking(move || {
+        let binaries = get_binaries_on_box_path(&box_name_clone);
+        sender
+            .send_blocking(BinariesFetchMessage::BinariesFetched(binaries))
+            .expect("The channel needs to be open.");
+    });
+
+    glib::spawn_future_local(clone!(
+        #[weak]
+        scrolled_win,
+        #[weak]
+        binaries_list,
+        #[strong]
+        binaries,
+        async move {
+            while let Ok(msg) = receiver.recv().await {
+                match msg {
+                    BinariesFetchMessage::BinariesFetched(found) => {
+                        for binary in &found {
+                            let row = adw::ActionRow::new();
+                            row.set_title(&markup_escape_text(
+                                binary.rsplit('/').next().unwrap_or_default(),
+                            ));
+                            row.set_subtitle(&markup_escape_text(binary));
+                            binaries_list.append(&row);
+                        }
+                        *binaries.borrow_mut() = found;
+
+                        scrolled_win.set_child(Some(&binaries_list));
+                    }
+                }
+            }
+        }
+    ));
+
+    export_btn.connect_clicked(move |btn| {
+        let Some(binary) = get_selected_binary() else {
+            return;
+        };
+        let export_path = expand_home_path(export_path_row.text().trim());
+
+        btn.set_sensitive(false);
+        error_lbl.set_visible(false);
+        let win = btn.root().and_downcast::<gtk::Window>().unwrap();
+        let success_lbl = success_lbl.clone();
+
+        let (sender, receiver) = async_channel::bounded(1);
+        let box_name_clone = box_name.clone();
+
+        gio::spawn_blocking(move || {
+            let result = export_binary_from_box(&box_name_clone, &binary, &export_path);
+            let binaries = get_binaries_exported_from_box(&box_name_clone);
+            sender
+                .send_blocking(BinaryExportMessage::Exported(result, binaries))
+                .expect("The channel needs to be open.");
+        });
+
+        let box_name = box_name.clone();
+        glib::spawn_future_local(clone!(
+            #[weak]
+            btn,
+            #[weak]
+            error_lbl,
+            #[weak]
+            exported_binaries_area,
+            async move {
+                while let Ok(msg) = receiver.recv().await {
+                    match msg {
+                        BinaryExportMessage::Exported(result, binaries) => {
+                            fill_exported_binaries(&exported_binaries_area, &box_name, binaries);
+
+                            match result {
+                                Ok(_) => {
+                                    //TRANSLATORS: Success Message
+                                    success_lbl.set_text(&gettext("Binary Exported!"));
+                                    win.destroy();
+                                }
+                                Err(error) => {
+                                    // the runtime's actual complaint is on the last line
+                                    error_lbl.set_text(error.lines().last().unwrap_or_default());
+                                    error_lbl.set_visible(true);
+                                    btn.set_sensitive(true);
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        ));
+    });
+}
+
+/// Expands a leading `~` to the user's home directory
+fn expand_home_path(path: &str) -> String {
+    match path.strip_prefix('~') {
+        Some(rest) if rest.is_empty() || rest.starts_with('/') => {
+            format!("{}{}", glib::home_dir().to_string_lossy(), rest)
+        }
+        _ => path.to_string(),
+    }
+}
+
+fn remove_exported_binary(box_name: &str, binary: &str, row: &adw::ActionRow) {
+    remove_exported_binary_from_box(&box_name, &binary);
+    row.set_title("Removed!");
+}
+
+fn run_app_in_box(app: &DBoxApp, box_name: &str) {
+    if app.terminal {
+        run_command_in_box_in_terminal(&app.exec_args, box_name);
+    } else {
+        run_command_in_box(&app.exec_args, box_name);
+    }
+}
+
+/// Stops a box, first asking for confirmation if `confirm-before-stop` is set
+fn on_stop_clicked(window: &ApplicationWindow, box_name: String, tab_num: u32) {
+    let settings = Settings::new(APP_ID);
+    if !settings.boolean("confirm-before-stop") {
+        stop_box(&box_name);
+        delayed_rerender(window, Some(tab_num));
+        return;
+    }
+
+    let d = adw::MessageDialog::new(
+        Some(window),
+        //TRANSLATORS: Confirmation Dialogue
+        Some(&gettext("Really Stop?")),
+        //TRANSLATORS: Confirmation Dialogue - {} replaced with the name of the Distrobox
+        Some(&gettext(&format!(
+            "Are you sure you want to stop {}?",
+            box_name
+        ))),
+    );
+    d.set_transient_for(Some(window));
+    //TRANSLATORS: Button Label
+    d.add_response("cancel", &gettext("Cancel"));
+    //TRANSLATORS: Button Label
+    d.add_response("stop", &gettext("Stop"));
+    d.set_default_response(Some("cancel"));
+    d.set_close_response("cancel");
+    d.set_response_appearance("stop", adw::ResponseAppearance::Destructive);
+
+    let win_clone = window.clone();
+
+    d.connect_response(None, move |d, res| {
+        if res == "stop" {
+            stop_box(&box_name);
+            d.destroy();
+
+            delayed_rerender(&win_clone, Some(tab_num));
+        }
+    });
+
+    d.present();
+}
+
+fn on_delete_clicked(window: &ApplicationWindow, box_name: String) {
+    let d = adw::MessageDialog::new(
+        Some(window),
+        //TRANSLATORS: Confirmation Dialogue
+        Some(&gettext("Really Delete?")),
+        //TRANSLATORS: Confirmation Dialogue - {} replaced with the name of the Distrobox
+        Some(&gettext(&format!(
+            "Are you sure you want to delete {}?",
+            box_name
+        ))),
+    );
+    d.set_transient_for(Some(window));
+    //TRANSLATORS: Button Label
+    d.add_response("cancel", &gettext("Cancel"));
+    //TRANSLATORS: Button Label
+    d.add_response("delete", &gettext("Delete"));
+    d.set_default_response(Some("cancel"));
+    d.set_close_response("cancel");
+    d.set_response_appearance("delete", adw::ResponseAppearance::Destructive);
+
+    // TRANSLATORS: Checkbox Label - in the delete confirmation
+    let unexport_check =
+        gtk::CheckButton::with_label(&gettext("Also remove this box's apps from your menu"));
+    unexport_check.set_active(true);
+    d.set_extra_child(Some(&unexport_check));
+
+    let win_clone = window.clone();
+
+    d.connect_response(None, move |d, res| {
+        if res == "delete" {
+            let unexport_apps = unexport_check.is_active();
+            d.destroy();
+
+            let (sender, receiver) = async_channel::bounded(1);
+            let box_name = box_name.clone();
+
+            gio::spawn_blocking(move || {
+                let unexport_error = if unexport_apps {
+                    unexport_all_apps_from_box(&box_name).err()
+                } else {
+                    None
+                };
+                delete_box(&box_name);
+                sender
+                    .send_blocking(BoxDeleteMessage::Deleted(unexport_error))
+                    .expect("The channel needs to be open.");
+            });
+
+            let win_clone = win_clone.clone();
+            glib::spawn_future_local(async move {
+                while let Ok(msg) = receiver.recv().await {
+                    match msg {
+                        BoxDeleteMessage::Deleted(unexport_error) => {
+                            //TRANSLATORS: Success Text
+                            let toast = adw::Toast::new(&gettext("Box Deleted!"));
+                            if let Some(child) = win_clone.clone().child() {
+                                let toast_area = child.downcast::<ToastOverlay>().unwrap();
+                                toast_area.add_toast(toast);
+
+                                if let Some(error) = unexport_error {
+                                    let toast = adw::Toast::new(&format!(
+                                        "{} {}",
+                                        //TRANSLATORS: Error Message - has the reason appended
+                                        gettext("Couldn't remove the box's apps from your menu:"),
+                                        // the actual complaint is on the last line
+                                        error.lines().last().unwrap_or_default()
+                                    ));
+                                    toast.set_timeout(0);
+                                    toast_area.add_toast(toast);
+                                }
+                            }
+
+                            delayed_rerender(&win_clone, None);
+                        }
+                    }
+                }
+            });
+        }
+    });
+
+    d.present();
+}
+
+fn on_recreate_clicked(window: &ApplicationWindow, box_name: String, image: String) {
+    let recreate_popup = gtk::Window::builder()
+        .transient_for(window)
+        .default_width(700)
+        .default_height(250)
+        .modal(true)
+        .build();
+
+    // TRANSLATORS: Heading Label - has box name appended
+    let recreate_prefix = &gettext("Recreate");
+    recreate_popup.set_title(Some(&format!("{} {}", recreate_prefix, box_name)));
+
+    // TRANSLATORS: Button Label
+    let recreate_btn = gtk::Button::with_label(&gettext("Recreate"));
+    recreate_btn.add_css_class("destructive-action");
+
+    // TRANSLATORS: Button Label
+    let cancel_btn = gtk::Button::with_label(&gettext("Cancel"));
+
+    cancel_btn.connect_clicked(move |btn| {
+        let win = btn.root().and_downcast::<gtk::Window>().unwrap();
+        win.destroy();
+    });
+
+    let titlebar = adw::HeaderBar::new();
+    titlebar.set_show_end_title_buttons(false);
+    titlebar.pack_end(&recreate_btn);
+    titlebar.pack_start(&cancel_btn);
+
+    recreate_popup.set_titlebar(Some(&titlebar));
+
+    let main_box = gtk::Box::new(Orientation::Vertical, 20);
+    main_box.set_margin_start(10);
+    main_box.set_margin_end(10);
+    main_box.set_margin_top(10);
+    main_box.set_margin_bottom(10);
+
+    // TRANSLATORS: Explanation in the Recreate pop-up
+    let explanation = gettext(
+        "The newest version of the box's image will be downloaded, then the box will be deleted and created again from it.",
+    );
+    let explanation_lbl = gtk::Label::new(Some(&explanation));
+    explanation_lbl.set_wrap(true);
+
+    // TRANSLATORS: Warning in the Recreate pop-up
+    let warning_lbl = gtk::Label::new(Some(&gettext(
+        "Files in the box's home directory are kept, but anything installed inside the box will need installing again.",
+    )));
+    warning_lbl.add_css_class("warning");
+    warning_lbl.set_wrap(true);
+
+    // TRANSLATORS: Warning in the Recreate pop-up, for boxes made outside BoxBuddy
+    let incomplete_lbl = gtk::Label::new(Some(&gettext(
+        "This box wasn't created by BoxBuddy, so any volumes or container manager flags it was given won't be kept.",
+    )));
+    incomplete_lbl.add_css_class("warning");
+    incomplete_lbl.set_wrap(true);
+    incomplete_lbl.set_visible(false);
+
+    // what the new box keeps from the old one
+    let boxed_list = gtk::ListBox::new();
+    boxed_list.set_selection_mode(gtk::SelectionMode::None);
+    boxed_list.add_css_class("boxed-list");
+
+    let image_row = ActionRow::new();
+    // TRANSLATORS: Row Label
+    image_row.set_title(&gettext("Image"));
+    image_row.set_subtitle(&image);
+    boxed_list.append(&image_row);
+
+    let option_rows = [
+        // TRANSLATORS: Row Label - the home directory a recreated box keeps
+        gettext("Home Directory"),
+        // TRANSLATORS: Row Label
+        gettext("Use Init System"),
+        // TRANSLATORS: Row Label
+        gettext("NVIDIA Drivers"),
+        // TRANSLATORS: Row Label
+        gettext("Additional Volumes"),
+        // TRANSLATORS: Row Label
+        gettext("Additional Packages"),
+    ]
+    .map(|title| {
+        let row = ActionRow::new();
+        row.set_title(&title);
+        // TRANSLATORS: Loading Message
+        row.set_subtitle(&gettext("Loading..."));
+        boxed_list.append(&row);
+        row
+    });
+
+    recreate_btn.set_sensitive(false);
+
+    let recreate_options: Rc<RefCell<Option<BoxCreateOptions>>> = Rc::default();
+
+    let (sender, receiver) = async_channel::bounded(1);
+    let bn = box_name.clone();
+    let img = image.clone();
+    gio::spawn_blocking(move || {
+        let (options, is_complete) = get_box_create_options(&bn, &img);
+        sender
+            .send_blocking(BoxOptionsFetchMessage::OptionsFetched(options, is_complete))
+            .expect("The channel needs to be open.");
+    });
+
+    glib::spawn_future_local(clone!(
+        #[weak]
+        recreate_btn,
+        #[weak]
+        incomplete_lbl,
+        #[strong]
+        recreate_options,
+        async move {
+            while let Ok(msg) = receiver.recv().await {
+                match msg {
+                    BoxOptionsFetchMessage::OptionsFetched(options, is_complete) => {
+                        let [home_row, init_row, nvidia_row, volumes_row, packages_row] =
+                            &option_rows;
+
+                        home_row.set_subtitle(&markup_escape_text(
+                            &options
+                                .home_path
+                                .clone()
+                                // TRANSLATORS: Row subtitle - the box shares the host's home directory
+                                .unwrap_or_else(|| gettext("Your home directory")),
+                        ));
+                        init_row.set_subtitle(&if options.use_init {
+                            // TRANSLATORS: Row subtitle
+                            gettext("Yes")
+                        } else {
+                            // TRANSLATORS: Row subtitle
+                            gettext("No")
+                        });
+                        nvidia_row.set_subtitle(&match options.nvidia {
+                            // TRANSLATORS: Dropdown option - decide based on the detected drivers
+                            NvidiaMode::Auto => gettext("Automatic"),
+                            // TRANSLATORS: Dropdown option
+                            NvidiaMode::On => gettext("On"),
+                            // TRANSLATORS: Dropdown option
+                            NvidiaMode::Off => gettext("Off"),
+                        });
+
+                        // TRANSLATORS: Row subtitle - nothing was given for this option
+                        let none = gettext("None");
+                        for (row, values) in [
+                            (volumes_row, &options.volumes),
+                            (packages_row, &options.additional_packages),
+                        ] {
+                            row.set_subtitle(&if values.is_empty() {
+                                none.clone()
+                            } else {
+                                markup_escape_text(&values.join(", ")).to_string()
+                            });
+                        }
+
+                        incomplete_lbl.set_visible(!is_complete);
+                        recreate_options.replace(Some(options));
+                        recreate_btn.set_sensitive(true);
+                    }
+                }
+            }
+        }
+    ));
+
+    let pull_widgets = make_pull_progress_widgets();
+    let loading_spinner = gtk::Spinner::new();
+
+    let pull_process = pull_widgets.pull_process.clone();
+    recreate_popup.connect_destroy(move |_popup| {
+        if let Some(mut child) = pull_process.borrow_mut().take() {
+            gio::spawn_blocking(move || stop_spawned_command(&mut child));
+        }
+    });
+
+    let win_clone = window.clone();
+    let pw_clone = pull_widgets.clone();
+    let loading_spinner_clone = loading_spinner.clone();
+    recreate_btn.connect_clicked(move |btn| {
+        let Some(options) = recreate_options.borrow().clone() else {
+            return;
+        };
+
+        btn.set_sensitive(false);
+
+        let box_name = box_name.clone();
+        let win_clone = win_clone.clone();
+        let spinner = loading_spinner_clone.clone();
+        let btn_clone = btn.clone();
+        let error_lbl = pw_clone.error_lbl.clone();
+
+        pull_image_with_progress(
+            &image,
+            &pw_clone,
+            move || {
+                spinner.start();
+
+                let (sender, receiver) = async_channel::bounded(1);
+
+                gio::spawn_blocking(move || {
+                    sender
+                        .send_blocking(BoxCreateMessage::Finished(recreate_box(&options)))
+                        .expect("The channel needs to be open.");
+                });
+
+                glib::spawn_future_local(async move {
+                    while let Ok(msg) = receiver.recv().await {
+                        match msg {
+                            BoxCreateMessage::Finished(Err(error)) => {
+                                spinner.stop();
+
+                                // the runtime's actual complaint is on the last line
+                                error_lbl.set_text(error.lines().last().unwrap_or_default());
+                                error_lbl.set_visible(true);
+                                btn_clone.set_sensitive(true);
+
+                                // the old box may already be gone
+                                delayed_rerender(&win_clone, None);
+                            }
+                            BoxCreateMessage::Finished(Ok(_)) => {
+                                spinner.stop();
+
+                                // the box now runs the newest image
+                                IMAGE_UPDATES.set(None);
+
+                                if let Some(win) = btn_clone.root().and_downcast::<gtk::Window>() {
+                                    win.destroy();
+                                }
+
+                                // TRANSLATORS: Success Text
+                                let toast = adw::Toast::new(&gettext("Box Recreated!"));
+                                if let Some(child) = win_clone.child() {
+                                    let toast_area = child.downcast::<ToastOverlay>();
+                                    toast_area.unwrap().add_toast(toast);
+                                }
+
+                                let num_boxes = get_number_of_boxes();
+                                delayed_rerender(&win_clone, Some(num_boxes - 1));
+
+                                let settings = Settings::new(APP_ID);
+                                if settings.boolean("open-terminal-after-create") {
+                                    open_terminal_in_box(box_name.clone());
+                                }
+                            }
+                        }
+                    }
+                });
+            },
+            clone!(
+                #[weak]
+                btn,
+                move || btn.set_sensitive(true)
+            ),
+        );
+    });
+
+    main_box.append(&explanation_lbl);
+    main_box.append(&warning_lbl);
+    main_box.append(&incomplete_lbl);
+    main_box.append(&boxed_list);
+    main_box.append(&pull_widgets.container);
+    main_box.append(&pull_widgets.error_lbl);
+    main_box.append(&loading_spinner);
+
+    recreate_popup.set_child(Some(&main_box));
+
+    recreate_popup.present();
+}
+
+fn on_clone_clicked(window: &ApplicationWindow, box_name: String) {
+    let name_input_popup = gtk::Window::builder()
+        .transient_for(window)
+        .default_width(700)
+        .default_height(250)
+        .modal(true)
+        .build();
+
+    // TRANSLATORS: Heading Label - has box name appended
+    let clone_prefix = &gettext("Clone");
+    name_input_popup.set_title(Some(&format!("{} {}", clone_prefix, box_name.clone())));
+
+    // TRANSLATORS: Button Label
+    let create_btn = gtk::Button::with_label(&gettext("Clone"));
+    create_btn.add_css_class("suggested-action");
+
+    // TRANSLATORS: Button Label
+    let cancel_btn = gtk::Button::with_label(&gettext("Cancel"));
+
+    cancel_btn.connect_clicked(move |btn| {
+        let win = btn.root().and_downcast::<gtk::Window>().unwrap();
+        win.destroy();
+    });
+
+    let new_box_titlebar = adw::HeaderBar::new();
+    new_box_titlebar.set_show_end_title_buttons(false);
+
+    new_box_titlebar.pack_end(&create_btn);
+    new_box_titlebar.pack_start(&cancel_btn);
+
+    name_input_popup.set_titlebar(Some(&new_box_titlebar));
+
+    let main_box = gtk::Box::new(Orientation::Vertical, 20);
+    main_box.set_margin_start(10);
+    main_box.set_margin_end(10);
+    main_box.set_margin_top(10);
+    main_box.set_margin_bottom(10);
+
+    //TRANSLATORS: Title / Instruction label
+    let title_label = gtk::Label::new(Some(&gettext("Enter the name of your new box")));
+    title_label.add_css_class("title-2");
+
+    let notice_label = gtk::Label::new(Some(&gettext(
+        "Note: Cloning can take a long time, please be patient",
+    )));
+
+    let boxed_list = gtk::ListBox::new();
+    boxed_list.set_selection_mode(gtk::SelectionMode::None);
+    boxed_list.add_css_class("boxed-list");
+
+    // name input
+    let name_entry_row = adw::EntryRow::new();
+    name_entry_row.set_hexpand(true);
+
+    // TRANSLATORS: Entry Label - Name input for new distrobox
+    name_entry_row.set_title(&gettext("Name"));
+
+    let existing_names = get_existing_box_names();
+    let name_error_icon = make_box_name_error_icon(&name_entry_row);
+
+    create_btn.set_sensitive(false);
+    name_entry_row.connect_changed(clone!(
+        #[weak]
+        create_btn,
+        move |row| {
+            create_btn.set_sensitive(check_box_name_row(row, &name_error_icon, &existing_names));
+        }
+    ));
+
+    let loading_spinner = gtk::Spinner::new();
+
+    let loading_spinner_clone = loading_spinner.clone();
+    let win_clone = window.clone();
+    let ne_row = name_entry_row.clone();
+    create_btn.connect_clicked(move |btn| {
+        let name = get_new_box_name(&ne_row);
+
+        if name.is_empty() {
+            return;
+        }
+
+        loading_spinner_clone.start();
+        let name_clone = name.clone();
+        let bn = box_name.clone();
+
+        let (sender, receiver) = async_channel::bounded(1);
+
+        gio::spawn_blocking(move || {
+            clone_box(&bn, &name);
+            sender
+                .send_blocking(BoxCreatedMessage::Success)
+                .expect("The channel needs to be open.");
+        });
+
+        let b_clone = btn.clone();
+        let ls_clone = loading_spinner_clone.clone();
+        let w_clone = win_clone.clone();
+
+        glib::spawn_future_local(clone!(
+            #[weak]
+            ls_clone,
+            async move {
+                while let Ok(msg) = receiver.recv().await {
+                    match msg {
+                        BoxCreatedMessage::Success => {
+                            ls_clone.stop();
+
+                            let win = b_clone.root().and_downcast::<gtk::Window>().unwrap();
+                            win.destroy();
+
+                            let num_boxes = get_number_of_boxes();
+                            delayed_rerender(&w_clone, Some(num_boxes - 1));
+
+                            let settings = Settings::new(APP_ID);
+                            if settings.boolean("open-terminal-after-create") {
+                                open_terminal_in_box(name_clone.clone());
+                            }
+                        }
+                    }
+                }
+            }
+        ));
+    });
+
+    boxed_list.append(&name_entry_row);
+    main_box.append(&title_label);
+    main_box.append(&boxed_list);
+    main_box.append(&notice_label);
+    main_box.append(&loading_spinner);
+
+    name_input_popup.set_child(Some(&main_box));
+
+    name_input_popup.present();
+}
+
+fn delayed_rerender(window: &ApplicationWindow, active_page: Option<u32>) {
+    let main_box = window.child().unwrap().first_child().unwrap();
+    let main_box_as_box = main_box.downcast::<gtk::Box>().unwrap();
+
+    load_boxes(&main_box_as_box, window, active_page);
+}
 
 fn show_no_supported_terminal_popup(window: &ApplicationWindow) {
     let supported_terminals = get_supported_terminals_list();
@@ -1562,202 +5726,1177 @@ fn on_install_deb_clicked(window: &ApplicationWindow, box_name: String) {
                     }
                 }
             }
-        }),
-    );
+        }),
+    );
+}
+
+fn on_install_rpm_clicked(window: &ApplicationWindow, box_name: String) {
+    let rpm_filter = gtk::FileFilter::new();
+
+    //TRANSLATORS: File type
+    rpm_filter.set_name(Some(&gettext("RPM Files")));
+    rpm_filter.add_mime_type("application/x-rpm");
+
+    let download_dir = get_download_dir_path();
+
+    let file_dialog = FileDialog::builder()
+        .default_filter(&rpm_filter)
+        .initial_folder(&gio::File::for_path(download_dir))
+        .modal(false)
+        .build();
+    file_dialog.open(
+        Some(window),
+        None::<&gio::Cancellable>,
+        clone!(@weak window => move |result| {
+            if let Ok(file) = result {
+                let rpm_path = file.path().unwrap().into_os_string().into_string();
+                if rpm_path.is_ok() {
+                    let rp = rpm_path.unwrap();
+                    if rp.starts_with("/run/user") {
+                        show_sandbox_access_popup(&window);
+                    } else if !has_file_extension(&rp, "rpm") {
+                        show_incorrect_binary_file_popup(&window, BinaryPackageType::Rpm);
+                    } else {
+                        install_rpm_in_box(box_name, rp);
+                    }
+                }
+            }
+        }),
+    );
+}
+
+fn show_sandbox_access_popup(window: &ApplicationWindow) {
+    //TRANSLATORS: Error / Info Message
+    let message_body = gettext("This file is not accessible to Flatpak - please copy it to your Downloads folder, or allow filesystem access. Please see the <a href='https://dvlv.github.io/BoxBuddyRS/tips'>documentation for details.</a>");
+    let d = adw::MessageDialog::new(
+        Some(window),
+        //TRANSLATORS: Popup Heading
+        Some(&gettext("File Not Accessible")),
+        Some(&message_body),
+    );
+    d.set_transient_for(Some(window));
+    d.set_body_use_markup(true);
+    //TRANSLATORS: Button Label
+    d.add_response("ok", &gettext("Ok"));
+    d.set_default_response(Some("ok"));
+    d.set_close_response("ok");
+
+    d.present();
+}
+
+fn show_incorrect_binary_file_popup(window: &ApplicationWindow, file_type: BinaryPackageType) {
+    let pkg_type = match file_type {
+        BinaryPackageType::Deb => ".deb",
+        BinaryPackageType::Rpm => ".rpm",
+    };
+    //TRANSLATORS: Error / Info Message - {} replaced with .deb or .rpm
+    let message_body = gettext(&format!(
+        "This file does not appear to be a {} file",
+        pkg_type
+    ));
+    let d = adw::MessageDialog::new(
+        Some(window),
+        //TRANSLATORS: Popup Heading
+        Some(&gettext("Incorrect File Type")),
+        Some(&message_body),
+    );
+    d.set_transient_for(Some(window));
+    //TRANSLATORS: Button Label
+    d.add_response("ok", &gettext("Ok"));
+    d.set_default_response(Some("ok"));
+    d.set_close_response("ok");
+
+    d.present();
+}
+
+fn show_disk_usage_popup(window: &ApplicationWindow) {
+    let disk_usage_popup = gtk::Window::builder()
+        // TRANSLATORS: Window Title - shows the disk space used by each box
+        .title(gettext("Disk Usage"))
+        .transient_for(window)
+        .default_width(700)
+        .default_height(450)
+        .modal(true)
+        .build();
+
+    let titlebar = adw::HeaderBar::new();
+
+    let main_box = gtk::Box::new(Orientation::Vertical, 10);
+    main_box.set_margin_start(10);
+    main_box.set_margin_end(10);
+    main_box.set_margin_top(10);
+    main_box.set_margin_bottom(10);
+
+    let loading_spinner = gtk::Spinner::new();
+
+    // TRANSLATORS: Loading Message
+    let loading_lbl = gtk::Label::new(Some(&gettext("Loading...")));
+    loading_lbl.add_css_class("title-2");
+
+    let scrolled_win = gtk::ScrolledWindow::new();
+    scrolled_win.set_vexpand(true);
+    scrolled_win.set_hexpand(true);
+
+    let scroll_area = gtk::Box::new(gtk::Orientation::Vertical, 15);
+    scroll_area.set_vexpand(true);
+    scroll_area.set_hexpand(true);
+
+    scroll_area.append(&loading_lbl);
+    scroll_area.append(&loading_spinner);
+
+    scrolled_win.set_child(Some(&scroll_area));
+    main_box.append(&scrolled_win);
+
+    disk_usage_popup.set_child(Some(&main_box));
+    disk_usage_popup.set_titlebar(Some(&titlebar));
+    loading_spinner.start();
+    disk_usage_popup.present();
+
+    let (sender, receiver) = async_channel::bounded(1);
+
+    gio::spawn_blocking(move || {
+        let report = get_disk_usage();
+        sender
+            .send_blocking(DiskUsageFetchMessage::DiskUsageFetched(report))
+            .expect("The channel needs to be open.");
+    });
+
+    glib::spawn_future_local(clone!(
+        #[weak]
+        scroll_area,
+        async move {
+            while let Ok(msg) = receiver.recv().await {
+                match msg {
+                    DiskUsageFetchMessage::DiskUsageFetched(mut report) => {
+                        // the tabs can show this fresh report too
+                        DISK_USAGE.set(Some((Instant::now(), report.clone())));
+
+                        loading_spinner.stop();
+                        scroll_area.remove(&loading_spinner);
+
+                        //TRANSLATORS: Heading - boxes sorted by disk usage
+                        loading_lbl.set_text(&gettext("Boxes By Size"));
+
+                        report
+                            .boxes
+                            .sort_by_key(|b| std::cmp::Reverse(b.own_size()));
+
+                        let boxed_list = gtk::ListBox::new();
+                        boxed_list.set_selection_mode(gtk::SelectionMode::None);
+                        boxed_list.add_css_class("boxed-list");
+
+                        for usage in &report.boxes {
+                            let row = adw::ActionRow::new();
+                            row.set_title(&markup_escape_text(&usage.name));
+                            row.set_subtitle(&markup_escape_text(&get_disk_usage_summary(usage)));
+                            row.set_tooltip_text(Some(&usage.image));
+
+                            let size_lbl = gtk::Label::new(Some(&format_size(usage.own_size())));
+                            row.add_suffix(&size_lbl);
+
+                            boxed_list.append(&row);
+                        }
+
+                        scroll_area.append(&boxed_list);
+
+                        //TRANSLATORS: Heading - images not used by any container
+                        let images_title = gtk::Label::new(Some(&gettext("Unused Images")));
+                        images_title.add_css_class("title-2");
+                        scroll_area.append(&images_title);
+
+                        let unused_images: Vec<_> =
+                            report.images.iter().filter(|i| i.containers == 0).collect();
+
+                        if unused_images.is_empty() {
+                            //TRANSLATORS: Info Message
+                            let no_images_lbl =
+                                gtk::Label::new(Some(&gettext("No unused images found")));
+                            scroll_area.append(&no_images_lbl);
+                        } else {
+                            let images_list = gtk::ListBox::new();
+                            images_list.set_selection_mode(gtk::SelectionMode::None);
+                            images_list.add_css_class("boxed-list");
+
+                            for image in unused_images {
+                                let row = adw::ActionRow::new();
+                                row.set_title(&markup_escape_text(&image.name));
+
+                                let size_lbl = gtk::Label::new(Some(&format_size(image.size)));
+                                row.add_suffix(&size_lbl);
+
+                                images_list.append(&row);
+                            }
+
+                            let reclaimable_lbl = gtk::Label::new(Some(&format!(
+                                "{}: {}",
+                                //TRANSLATORS: Label before the space freed by removing unused images
+                                gettext("Reclaimable"),
+                                format_size(report.reclaimable_size())
+                            )));
+                            reclaimable_lbl.set_halign(Align::End);
+
+                            scroll_area.append(&images_list);
+                            scroll_area.append(&reclaimable_lbl);
+                        }
+                    }
+                }
+            }
+        }
+    ));
+}
+
+fn show_images_popup(window: &ApplicationWindow) {
+    let images_popup = gtk::Window::builder()
+        // TRANSLATORS: Window Title - lists the downloaded container images
+        .title(gettext("Images"))
+        .transient_for(window)
+        .default_width(750)
+        .default_height(500)
+        .modal(true)
+        .build();
+
+    let pull_btn = gtk::Button::from_icon_name("folder-download-symbolic");
+    // TRANSLATORS: Button tooltip
+    pull_btn.set_tooltip_text(Some(&gettext("Pull Image")));
+
+    let prune_btn = gtk::Button::from_icon_name("edit-clear-all-symbolic");
+    // TRANSLATORS: Button tooltip
+    prune_btn.set_tooltip_text(Some(&gettext("Remove Unused Images")));
+
+    let titlebar = adw::HeaderBar::new();
+    titlebar.pack_start(&pull_btn);
+    titlebar.pack_start(&prune_btn);
+
+    let toast_overlay = ToastOverlay::new();
+
+    let main_box = gtk::Box::new(Orientation::Vertical, 10);
+    main_box.set_margin_start(10);
+    main_box.set_margin_end(10);
+    main_box.set_margin_top(10);
+    main_box.set_margin_bottom(10);
+
+    let scrolled_win = gtk::ScrolledWindow::new();
+    scrolled_win.set_vexpand(true);
+    scrolled_win.set_hexpand(true);
+
+    let images_area = gtk::Box::new(gtk::Orientation::Vertical, 15);
+    images_area.set_vexpand(true);
+    images_area.set_hexpand(true);
+
+    scrolled_win.set_child(Some(&images_area));
+    main_box.append(&scrolled_win);
+    toast_overlay.set_child(Some(&main_box));
+
+    pull_btn.connect_clicked(clone!(
+        #[weak]
+        window,
+        #[weak]
+        images_popup,
+        #[weak]
+        images_area,
+        #[weak]
+        toast_overlay,
+        move |_btn| {
+            show_pull_image_popup(&window, &images_popup, &images_area, &toast_overlay);
+        }
+    ));
+
+    prune_btn.connect_clicked(clone!(
+        #[weak]
+        window,
+        #[weak]
+        images_popup,
+        #[weak]
+        images_area,
+        #[weak]
+        toast_overlay,
+        move |_btn| {
+            on_prune_images_clicked(&window, &images_popup, &images_area, &toast_overlay);
+        }
+    ));
+
+    images_popup.set_child(Some(&toast_overlay));
+    images_popup.set_titlebar(Some(&titlebar));
+    images_popup.present();
+
+    load_images_list(window, &images_popup, &images_area, &toast_overlay);
+}
+
+/// Fetches the downloaded images in the background, then fills the Images pop-up with them
+fn load_images_list(
+    window: &ApplicationWindow,
+    images_popup: &gtk::Window,
+    images_area: &gtk::Box,
+    toast_overlay: &ToastOverlay,
+) {
+    while let Some(child) = images_area.first_child() {
+        images_area.remove(&child);
+    }
+
+    let loading_spinner = gtk::Spinner::new();
+
+    // TRANSLATORS: Loading Message
+    let loading_lbl = gtk::Label::new(Some(&gettext("Loading...")));
+    loading_lbl.add_css_class("title-2");
+
+    images_area.append(&loading_lbl);
+    images_area.append(&loading_spinner);
+    loading_spinner.start();
+
+    let (sender, receiver) = async_channel::bounded(1);
+
+    gio::spawn_blocking(move || {
+        let images = get_container_images();
+        sender
+            .send_blocking(ImagesFetchMessage::ImagesFetched(images))
+            .expect("The channel needs to be open.");
+    });
+
+    glib::spawn_future_local(clone!(
+        #[weak]
+        window,
+        #[weak]
+        images_popup,
+        #[weak]
+        images_area,
+        #[weak]
+        toast_overlay,
+        async move {
+            while let Ok(msg) = receiver.recv().await {
+                match msg {
+                    ImagesFetchMessage::ImagesFetched(mut images) => {
+                        loading_spinner.stop();
+                        images_area.remove(&loading_spinner);
+
+                        if images.is_empty() {
+                            //TRANSLATORS: Info Message
+                            loading_lbl.set_text(&gettext("No images found"));
+                            return;
+                        }
+
+                        //TRANSLATORS: Heading - downloaded images, largest first
+                        loading_lbl.set_text(&gettext("Downloaded Images"));
+
+                        images.sort_by_key(|i| std::cmp::Reverse(i.size));
+
+                        let boxed_list = gtk::ListBox::new();
+                        boxed_list.set_selection_mode(gtk::SelectionMode::None);
+                        boxed_list.add_css_class("boxed-list");
+
+                        for image in &images {
+                            boxed_list.append(&make_image_row(
+                                image,
+                                &window,
+                                &images_popup,
+                                &images_area,
+                                &toast_overlay,
+                            ));
+                        }
+
+                        images_area.append(&boxed_list);
+                    }
+                }
+            }
+        }
+    ));
 }
 
-fn on_install_rpm_clicked(window: &ApplicationWindow, box_name: String) {
-    let rpm_filter = gtk::FileFilter::new();
+fn make_image_row(
+    image: &ContainerImage,
+    window: &ApplicationWindow,
+    images_popup: &gtk::Window,
+    images_area: &gtk::Box,
+    toast_overlay: &ToastOverlay,
+) -> adw::ActionRow {
+    let used_by = if image.boxes.is_empty() {
+        //TRANSLATORS: Image subtitle - no boxes were created from this image
+        gettext("Not used by any box")
+    } else {
+        //TRANSLATORS: Image subtitle - followed by a list of box names
+        format!("{} {}", gettext("Used by"), image.boxes.join(", "))
+    };
 
-    //TRANSLATORS: File type
-    rpm_filter.set_name(Some(&gettext("RPM Files")));
-    rpm_filter.add_mime_type("application/x-rpm");
+    let row = adw::ActionRow::new();
+    row.set_title(&markup_escape_text(&image.name));
+    row.set_subtitle(&markup_escape_text(&format!(
+        "{} · {} {} · {}",
+        format_size(image.size),
+        //TRANSLATORS: Image subtitle - followed by the date the image was created
+        gettext("Created"),
+        image.created,
+        used_by
+    )));
+    row.set_tooltip_text(Some(&image.id));
 
-    let download_dir = get_download_dir_path();
+    let create_btn = gtk::Button::from_icon_name("list-add-symbolic");
+    create_btn.add_css_class("flat");
+    create_btn.set_valign(Align::Center);
+    // TRANSLATORS: Button tooltip
+    create_btn.set_tooltip_text(Some(&gettext("Create Box From Image")));
+    // untagged images can't be picked by name in the create pop-up
+    create_btn.set_sensitive(image.is_tagged());
 
-    let file_dialog = FileDialog::builder()
-        .default_filter(&rpm_filter)
-        .initial_folder(&gio::File::for_path(download_dir))
-        .modal(false)
-        .build();
-    file_dialog.open(
-        Some(window),
-        None::<&gio::Cancellable>,
-        clone!(@weak window => move |result| {
-            if let Ok(file) = result {
-                let rpm_path = file.path().unwrap().into_os_string().into_string();
-                if rpm_path.is_ok() {
-                    let rp = rpm_path.unwrap();
-                    if rp.starts_with("/run/user") {
-                        show_sandbox_access_popup(&window);
-                    } else if !has_file_extension(&rp, "rpm") {
-                        show_incorrect_binary_file_popup(&window, BinaryPackageType::Rpm);
-                    } else {
-                        install_rpm_in_box(box_name, rp);
-                    }
+    let image_name = image.name.clone();
+    create_btn.connect_clicked(clone!(
+        #[weak]
+        window,
+        #[weak]
+        images_popup,
+        move |_btn| {
+            images_popup.destroy();
+            create_new_distrobox(&window, Some(image_name.clone()));
+        }
+    ));
+
+    let remove_btn = gtk::Button::from_icon_name("user-trash-symbolic");
+    remove_btn.add_css_class("flat");
+    remove_btn.set_valign(Align::Center);
+    if image.boxes.is_empty() {
+        // TRANSLATORS: Button tooltip
+        remove_btn.set_tooltip_text(Some(&gettext("Remove Image")));
+    } else {
+        remove_btn.set_sensitive(false);
+        // TRANSLATORS: Button tooltip - shown when the button is disabled
+        remove_btn.set_tooltip_text(Some(&gettext(
+            "Delete the boxes using this image before removing it",
+        )));
+    }
+
+    let image_ref = image.reference().to_string();
+    remove_btn.connect_clicked(clone!(
+        #[weak]
+        window,
+        #[weak]
+        images_popup,
+        #[weak]
+        images_area,
+        #[weak]
+        toast_overlay,
+        move |_btn| {
+            let d = adw::MessageDialog::new(
+                Some(&images_popup),
+                //TRANSLATORS: Confirmation Dialogue
+                Some(&gettext("Really Remove?")),
+                //TRANSLATORS: Confirmation Dialogue - {} replaced with the name of the image
+                Some(&gettext(&format!(
+                    "Are you sure you want to remove {}?",
+                    image_ref
+                ))),
+            );
+            //TRANSLATORS: Button Label
+            d.add_response("cancel", &gettext("Cancel"));
+            //TRANSLATORS: Button Label
+            d.add_response("remove", &gettext("Remove"));
+            d.set_default_response(Some("cancel"));
+            d.set_close_response("cancel");
+            d.set_response_appearance("remove", adw::ResponseAppearance::Destructive);
+
+            let image_ref = image_ref.clone();
+            d.connect_response(None, move |d, res| {
+                if res == "remove" {
+                    let image_ref = image_ref.clone();
+                    run_image_action(
+                        move || remove_image(&image_ref),
+                        //TRANSLATORS: Success Text
+                        gettext("Image Removed!"),
+                        &window,
+                        &images_popup,
+                        &images_area,
+                        &toast_overlay,
+                    );
                 }
-            }
-        }),
-    );
+                d.destroy();
+            });
+
+            d.present();
+        }
+    ));
+
+    row.add_suffix(&create_btn);
+    row.add_suffix(&remove_btn);
+
+    row
 }
 
-fn show_sandbox_access_popup(window: &ApplicationWindow) {
-    //TRANSLATORS: Error / Info Message
-    let message_body = gettext("This file is not accessible to Flatpak - please copy it to your Downloads folder, or allow filesystem access. Please see the <a href='https://dvlv.github.io/BoxBuddyRS/tips'>documentation for details.</a>");
+fn show_pull_image_popup(
+    window: &ApplicationWindow,
+    images_popup: &gtk::Window,
+    images_area: &gtk::Box,
+    toast_overlay: &ToastOverlay,
+) {
     let d = adw::MessageDialog::new(
-        Some(window),
+        Some(images_popup),
         //TRANSLATORS: Popup Heading
-        Some(&gettext("File Not Accessible")),
-        Some(&message_body),
+        Some(&gettext("Pull Image")),
+        //TRANSLATORS: Popup Body
+        Some(&gettext(
+            "Enter the image to download, such as docker.io/library/ubuntu:24.04",
+        )),
     );
-    d.set_transient_for(Some(window));
-    d.set_body_use_markup(true);
+
+    let image_entry = gtk::Entry::new();
+    image_entry.set_activates_default(true);
+    d.set_extra_child(Some(&image_entry));
+
     //TRANSLATORS: Button Label
-    d.add_response("ok", &gettext("Ok"));
-    d.set_default_response(Some("ok"));
-    d.set_close_response("ok");
+    d.add_response("cancel", &gettext("Cancel"));
+    //TRANSLATORS: Button Label
+    d.add_response("pull", &gettext("Pull"));
+    d.set_default_response(Some("pull"));
+    d.set_close_response("cancel");
+    d.set_response_appearance("pull", adw::ResponseAppearance::Suggested);
+
+    let window = window.clone();
+    let images_popup = images_popup.clone();
+    let images_area = images_area.clone();
+    let toast_overlay = toast_overlay.clone();
+    d.connect_response(None, move |d, res| {
+        let image = image_entry.text().trim().to_string();
+        if res == "pull" && !image.is_empty() {
+            //TRANSLATORS: Info Message - the image is being downloaded
+            toast_overlay.add_toast(adw::Toast::new(&gettext("Pulling Image...")));
+
+            run_image_action(
+                move || pull_image(&image),
+                //TRANSLATORS: Success Text
+                gettext("Image Pulled!"),
+                &window,
+                &images_popup,
+                &images_area,
+                &toast_overlay,
+            );
+        }
+        d.destroy();
+    });
 
     d.present();
 }
 
-fn show_incorrect_binary_file_popup(window: &ApplicationWindow, file_type: BinaryPackageType) {
-    let pkg_type = match file_type {
-        BinaryPackageType::Deb => ".deb",
-        BinaryPackageType::Rpm => ".rpm",
-    };
-    //TRANSLATORS: Error / Info Message - {} replaced with .deb or .rpm
-    let message_body = gettext(&format!(
-        "This file does not appear to be a {} file",
-        pkg_type
-    ));
+fn on_prune_images_clicked(
+    window: &ApplicationWindow,
+    images_popup: &gtk::Window,
+    images_area: &gtk::Box,
+    toast_overlay: &ToastOverlay,
+) {
     let d = adw::MessageDialog::new(
-        Some(window),
-        //TRANSLATORS: Popup Heading
-        Some(&gettext("Incorrect File Type")),
-        Some(&message_body),
+        Some(images_popup),
+        //TRANSLATORS: Confirmation Dialogue
+        Some(&gettext("Remove Unused Images?")),
+        //TRANSLATORS: Confirmation Dialogue
+        Some(&gettext(
+            "Every image which is not used by a box or other container will be removed",
+        )),
     );
-    d.set_transient_for(Some(window));
     //TRANSLATORS: Button Label
-    d.add_response("ok", &gettext("Ok"));
-    d.set_default_response(Some("ok"));
-    d.set_close_response("ok");
+    d.add_response("cancel", &gettext("Cancel"));
+    //TRANSLATORS: Button Label
+    d.add_response("prune", &gettext("Remove"));
+    d.set_default_response(Some("cancel"));
+    d.set_close_response("cancel");
+    d.set_response_appearance("prune", adw::ResponseAppearance::Destructive);
+
+    let window = window.clone();
+    let images_popup = images_popup.clone();
+    let images_area = images_area.clone();
+    let toast_overlay = toast_overlay.clone();
+    d.connect_response(None, move |d, res| {
+        if res == "prune" {
+            run_image_action(
+                prune_images,
+                //TRANSLATORS: Success Text
+                gettext("Unused Images Removed!"),
+                &window,
+                &images_popup,
+                &images_area,
+                &toast_overlay,
+            );
+        }
+        d.destroy();
+    });
 
     d.present();
 }
 
-fn show_preferred_terminal_popup(window: &ApplicationWindow) {
-    let terms = get_supported_terminals();
+/// Runs an image command in the background, then shows how it went and reloads the images list
+fn run_image_action<F>(
+    action: F,
+    success_message: String,
+    window: &ApplicationWindow,
+    images_popup: &gtk::Window,
+    images_area: &gtk::Box,
+    toast_overlay: &ToastOverlay,
+) where
+    F: FnOnce() -> Result<String, String> + Send + 'static,
+{
+    let (sender, receiver) = async_channel::bounded(1);
 
-    let settings = Settings::new(APP_ID);
-    let default_term = settings.string("default-terminal");
-    let mut selected_term_idx: u32 = 0;
+    gio::spawn_blocking(move || {
+        sender
+            .send_blocking(ImageActionMessage::Finished(action()))
+            .expect("The channel needs to be open.");
+    });
+
+    glib::spawn_future_local(clone!(
+        #[weak]
+        window,
+        #[weak]
+        images_popup,
+        #[weak]
+        images_area,
+        #[weak]
+        toast_overlay,
+        async move {
+            while let Ok(msg) = receiver.recv().await {
+                match msg {
+                    ImageActionMessage::Finished(result) => {
+                        let toast = match result {
+                            Ok(_) => adw::Toast::new(&success_message),
+                            Err(error) => {
+                                // the runtime's actual complaint is on the last line
+                                let error = error.lines().last().unwrap_or_default();
+                                let toast = adw::Toast::new(&markup_escape_text(error));
+                                toast.set_timeout(0);
+                                toast
+                            }
+                        };
+                        toast_overlay.add_toast(toast);
 
-    for (idx, term) in terms.iter().enumerate() {
-        if term.name == default_term {
-            selected_term_idx = idx as u32;
-            break;
+                        load_images_list(&window, &images_popup, &images_area, &toast_overlay);
+                    }
+                }
+            }
         }
-    }
+    ));
+}
+
+/// A `distrobox.conf` key shown in Preferences, along with the widget which edits it
+enum DistroboxConfigRow {
+    Text {
+        key: &'static str,
+        entry: gtk::Entry,
+    },
+    Choice {
+        key: &'static str,
+        combo: adw::ComboRow,
+        /// Value written for each option, where "" means unset
+        values: Vec<String>,
+    },
+}
 
-    let term_pref_popup = gtk::Window::builder()
+fn show_preferences_window(window: &ApplicationWindow) {
+    let prefs_window = adw::PreferencesWindow::builder()
         // TRANSLATORS: Popup Window Title
-        .title(gettext("Preferred Terminal"))
+        .title(gettext("Preferences"))
         .transient_for(window)
-        .default_width(500)
-        .default_height(250)
+        .default_width(700)
+        .default_height(600)
         .modal(true)
         .build();
 
-    // TRANSLATORS: Button Label
-    let save_btn = gtk::Button::with_label(&gettext("Save"));
-    save_btn.add_css_class("suggested-action");
-
-    // TRANSLATORS: Button Label
-    let cancel_btn = gtk::Button::with_label(&gettext("Cancel"));
-    cancel_btn.connect_clicked(move |btn| {
-        let win = btn.root().and_downcast::<gtk::Window>().unwrap();
-        win.destroy();
-    });
+    prefs_window.add(&make_general_preferences_page(window));
+    prefs_window.add(&make_distrobox_config_page(&prefs_window, window));
+    prefs_window.present();
+}
 
-    let term_pref_titlebar = adw::HeaderBar::new();
-    term_pref_titlebar.set_show_end_title_buttons(false);
-    term_pref_titlebar.pack_end(&save_btn);
-    term_pref_titlebar.pack_start(&cancel_btn);
+/// Where the tabs for each box go, from the `tab-position` setting
+fn get_tab_position() -> PositionType {
+    let settings = Settings::new(APP_ID);
+    match settings.string("tab-position").as_str() {
+        "top" => PositionType::Top,
+        "right" => PositionType::Right,
+        "bottom" => PositionType::Bottom,
+        _ => PositionType::Left,
+    }
+}
 
-    term_pref_popup.set_titlebar(Some(&term_pref_titlebar));
+/// Builds the Preferences page for BoxBuddy's own settings. Each row saves
+/// as soon as it is changed.
+fn make_general_preferences_page(window: &ApplicationWindow) -> adw::PreferencesPage {
+    let settings = Settings::new(APP_ID);
 
-    let main_box = gtk::Box::new(gtk::Orientation::Vertical, 20);
-    main_box.set_margin_top(20);
+    let page = adw::PreferencesPage::builder()
+        // TRANSLATORS: Preferences page title
+        .title(gettext("General"))
+        .icon_name("preferences-system-symbolic")
+        .build();
 
-    // TRANSLATORS: Instructions label
-    let instruction_label = gtk::Label::new(Some(&gettext("Select your preferred terminal")));
-    instruction_label.add_css_class("title-2");
+    // Behaviour
+    let behaviour_group = adw::PreferencesGroup::builder()
+        // TRANSLATORS: Preferences group title
+        .title(gettext("Behaviour"))
+        .build();
 
-    let exp = gtk::PropertyExpression::new(
-        gtk::StringObject::static_type(),
-        None::<gtk::Expression>,
-        "string",
-    );
+    let terms = get_supported_terminals();
+    let default_term = settings.string("default-terminal");
+    let selected_term_idx = terms
+        .iter()
+        .position(|t| t.name == default_term)
+        .unwrap_or(0) as u32;
 
     let term_names_as_refs: Vec<&str> = terms.iter().map(|t| t.name.as_ref()).collect();
-    let term_names_strlist = gtk::StringList::new(&term_names_as_refs);
-    let terms_dropdown = gtk::DropDown::new(Some(term_names_strlist), Some(exp));
-
-    terms_dropdown.set_selected(selected_term_idx);
-    terms_dropdown.set_enable_search(true);
-    terms_dropdown.set_search_match_mode(gtk::StringFilterMatchMode::Substring);
-    terms_dropdown.set_width_request(400);
-
-    let terms_dd_row = adw::ActionRow::new();
-    // TRANSLATORS: Label for Dropdown of terminals available
-    terms_dd_row.set_title(&gettext("Terminal"));
-    terms_dd_row.set_activatable_widget(Some(&terms_dropdown));
-    terms_dd_row.add_suffix(&terms_dropdown);
-
-    let dd_clone = terms_dropdown.clone();
-    let popup_clone = term_pref_popup.clone();
-    let win_clone = window.clone();
-    save_btn.connect_clicked(move |_btn| {
-        let term_name = dd_clone
-            .selected_item()
-            .unwrap()
-            .downcast::<gtk::StringObject>()
-            .unwrap()
-            .string()
-            .to_string();
-
-        let settings = gio::Settings::new(APP_ID);
-        if settings
-            .set_string("default-terminal", term_name.as_ref())
-            .is_ok()
-        {
-            // TRANSLATORS: Success Message
-            let toast = adw::Toast::new(&gettext("Terminal Preference Saved!"));
-            if let Some(child) = win_clone.clone().child() {
-                let toast_area = child.downcast::<ToastOverlay>();
-                toast_area.unwrap().add_toast(toast);
+    let terms_row = adw::ComboRow::new();
+    // TRANSLATORS: Preferences row title
+    terms_row.set_title(&gettext("Preferred Terminal"));
+    terms_row.set_model(Some(&gtk::StringList::new(&term_names_as_refs)));
+    terms_row.set_enable_search(true);
+    terms_row.set_selected(selected_term_idx);
+    terms_row.connect_selected_notify(clone!(
+        #[strong]
+        settings,
+        move |row| {
+            if let Some(term) = terms.get(row.selected() as usize) {
+                let _ = settings.set_string("default-terminal", &term.name);
+            }
+        }
+    ));
+    behaviour_group.add(&terms_row);
+
+    // "auto" first, then the same order they are auto-detected in
+    let mut manager_values = vec!["auto"];
+    manager_values.extend(CONTAINER_MANAGERS);
+
+    let manager_names: Vec<String> = manager_values
+        .iter()
+        .map(|m| match *m {
+            // TRANSLATORS: Dropdown option - pick the container manager automatically
+            "auto" => gettext("Automatic"),
+            "podman" => String::from("Podman"),
+            "docker" => String::from("Docker"),
+            "lilipod" => String::from("Lilipod"),
+            other => other.to_string(),
+        })
+        .collect();
+
+    let current_manager = settings.string("container-manager");
+    let selected_manager_idx = manager_values
+        .iter()
+        .position(|m| *m == current_manager.as_str())
+        .unwrap_or(0) as u32;
+
+    let manager_names_as_refs: Vec<&str> = manager_names.iter().map(String::as_str).collect();
+    let manager_row = adw::ComboRow::new();
+    // TRANSLATORS: Preferences row title
+    manager_row.set_title(&gettext("Container Manager"));
+    // TRANSLATORS: Preferences row subtitle
+    manager_row.set_subtitle(&gettext("Used by BoxBuddy and passed on to Distrobox"));
+    manager_row.set_model(Some(&gtk::StringList::new(&manager_names_as_refs)));
+    manager_row.set_selected(selected_manager_idx);
+    manager_row.connect_selected_notify(clone!(
+        #[strong]
+        settings,
+        #[weak]
+        window,
+        move |row| {
+            let manager = manager_values
+                .get(row.selected() as usize)
+                .copied()
+                .unwrap_or("auto");
+
+            if settings.set_string("container-manager", manager).is_ok() {
+                delayed_rerender(&window, None);
             }
+        }
+    ));
+    behaviour_group.add(&manager_row);
+
+    let rootful_row = adw::SwitchRow::new();
+    // TRANSLATORS: Preferences row title
+    rootful_row.set_title(&gettext("Manage Rootful Boxes"));
+    // TRANSLATORS: Preferences row subtitle
+    rootful_row.set_subtitle(&gettext(
+        "Boxes created as root. Your password will be asked for when they are used",
+    ));
+    settings.bind("rootful", &rootful_row, "active").build();
+    rootful_row.connect_active_notify(clone!(
+        #[weak]
+        window,
+        move |_row| {
+            delayed_rerender(&window, None);
+        }
+    ));
+    behaviour_group.add(&rootful_row);
 
-            popup_clone.destroy();
+    let confirm_stop_row = adw::SwitchRow::new();
+    // TRANSLATORS: Preferences row title
+    confirm_stop_row.set_title(&gettext("Confirm Before Stopping a Box"));
+    settings
+        .bind("confirm-before-stop", &confirm_stop_row, "active")
+        .build();
+    behaviour_group.add(&confirm_stop_row);
+
+    let open_terminal_row = adw::SwitchRow::new();
+    // TRANSLATORS: Preferences row title
+    open_terminal_row.set_title(&gettext("Open a Terminal After Creating a Box"));
+    // TRANSLATORS: Preferences row subtitle
+    open_terminal_row.set_subtitle(&gettext(
+        "The first terminal finishes setting up the box, which can take a while",
+    ));
+    settings
+        .bind("open-terminal-after-create", &open_terminal_row, "active")
+        .build();
+    behaviour_group.add(&open_terminal_row);
+
+    // New Boxes
+    let new_boxes_group = adw::PreferencesGroup::builder()
+        // TRANSLATORS: Preferences group title
+        .title(gettext("New Boxes"))
+        // TRANSLATORS: Preferences group description
+        .description(gettext("What the Create New Distrobox window starts with"))
+        .build();
 
-            delayed_rerender(&win_clone, None);
-        } else {
-            // TRANSLATORS: Error Message
-            let toast = adw::Toast::new(&gettext("Sorry, Preference Could Not Be Saved"));
-            if let Some(child) = win_clone.clone().child() {
-                let toast_area = child.downcast::<ToastOverlay>();
-                toast_area.unwrap().add_toast(toast);
+    let default_image_row = adw::EntryRow::new();
+    // TRANSLATORS: Preferences row title
+    default_image_row.set_title(&gettext(
+        "Default Image (Leave blank to use distrobox.conf)",
+    ));
+    default_image_row.set_text(&settings.string("default-image"));
+    default_image_row.set_show_apply_button(true);
+    default_image_row.connect_apply(clone!(
+        #[strong]
+        settings,
+        move |row| {
+            let _ = settings.set_string("default-image", row.text().trim());
+        }
+    ));
+    new_boxes_group.add(&default_image_row);
+
+    let default_init_row = adw::SwitchRow::new();
+    // TRANSLATORS: Preferences row title
+    default_init_row.set_title(&gettext("Use init system"));
+    settings
+        .bind("default-init", &default_init_row, "active")
+        .build();
+    new_boxes_group.add(&default_init_row);
+
+    let default_volumes_row = adw::EntryRow::new();
+    // TRANSLATORS: Preferences row title - volumes are written as host-path:box-path
+    default_volumes_row.set_title(&gettext("Default Volumes (host:box, separated by spaces)"));
+    let default_volumes = settings.strv("default-volumes");
+    let default_volumes: Vec<&str> = default_volumes.iter().map(|v| v.as_str()).collect();
+    default_volumes_row.set_text(&default_volumes.join(" "));
+    default_volumes_row.set_show_apply_button(true);
+    default_volumes_row.connect_apply(clone!(
+        #[strong]
+        settings,
+        move |row| {
+            let text = row.text();
+            let volumes: Vec<&str> = text.split_whitespace().collect();
+            let _ = settings.set_strv("default-volumes", volumes);
+        }
+    ));
+    // volumes can't be added without host access, so the create window won't show them
+    default_volumes_row.set_visible(has_host_access());
+    new_boxes_group.add(&default_volumes_row);
+
+    // Appearance
+    let appearance_group = adw::PreferencesGroup::builder()
+        // TRANSLATORS: Preferences group title
+        .title(gettext("Appearance"))
+        .build();
+
+    let tab_position_values = ["left", "top", "right", "bottom"];
+    let tab_position_names = [
+        // TRANSLATORS: Dropdown option - tab position
+        gettext("Left"),
+        // TRANSLATORS: Dropdown option - tab position
+        gettext("Top"),
+        // TRANSLATORS: Dropdown option - tab position
+        gettext("Right"),
+        // TRANSLATORS: Dropdown option - tab position
+        gettext("Bottom"),
+    ];
+    let current_tab_position = settings.string("tab-position");
+    let selected_tab_position_idx = tab_position_values
+        .iter()
+        .position(|p| *p == current_tab_position.as_str())
+        .unwrap_or(0) as u32;
+
+    let tab_position_names_as_refs: Vec<&str> =
+        tab_position_names.iter().map(String::as_str).collect();
+    let tab_position_row = adw::ComboRow::new();
+    // TRANSLATORS: Preferences row title
+    tab_position_row.set_title(&gettext("Tab Position"));
+    tab_position_row.set_model(Some(&gtk::StringList::new(&tab_position_names_as_refs)));
+    tab_position_row.set_selected(selected_tab_position_idx);
+    tab_position_row.connect_selected_notify(clone!(
+        #[strong]
+        settings,
+        #[weak]
+        window,
+        move |row| {
+            let position = tab_position_values
+                .get(row.selected() as usize)
+                .copied()
+                .unwrap_or("left");
+
+            if settings.set_string("tab-position", position).is_ok() {
+                delayed_rerender(&window, None);
             }
+        }
+    ));
+    appearance_group.add(&tab_position_row);
+
+    let stats_interval_row = adw::SpinRow::with_range(1.0, 3600.0, 1.0);
+    // TRANSLATORS: Preferences row title
+    stats_interval_row.set_title(&gettext("Stats Refresh Interval"));
+    // TRANSLATORS: Preferences row subtitle
+    stats_interval_row.set_subtitle(&gettext(
+        "Seconds between updates of the CPU and memory usage of running boxes",
+    ));
+    stats_interval_row.set_value(f64::from(settings.uint("stats-refresh-interval")));
+    stats_interval_row.connect_value_notify(clone!(
+        #[strong]
+        settings,
+        move |row| {
+            let _ = settings.set_uint("stats-refresh-interval", row.value() as u32);
+        }
+    ));
+    appearance_group.add(&stats_interval_row);
 
-            popup_clone.destroy();
+    page.add(&behaviour_group);
+    page.add(&new_boxes_group);
+    page.add(&appearance_group);
+
+    page
+}
+
+/// Builds the Preferences page for editing the user's `distrobox.conf`.
+/// Values set in the system config files are shown against each row.
+fn make_distrobox_config_page(
+    prefs_window: &adw::PreferencesWindow,
+    window: &ApplicationWindow,
+) -> adw::PreferencesPage {
+    let user_config = read_user_config();
+    let system_values = get_system_config_values();
+    let distroboxrc_values = get_distroboxrc_values();
+    let distroboxrc_path = get_distroboxrc_path();
+
+    let inherited_subtitle = |key: &str| -> String {
+        if let Some(value) = distroboxrc_values.get(key) {
+            return markup_escape_text(&format!(
+                "{}: {} ({})",
+                // TRANSLATORS: Subtitle prefix for a value set in ~/.distroboxrc, which wins over this page
+                gettext("Overridden by"),
+                value,
+                distroboxrc_path
+            ))
+            .to_string();
+        }
 
-            delayed_rerender(&win_clone, None);
+        match system_values.get(key) {
+            Some(inherited) => markup_escape_text(&format!(
+                "{}: {} ({})",
+                // TRANSLATORS: Subtitle prefix for a value set in a system config file
+                gettext("System default"),
+                inherited.value,
+                inherited.path
+            ))
+            .to_string(),
+            // TRANSLATORS: Subtitle for a setting with no value in the system config files
+            None => gettext("No system default"),
         }
+    };
+
+    let page = adw::PreferencesPage::builder()
+        // TRANSLATORS: Preferences page title
+        .title(gettext("Distrobox"))
+        .icon_name("preferences-other-symbolic")
+        .build();
+
+    // TRANSLATORS: Button Label
+    let save_btn = gtk::Button::with_label(&gettext("Save"));
+    save_btn.add_css_class("suggested-action");
+    save_btn.set_valign(Align::Center);
+
+    let group = adw::PreferencesGroup::builder()
+        // TRANSLATORS: Preferences group title
+        .title(gettext("Distrobox Configuration"))
+        .description(markup_escape_text(&format!(
+            // TRANSLATORS: Preferences group description, followed by a file path
+            "{} {}",
+            gettext("Defaults used by Distrobox for new boxes, saved to"),
+            get_user_config_path()
+        )))
+        .header_suffix(&save_btn)
+        .build();
+
+    let mut config_rows: Vec<DistroboxConfigRow> = Vec::new();
+
+    let text_keys = [
+        // TRANSLATORS: Preferences row title
+        ("container_image_default", gettext("Default Image")),
+        // TRANSLATORS: Preferences row title
+        ("container_name_default", gettext("Default Name")),
+        // TRANSLATORS: Preferences row title
+        (
+            "container_user_custom_home",
+            gettext("Default Home Directory"),
+        ),
+        // TRANSLATORS: Preferences row title - a space-separated list of host:container paths
+        (
+            "container_additional_volumes",
+            gettext("Additional Volumes"),
+        ),
+    ];
+
+    for (key, title) in text_keys {
+        let placeholder = system_values
+            .get(key)
+            .map(|v| v.value.clone())
+            .unwrap_or_default();
+
+        let entry = gtk::Entry::builder()
+            .text(user_config.get(key).unwrap_or_default())
+            .placeholder_text(&placeholder)
+            .valign(Align::Center)
+            .hexpand(true)
+            .build();
+
+        let row = adw::ActionRow::new();
+        row.set_title(&title);
+        row.set_subtitle(&inherited_subtitle(key));
+        row.add_suffix(&entry);
+        row.set_activatable_widget(Some(&entry));
+        group.add(&row);
+
+        config_rows.push(DistroboxConfigRow::Text { key, entry });
+    }
+
+    // TRANSLATORS: Dropdown option - leave this setting out of the config file
+    let not_set = gettext("Not set");
+
+    // container manager
+    let mut manager_values = vec![String::new()];
+    manager_values.extend(CONTAINER_MANAGERS.iter().map(|m| m.to_string()));
+    let mut manager_names = vec![not_set.clone()];
+    manager_names.extend(["Podman", "Docker", "Lilipod"].map(String::from));
+
+    let user_manager = user_config.get("container_manager").unwrap_or_default();
+    if !manager_values.contains(&user_manager) {
+        // keep anything we don't know about rather than losing it on save
+        manager_values.push(user_manager.clone());
+        manager_names.push(user_manager.clone());
+    }
+    let manager_idx = manager_values
+        .iter()
+        .position(|m| *m == user_manager)
+        .unwrap_or(0) as u32;
+
+    let manager_names_as_refs: Vec<&str> = manager_names.iter().map(String::as_str).collect();
+    let manager_row = adw::ComboRow::new();
+    // TRANSLATORS: Preferences row title
+    manager_row.set_title(&gettext("Container Manager"));
+    manager_row.set_subtitle(&format!(
+        "{}\n{}",
+        // TRANSLATORS: Explanation of when distrobox.conf's container manager applies
+        gettext("Only used by BoxBuddy when its own Container Manager is set to Automatic"),
+        inherited_subtitle("container_manager")
+    ));
+    manager_row.set_model(Some(&gtk::StringList::new(&manager_names_as_refs)));
+    manager_row.set_selected(manager_idx);
+    group.add(&manager_row);
+
+    config_rows.push(DistroboxConfigRow::Choice {
+        key: "container_manager",
+        combo: manager_row,
+        values: manager_values,
     });
 
-    main_box.append(&instruction_label);
-    main_box.append(&terms_dd_row);
+    // on/off settings
+    let bool_keys = [
+        // TRANSLATORS: Preferences row title
+        ("nvidia", gettext("Use NVIDIA Drivers")),
+        // TRANSLATORS: Preferences row title
+        ("container_always_pull", gettext("Always Pull Images")),
+        // TRANSLATORS: Preferences row title
+        (
+            "container_generate_entry",
+            gettext("Add Boxes to Application Menu"),
+        ),
+        // TRANSLATORS: Preferences row title
+        ("verbose", gettext("Verbose Output")),
+    ];
+
+    // TRANSLATORS: Dropdown option
+    let yes = gettext("Yes");
+    // TRANSLATORS: Dropdown option
+    let no = gettext("No");
+    let bool_names = [not_set.as_str(), yes.as_str(), no.as_str()];
+
+    for (key, title) in bool_keys {
+        let selected = match user_config.get_bool(key) {
+            Some(true) => 1,
+            Some(false) => 2,
+            None => 0,
+        };
+
+        let row = adw::ComboRow::new();
+        row.set_title(&title);
+        row.set_subtitle(&inherited_subtitle(key));
+        row.set_model(Some(&gtk::StringList::new(&bool_names)));
+        row.set_selected(selected);
+        group.add(&row);
+
+        config_rows.push(DistroboxConfigRow::Choice {
+            key,
+            combo: row,
+            values: vec![String::new(), "1".to_string(), "0".to_string()],
+        });
+    }
+
+    save_btn.connect_clicked(clone!(
+        #[weak]
+        prefs_window,
+        #[weak]
+        window,
+        move |_btn| {
+            // re-read in case it was edited by hand while this was open
+            let mut config = read_user_config();
+
+            for config_row in &config_rows {
+                let (key, value) = match config_row {
+                    DistroboxConfigRow::Text { key, entry } => {
+                        (key, entry.text().trim().to_string())
+                    }
+                    DistroboxConfigRow::Choice { key, combo, values } => (
+                        key,
+                        values
+                            .get(combo.selected() as usize)
+                            .cloned()
+                            .unwrap_or_default(),
+                    ),
+                };
+
+                // leave lines alone when they already mean the same thing, e.g. "true" and "1"
+                let current_value = config.get(key).unwrap_or_default();
+                let is_same_bool = parse_config_bool(&value)
+                    .is_some_and(|b| parse_config_bool(&current_value) == Some(b));
+                if value == current_value || is_same_bool {
+                    continue;
+                }
+
+                if value.is_empty() {
+                    config.remove(key);
+                } else {
+                    config.set(key, &value);
+                }
+            }
+
+            let toast = if write_user_config(&config).is_ok() {
+                // TRANSLATORS: Success Message
+                adw::Toast::new(&gettext("Distrobox Configuration Saved!"))
+            } else {
+                // TRANSLATORS: Error Message
+                adw::Toast::new(&gettext("Sorry, Preference Could Not Be Saved"))
+            };
+            prefs_window.add_toast(toast);
+
+            // the container manager may have changed
+            delayed_rerender(&window, None);
+        }
+    ));
+
+    page.add(&group);
 
-    term_pref_popup.set_child(Some(&main_box));
-    term_pref_popup.present();
+    page
 }
@@ -4,9 +4,12 @@ use gtk::gio::Settings;
 use gtk::prelude::SettingsExt;
 use std::collections::HashMap;
 use std::env;
+use std::io::Write;
 use std::path::Path;
-use std::process::Command;
+use std::process::{Child, Command, Stdio};
+use std::time::{Duration, Instant};
 
+use crate::distrobox_config::get_distrobox_config_value;
 use crate::get_all_distroboxes;
 use crate::APP_ID;
 
@@ -38,6 +41,186 @@ pub struct CpuMemUsage {
     pub mem: String,
     /// Mem percentage usage
     pub mem_percent: String,
+    /// Network I/O, as "received / sent"
+    pub net_io: String,
+    /// Block I/O, as "read / written"
+    pub block_io: String,
+}
+
+/// Used to represent the disk space used by a single box
+#[derive(Debug, Clone)]
+pub struct BoxDiskUsage {
+    /// Name of the box
+    pub name: String,
+    /// Size of the container's writable layer, in bytes
+    pub container_size: u64,
+    /// The image the box was created from
+    pub image: String,
+    /// Size of the box's image, in bytes
+    pub image_size: u64,
+    /// Custom home directory of the box, if one was set with `--home`
+    pub home_path: Option<String>,
+    /// Size of the custom home directory, in bytes
+    pub home_size: u64,
+}
+
+/// Used to represent the disk space used by a downloaded container image
+#[derive(Debug, Clone)]
+pub struct ImageDiskUsage {
+    /// "image:version" name of the image
+    pub name: String,
+    /// Short ID of the image
+    pub id: String,
+    /// Size of the image, in bytes
+    pub size: u64,
+    /// Number of containers (boxes or otherwise) using this image
+    pub containers: u32,
+}
+
+/// Disk usage of all boxes and images, as reported by `system df -v`
+#[derive(Debug, Clone, Default)]
+pub struct DiskUsageReport {
+    /// Every distrobox, in the order `distrobox list` returns them
+    pub boxes: Vec<BoxDiskUsage>,
+    /// Every downloaded image
+    pub images: Vec<ImageDiskUsage>,
+}
+
+/// Used to represent a process running inside a box, as listed by `top` or `ps`
+#[derive(Debug, Clone)]
+pub struct BoxProcess {
+    /// Process ID
+    pub pid: String,
+    /// User the process is running as
+    pub user: String,
+    /// CPU usage percentage
+    pub cpu: String,
+    /// Memory usage percentage
+    pub mem: String,
+    /// The process's cgroups, comma separated
+    pub cgroup: String,
+    /// Full command line of the process
+    pub command: String,
+}
+
+/// Used to represent a downloaded container image, for the Images window
+#[derive(Debug, Clone)]
+pub struct ContainerImage {
+    /// Short ID of the image
+    pub id: String,
+    /// "repository:tag" name of the image, "<none>:<none>" if it is untagged
+    pub name: String,
+    /// Size of the image, in bytes
+    pub size: u64,
+    /// Date the image was created, as YYYY-MM-DD
+    pub created: String,
+    /// Names of the boxes created from this image
+    pub boxes: Vec<String>,
+}
+
+/// Progress of an image pull, built up from the runtime's output one line at a time
+#[derive(Debug, Clone, Default)]
+pub struct PullProgress {
+    /// Each layer's ID and how far along it is, from 0.0 to 1.0, in the order they were first seen
+    layers: Vec<(String, f64)>,
+}
+
+/// Signals which can be sent to a process in a box
+#[derive(Debug, Clone, Copy)]
+pub enum ProcessSignal {
+    /// SIGTERM - asks the process to exit
+    Term,
+    /// SIGKILL - ends the process immediately
+    Kill,
+}
+
+impl ProcessSignal {
+    fn as_str(self) -> &'static str {
+        match self {
+            ProcessSignal::Term => "TERM",
+            ProcessSignal::Kill => "KILL",
+        }
+    }
+}
+
+impl BoxDiskUsage {
+    /// Space used by this box alone. The image isn't counted as other
+    /// boxes may share it.
+    pub fn own_size(&self) -> u64 {
+        self.container_size + self.home_size
+    }
+}
+
+impl ContainerImage {
+    /// Whether the image has a name, rather than just an ID
+    pub fn is_tagged(&self) -> bool {
+        !self.name.contains("<none>")
+    }
+
+    /// The name to pass to the runtime for this image - its ID if it is untagged
+    pub fn reference(&self) -> &str {
+        if self.is_tagged() {
+            &self.name
+        } else {
+            &self.id
+        }
+    }
+}
+
+impl PullProgress {
+    /// Updates the progress from a line of `pull` output. Returns false if
+    /// the line had nothing to do with the layers.
+    pub fn update(&mut self, line: &str) -> bool {
+        // podman only reports blobs as they start when not in a terminal, but
+        // once it moves on to the config every layer has been copied
+        if line.starts_with("Copying config") || line.starts_with("Writing manifest") {
+            for layer in &mut self.layers {
+                layer.1 = 1.0;
+            }
+            return true;
+        }
+
+        let Some((layer_id, progress)) = parse_pull_line(line) else {
+            return false;
+        };
+
+        match self.layers.iter_mut().find(|(id, _)| *id == layer_id) {
+            Some(layer) => layer.1 = layer.1.max(progress),
+            None => self.layers.push((layer_id, progress)),
+        }
+
+        true
+    }
+
+    /// How far along the whole pull is, from 0.0 to 1.0
+    pub fn fraction(&self) -> f64 {
+        if self.layers.is_empty() {
+            return 0.0;
+        }
+
+        self.layers.iter().map(|(_, p)| p).sum::<f64>() / self.layers.len() as f64
+    }
+
+    /// Number of layers which have finished
+    pub fn layers_done(&self) -> usize {
+        self.layers.iter().filter(|(_, p)| *p >= 1.0).count()
+    }
+
+    /// Number of layers seen so far. More may appear as the pull goes on.
+    pub fn layers_total(&self) -> usize {
+        self.layers.len()
+    }
+}
+
+impl DiskUsageReport {
+    /// Space which would be freed by removing images no container uses
+    pub fn reclaimable_size(&self) -> u64 {
+        self.images
+            .iter()
+            .filter(|i| i.containers == 0)
+            .map(|i| i.size)
+            .sum()
+    }
 }
 
 impl FilesystemAccess {
@@ -49,24 +232,77 @@ impl FilesystemAccess {
     }
 }
 
-/// Runs shell command. Uses flatpak-spawn if `BoxBuddy` is running as a Flatpak
-pub fn run_command(
-    cmd_to_run: &str,
-    args_for_cmd: Option<&[&str]>,
-) -> Result<std::process::Output, std::io::Error> {
+/// Container managers distrobox supports, in the order they are auto-detected
+pub const CONTAINER_MANAGERS: [&str; 3] = ["podman", "docker", "lilipod"];
+
+/// Distrobox commands which take `--root` to manage rootful boxes
+const ROOT_SUBCOMMANDS: [&str; 6] = ["create", "enter", "list", "rm", "stop", "upgrade"];
+
+/// Builds a shell command. Uses flatpak-spawn if `BoxBuddy` is running as a Flatpak.
+/// Calls to `distrobox` get the environment from `get_distrobox_env`, and
+/// `--root` when managing rootful boxes.
+fn build_command(cmd_to_run: &str, args_for_cmd: Option<&[&str]>) -> Command {
+    let is_distrobox = cmd_to_run == "distrobox";
+
     let mut cmd = Command::new(cmd_to_run);
 
     if is_flatpak() {
         cmd = Command::new("flatpak-spawn");
         cmd.arg("--host");
+        if is_distrobox {
+            for (key, value) in get_distrobox_env() {
+                cmd.arg(format!("--env={key}={value}"));
+            }
+        }
         cmd.arg(cmd_to_run);
+    } else if is_distrobox {
+        cmd.envs(get_distrobox_env());
     }
 
     if let Some(a) = args_for_cmd {
-        cmd.args(a);
+        match a.split_first() {
+            Some((subcommand, rest)) if is_distrobox && ROOT_SUBCOMMANDS.contains(subcommand) => {
+                cmd.arg(subcommand);
+                if is_rootful() {
+                    cmd.arg("--root");
+                }
+                cmd.args(rest);
+            }
+            _ => {
+                cmd.args(a);
+            }
+        }
+    }
+
+    cmd
+}
+
+/// Runs shell command. Uses flatpak-spawn if `BoxBuddy` is running as a Flatpak
+pub fn run_command(
+    cmd_to_run: &str,
+    args_for_cmd: Option<&[&str]>,
+) -> Result<std::process::Output, std::io::Error> {
+    build_command(cmd_to_run, args_for_cmd).output()
+}
+
+/// Runs shell command with `input` written to its stdin. Uses flatpak-spawn
+/// if `BoxBuddy` is running as a Flatpak.
+pub fn run_command_with_input(
+    cmd_to_run: &str,
+    args_for_cmd: Option<&[&str]>,
+    input: &[u8],
+) -> Result<std::process::Output, std::io::Error> {
+    let mut child = build_command(cmd_to_run, args_for_cmd)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin.write_all(input)?;
     }
 
-    cmd.output()
+    child.wait_with_output()
 }
 
 /// Runs shell command and returns the output as a string
@@ -114,6 +350,47 @@ pub fn get_command_output_no_err(cmd_to_run: &str, args_for_cmd: Option<&[&str]>
     }
 }
 
+/// Spawns a shell command with its stdout and stderr piped back, so its output
+/// can be streamed while it runs. Uses flatpak-spawn if `BoxBuddy` is running as a Flatpak
+pub fn spawn_command_with_output(
+    cmd_to_run: &str,
+    args_for_cmd: Option<&[&str]>,
+) -> Result<Child, std::io::Error> {
+    let mut cmd = build_command(cmd_to_run, args_for_cmd);
+
+    cmd.stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+}
+
+/// How long a spawned command is given to exit after SIGTERM before it is killed
+const STOP_GRACE_PERIOD: Duration = Duration::from_secs(2);
+
+/// Stops a command started by `spawn_command_with_output`. Sends SIGTERM
+/// rather than SIGKILL so that flatpak-spawn passes it on to the host process,
+/// and only kills it if it is still running after `STOP_GRACE_PERIOD`.
+/// Blocks until the command has exited, so call it off the UI thread.
+pub fn stop_spawned_command(child: &mut Child) {
+    let pid = child.id().to_string();
+    let _ = Command::new("kill").args(["-s", "TERM", &pid]).status();
+
+    let started = Instant::now();
+    while started.elapsed() < STOP_GRACE_PERIOD {
+        match child.try_wait() {
+            Ok(None) => std::thread::sleep(Duration::from_millis(50)),
+            // exited, or can't be waited on at all
+            _ => break,
+        }
+    }
+
+    if !matches!(child.try_wait(), Ok(Some(_))) {
+        let _ = child.kill();
+    }
+
+    let _ = child.wait();
+}
+
 /// Checks if the extension of a file (passed as a string) corresponds to a given string.
 /// Case insensitive.
 pub fn has_file_extension(path: &str, extension: &str) -> bool {
@@ -227,19 +504,23 @@ pub fn has_distrobox_installed() -> bool {
     true
 }
 
-/// Whether or not the `podman` or `docker` command can be successfully run
+/// Whether or not the container manager `BoxBuddy` is set to use (`podman`,
+/// `docker` or `lilipod`) can be successfully run
 pub fn has_podman_or_docker_installed() -> bool {
-    let output = get_command_output("which", Some(&["podman"]));
+    has_command_installed(&get_container_runtime())
+}
 
-    if output.contains("no podman in") || output.is_empty() {
-        let docker_output = get_command_output("which", Some(&["docker"]));
+/// Whether or not a command is available on the host, according to `which`
+pub fn has_command_installed(command: &str) -> bool {
+    let output = get_command_output("which", Some(&[command]));
 
-        if docker_output.contains("no docker in") || docker_output.is_empty() {
-            return false;
-        }
-    }
+    !output.contains(&format!("no {command} in")) && !output.is_empty()
+}
 
-    true
+/// Checks whether anything exists at a path on the host. Goes through
+/// flatpak-spawn if needed, as the sandbox may not be able to see it.
+pub fn host_path_exists(path: &str) -> bool {
+    run_command("test", Some(&["-e", path])).is_ok_and(|o| o.status.success())
 }
 
 /// Returns a Vec of `TerminalOption`s representing all terminals supported by `BoxBuddy`
@@ -448,50 +729,147 @@ pub fn get_users_supported_terminal_flatpaks() -> Vec<String> {
     user_flatpak_terms
 }
 
-/// Returns "podman" or "docker", based on which is installed, for use by
-/// `get_repository_list` below
+/// Returns the container manager to use - "podman", "docker" or "lilipod".
+/// Uses the `container-manager` setting if one has been chosen, otherwise
+/// `DBX_CONTAINER_MANAGER` if it is set, otherwise the first one installed,
+/// which is the same one distrobox picks for itself.
 pub fn get_container_runtime() -> String {
-    let mut runtime = String::from("podman");
+    if let Some(chosen_manager) = get_chosen_container_manager() {
+        return chosen_manager;
+    }
+
+    // distrobox itself honours this, so we should too
+    if let Ok(env_manager) = env::var("DBX_CONTAINER_MANAGER") {
+        if CONTAINER_MANAGERS.contains(&env_manager.as_str()) {
+            return env_manager;
+        }
+    }
+
+    if let Some(conf_manager) = get_distrobox_config_value("container_manager") {
+        if CONTAINER_MANAGERS.contains(&conf_manager.as_str()) {
+            return conf_manager;
+        }
+    }
+
+    for manager in CONTAINER_MANAGERS {
+        if has_command_installed(manager) {
+            return manager.to_string();
+        }
+    }
+
+    String::from(CONTAINER_MANAGERS[0])
+}
 
-    let output = get_command_output("which", Some(&["podman"]));
-    if output.contains("no podman in") || output.is_empty() {
-        runtime = String::from("docker");
+/// The container manager picked in Preferences, or None if it is left on "auto"
+fn get_chosen_container_manager() -> Option<String> {
+    let settings = Settings::new(APP_ID);
+    let chosen_manager = settings.string("container-manager");
+
+    CONTAINER_MANAGERS
+        .contains(&chosen_manager.as_str())
+        .then(|| chosen_manager.to_string())
+}
+
+/// Whether `BoxBuddy` manages rootful boxes, which distrobox runs through sudo
+pub fn is_rootful() -> bool {
+    Settings::new(APP_ID).boolean("rootful")
+}
+
+/// Environment variables to call distrobox with. The container manager is only
+/// passed on when one has been chosen, so that "auto" leaves distrobox to pick
+/// its own. Rootful boxes use pkexec to ask for a password, as there's no
+/// terminal for sudo to ask in, unless the user has set their own sudo program.
+pub fn get_distrobox_env() -> Vec<(String, String)> {
+    let mut env_vars = vec![];
+
+    if let Some(manager) = get_chosen_container_manager() {
+        env_vars.push((String::from("DBX_CONTAINER_MANAGER"), manager));
+    }
+
+    if is_rootful()
+        && env::var_os("DBX_SUDO_PROGRAM").is_none()
+        && get_distrobox_config_value("distrobox_sudo_program").is_none()
+    {
+        env_vars.push((String::from("DBX_SUDO_PROGRAM"), String::from("pkexec")));
+    }
+
+    env_vars
+}
+
+/// Returns the argv to run a distrobox subcommand with the environment from
+/// `get_distrobox_env`, and `--root` when managing rootful boxes. Used in places
+/// which can't set the environment directly (such as spawning a terminal).
+pub fn get_distrobox_command(subcommand: &str) -> Vec<String> {
+    let mut command = vec![String::from("env")];
+    command.extend(
+        get_distrobox_env()
+            .into_iter()
+            .map(|(key, value)| format!("{key}={value}")),
+    );
+    command.push(String::from("distrobox"));
+    command.push(subcommand.to_string());
+
+    if is_rootful() && ROOT_SUBCOMMANDS.contains(&subcommand) {
+        command.push(String::from("--root"));
     }
 
-    runtime
+    command
 }
 
-/// Gets CPU and Memory used for each box.
+/// Gets CPU, Memory, Network and Block I/O used by every running box, keyed
+/// by box name. Uses a single `stats --no-stream` call for all containers.
 /// In here instead of Distrobox Handler because we have
 /// to shell out to the actual runtime.
-pub fn get_cpu_and_mem_usage(box_name: &str) -> CpuMemUsage {
+pub fn get_all_cpu_and_mem_usage() -> HashMap<String, CpuMemUsage> {
     let runtime = get_container_runtime();
     let stats_output = get_command_output_no_err(
         &runtime,
         Some(&[
             "stats",
-            box_name,
             "--no-stream",
             "--format",
-            "{{.CPUPerc}};{{.MemPerc}};{{.MemUsage}}",
+            "{{.Name}};{{.CPUPerc}};{{.MemPerc}};{{.MemUsage}};{{.NetIO}};{{.BlockIO}}",
         ]),
     );
 
-    let output_pieces: Vec<&str> = stats_output.split(';').collect();
-    if output_pieces.len() != 3 {
-        // We failed to get the output for some reason
-        return CpuMemUsage {
-            cpu: String::new(),
-            mem: String::new(),
-            mem_percent: String::new(),
-        };
-    }
+    parse_stats_output(&stats_output)
+}
+
+/// Parses the output of `stats --no-stream` using the format in
+/// `get_all_cpu_and_mem_usage`. Lines which don't match are skipped.
+pub fn parse_stats_output(stats_output: &str) -> HashMap<String, CpuMemUsage> {
+    let mut usage = HashMap::new();
+
+    for line in stats_output.lines() {
+        let output_pieces: Vec<&str> = line.split(';').map(str::trim).collect();
+        if output_pieces.len() != 6 || output_pieces[0].is_empty() {
+            continue;
+        }
 
-    CpuMemUsage {
-        cpu: output_pieces[0].trim().to_string(),
-        mem: output_pieces[1].trim().to_string(),
-        mem_percent: output_pieces[2].trim().to_string(),
+        usage.insert(
+            output_pieces[0].to_string(),
+            CpuMemUsage {
+                cpu: output_pieces[1].to_string(),
+                mem: output_pieces[2].to_string(),
+                mem_percent: output_pieces[3].to_string(),
+                net_io: output_pieces[4].to_string(),
+                block_io: output_pieces[5].to_string(),
+            },
+        );
     }
+
+    usage
+}
+
+/// Turns a percentage from the runtime, such as "12.34%", into a number.
+/// Returns 0 if it can't be parsed.
+pub fn parse_percentage(percentage: &str) -> f64 {
+    percentage
+        .trim()
+        .trim_end_matches('%')
+        .trim()
+        .parse::<f64>()
+        .unwrap_or(0.0)
 }
 
 /// Returns a Vec of "image:version" strings for all container images already
@@ -513,117 +891,1047 @@ pub fn get_repository_list() -> Vec<String> {
         .collect();
 }
 
-/// Whether or not `BoxBuddy` is running as a Flatpak
-pub fn is_flatpak() -> bool {
-    let fp_env = std::env::var("FLATPAK_ID").is_ok();
-    if fp_env {
-        return true;
-    }
+/// Lists every downloaded image, along with the boxes which use each one
+pub fn get_container_images() -> Vec<ContainerImage> {
+    let runtime = get_container_runtime();
+    let output = get_command_output_no_err(
+        &runtime,
+        Some(&[
+            "images",
+            "--format",
+            "{{.ID}};{{.Repository}}:{{.Tag}};{{.Size}};{{.CreatedAt}}",
+        ]),
+    );
 
-    Path::new("/.flatpak-info").exists()
+    let box_image_ids = get_box_image_ids(&runtime);
+
+    let mut images = parse_images_output(&output);
+    set_image_boxes(&mut images, &box_image_ids);
+
+    images
 }
 
-/// Whether or not the user appears to have an NVIDIA card, used to pass
-/// the --nvidia flag when creating a new box.
-pub fn is_nvidia() -> bool {
-    let which_lspci = get_command_output("which", Some(&["lspci"]));
-    if which_lspci.contains("no lspci") || which_lspci.is_empty() {
-        // cant detect hardware, assume no
-        return false;
+/// Fills in the boxes of each image, from the full image ID of each box
+fn set_image_boxes(images: &mut [ContainerImage], box_image_ids: &HashMap<String, String>) {
+    for image in images {
+        image.boxes = box_image_ids
+            .iter()
+            .filter(|(_, image_id)| !image.id.is_empty() && image_id.starts_with(&image.id))
+            .map(|(box_name, _)| box_name.clone())
+            .collect();
+        image.boxes.sort();
     }
+}
 
-    let lspci_output = get_command_output("lspci", None);
-
-    let mut has_nvidia = false;
+/// Parses the output of `images --format "{{.ID}};{{.Repository}}:{{.Tag}};{{.Size}};{{.CreatedAt}}"`.
+/// The boxes of each image are left empty.
+pub fn parse_images_output(output: &str) -> Vec<ContainerImage> {
+    let mut images = Vec::new();
 
-    for line in lspci_output.lines() {
-        if line.contains("NVIDIA") {
-            has_nvidia = true;
-            break;
+    for line in output.lines() {
+        let fields: Vec<&str> = line.trim().split(';').collect();
+        if fields.len() < 4 {
+            continue;
         }
+
+        images.push(ContainerImage {
+            id: fields[0].to_string(),
+            name: fields[1].to_string(),
+            size: parse_size(fields[2]),
+            // "2024-01-10 12:00:00 +0000 UTC", we only want the date
+            created: fields[3]
+                .split_whitespace()
+                .next()
+                .unwrap_or_default()
+                .to_string(),
+            boxes: Vec::new(),
+        });
     }
 
-    has_nvidia
+    images
 }
 
-/// Set up gettext
-#[allow(unused_assignments)]
-pub fn set_up_localisation() {
-    textdomain("boxbuddyrs").expect("failed to initialise gettext");
-    bind_textdomain_codeset("boxbuddyrs", "UTF-8").expect("failed to bind textdomain for gettext");
+/// Gets the full ID of the image each box was created from, keyed by box name
+fn get_box_image_ids(runtime: &str) -> HashMap<String, String> {
+    let mut image_ids = HashMap::new();
 
-    let language_code = env::var("LANG").unwrap_or_else(|_| "en_US".to_string());
+    let my_boxes = get_all_distroboxes();
+    if my_boxes.is_empty() {
+        return image_ids;
+    }
 
-    let mut locale_directory = String::from("./po");
+    let mut args = vec![
+        "inspect",
+        "--type",
+        "container",
+        "--format",
+        "{{.Name}}|{{.Image}}",
+    ];
+    args.extend(my_boxes.iter().map(|b| b.name.as_str()));
 
-    // --TRANSLATORS: Comment out the next 8 lines to test your development locale
-    if is_flatpak() {
-        locale_directory = String::from("/app/po");
+    let output = get_command_output_no_err(runtime, Some(args.as_slice()));
+    image_ids.extend(parse_box_image_ids(&output));
+
+    image_ids
+}
+
+/// Parses the output of `inspect --format "{{.Name}}|{{.Image}}"`
+fn parse_box_image_ids(output: &str) -> HashMap<String, String> {
+    let mut image_ids = HashMap::new();
+
+    for line in output.lines() {
+        let Some((name, image_id)) = line.split_once('|') else {
+            continue;
+        };
+
+        // docker prefixes container names with a slash, and image IDs with their digest type
+        let name = name.trim().trim_start_matches('/');
+        let image_id = image_id.trim().trim_start_matches("sha256:");
+
+        image_ids.insert(name.to_string(), image_id.to_string());
+    }
+
+    image_ids
+}
+
+/// Runs an image command on the runtime, returning its stderr if it fails
+fn run_image_command(args: &[&str]) -> Result<String, String> {
+    let runtime = get_container_runtime();
+    let output = run_command(&runtime, Some(args)).map_err(|e| e.to_string())?;
+
+    if output.status.success() {
+        Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
     } else {
-        let home_dir = env::var("HOME").unwrap_or_else(|_| ".".to_string());
-        let data_home =
-            env::var("XDG_DATA_HOME").unwrap_or_else(|_| format!("{home_dir}/.local/share"));
+        Err(String::from_utf8_lossy(&output.stderr).trim().to_string())
+    }
+}
 
-        locale_directory = format!("{data_home}/locale");
+/// Downloads an image, such as "docker.io/library/ubuntu:24.04"
+pub fn pull_image(image: &str) -> Result<String, String> {
+    run_image_command(&["pull", image])
+}
+
+/// Starts pulling an image, with its output piped so its progress can be shown
+pub fn spawn_image_pull(image: &str) -> Result<Child, std::io::Error> {
+    let runtime = get_container_runtime();
+    spawn_command_with_output(&runtime, Some(&["pull", image]))
+}
+
+/// Checks whether an image has already been downloaded
+pub fn is_image_downloaded(image: &str) -> bool {
+    let runtime = get_container_runtime();
+    run_command(
+        &runtime,
+        Some(&["image", "inspect", "--format", "{{.Id}}", image]),
+    )
+    .is_ok_and(|output| output.status.success())
+}
+
+/// Parses a line of `pull` output into a layer ID and how far along that layer is.
+/// Podman writes lines like "Copying blob 4f4fb700ef54 done", docker ones
+/// like "4f4fb700ef54: Downloading [==>   ]  1.2MB/45.1MB".
+pub fn parse_pull_line(line: &str) -> Option<(String, f64)> {
+    let line = line.trim();
+
+    if let Some(rest) = line.strip_prefix("Copying blob ") {
+        let mut parts = rest.split_whitespace();
+        let layer_id = parts.next()?.trim_start_matches("sha256:");
+        let status = parts.collect::<Vec<&str>>().join(" ");
+
+        let progress = if status.contains("done")
+            || status.contains("skipped")
+            || status.contains("already exists")
+        {
+            1.0
+        } else {
+            parse_transfer_fraction(&status).unwrap_or(0.0)
+        };
+
+        return Some((layer_id.chars().take(12).collect(), progress));
     }
 
-    let locale_directory_path = std::path::PathBuf::from(&locale_directory);
-    gettextrs::bindtextdomain("boxbuddyrs", locale_directory_path).expect("a");
+    let (layer_id, status) = line.split_once(": ")?;
+    if layer_id.len() != 12 || !layer_id.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
 
-    setlocale(LocaleCategory::LcMessages, language_code);
+    // downloading is most of the work, extracting the rest
+    let progress = if status.starts_with("Pull complete") || status.starts_with("Already exists") {
+        1.0
+    } else if status.starts_with("Download complete") || status.starts_with("Verifying Checksum") {
+        0.7
+    } else if status.starts_with("Downloading") {
+        0.7 * parse_transfer_fraction(status).unwrap_or(0.0)
+    } else if status.starts_with("Extracting") {
+        0.7 + 0.3 * parse_transfer_fraction(status).unwrap_or(0.0)
+    } else {
+        0.0
+    };
+
+    Some((layer_id.to_string(), progress))
 }
 
-/// Gets list of .desktop files on the host system which may have been exported from
-/// a box. This is to determine whether to show the "Remove from Menu" button on the
-/// View Applications pop-up
-pub fn get_host_desktop_files() -> Vec<String> {
-    let mut host_apps: Vec<String> = Vec::<String>::new();
+/// Reads a transfer such as "12.0MiB / 45.1MiB" from the end of a line as a fraction
+fn parse_transfer_fraction(status: &str) -> Option<f64> {
+    let (done, total) = status.rsplit_once('/')?;
+    let done = parse_size(done.split_whitespace().last()?.trim_start_matches(']'));
+    let total = parse_size(total.split_whitespace().next()?);
 
-    if is_flatpak() {
-        // we can't use fs in the flatpak sandbox, so parse `ls`.
-        let mut data_home = get_command_output("bash", Some(&["-c", "echo $XDG_DATA_HOME"]));
-        if data_home.trim().is_empty() {
-            let mut home_dir = get_command_output("bash", Some(&["-c", "echo $HOME"]));
-            home_dir = home_dir.trim().to_string();
-            data_home = format!("{home_dir}/.local/share");
-        }
+    if total == 0 {
+        return None;
+    }
 
-        let applications_dir = format!("{data_home}/applications");
+    Some((done as f64 / total as f64).min(1.0))
+}
 
-        let ls_lines = get_command_output("ls", Some(&[applications_dir.as_str()]));
+/// Checks the registry of each image for a newer version than the one downloaded,
+/// by comparing digests. Images which can't be checked - because skopeo isn't
+/// installed, the registry can't be reached or the image was built locally - are left out.
+pub fn get_image_updates(images: &[String]) -> HashMap<String, bool> {
+    let mut updates = HashMap::new();
 
-        let desktop_files = ls_lines.split('\n');
-        for df in desktop_files {
-            if !df.is_empty() {
-                host_apps.push(df.to_string());
-            }
+    if !has_command_installed("skopeo") {
+        return updates;
+    }
+
+    let runtime = get_container_runtime();
+
+    for image in images {
+        let local_digests = get_command_output_no_err(
+            &runtime,
+            Some(&[
+                "image",
+                "inspect",
+                "--format",
+                "{{range .RepoDigests}}{{.}} {{end}}",
+                image,
+            ]),
+        );
+        let local_digests: Vec<&str> = local_digests
+            .split_whitespace()
+            .filter_map(|d| d.split_once('@').map(|(_, digest)| digest))
+            .collect();
+
+        // built locally, so there's nothing to compare against
+        if local_digests.is_empty() {
+            continue;
         }
-    } else {
-        let home_dir = env::var("HOME").unwrap_or_else(|_| ".".to_string());
-        let data_home =
-            env::var("XDG_DATA_HOME").unwrap_or_else(|_| format!("{home_dir}/.local/share"));
 
-        let applications_dir = format!("{data_home}/applications");
-        let applications_dir_path = Path::new(&applications_dir);
+        let Ok(output) = run_command(
+            "skopeo",
+            Some(&[
+                "inspect",
+                "--format",
+                "{{.Digest}}",
+                &format!("docker://{image}"),
+            ]),
+        ) else {
+            continue;
+        };
 
-        if applications_dir_path.exists() {
-            let my_apps = std::fs::read_dir(applications_dir_path);
-            if let Ok(apps) = my_apps {
-                for host_app in apps.flatten() {
-                    if let Ok(fname) = host_app.file_name().into_string() {
-                        host_apps.push(fname);
-                    }
-                }
-            }
+        let remote_digest = String::from_utf8_lossy(&output.stdout).trim().to_string();
+        if !output.status.success() || remote_digest.is_empty() {
+            continue;
         }
+
+        // multi-arch images have a digest for the list and one for our platform,
+        // and which one the registry reports varies
+        updates.insert(
+            image.clone(),
+            !local_digests.contains(&remote_digest.as_str()),
+        );
     }
 
-    host_apps
+    updates
 }
 
-/// Returns a struct which allows us to determine whether the user has added
-/// a `home` or `host` Filesystem override to a Flatpak install.
-/// This lets us disable features which won't work without these permissions.
+/// Gets a box's custom home directory, or None if it uses the host's home
+pub fn get_custom_home_path(box_name: &str) -> Option<String> {
+    let runtime = get_container_runtime();
+    get_custom_home_paths(&runtime, &[box_name]).remove(box_name)
+}
+
+/// Removes a downloaded image. Fails if any container still uses it.
+pub fn remove_image(image: &str) -> Result<String, String> {
+    run_image_command(&["rmi", image])
+}
+
+/// Removes every image which no container is using
+pub fn prune_images() -> Result<String, String> {
+    run_image_command(&["image", "prune", "--all", "--force"])
+}
+
+/// Longest box name allowed. Distrobox uses the name as the box's hostname
+/// by default, and hostnames can't be longer than this.
+pub const MAX_BOX_NAME_LENGTH: usize = 63;
+
+/// Why a name can't be used for a new box
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BoxNameError {
+    Empty,
+    TooLong,
+    /// Podman and Docker only allow letters, numbers, `_`, `.` and `-`,
+    /// starting with a letter or number
+    InvalidCharacters,
+    AlreadyExists,
+}
+
+/// Checks a name can be given to a new box, following the container
+/// runtimes' naming rules. `existing_names` are the names of the user's boxes.
+pub fn validate_box_name(name: &str, existing_names: &[String]) -> Result<(), BoxNameError> {
+    let mut chars = name.chars();
+    let Some(first_char) = chars.next() else {
+        return Err(BoxNameError::Empty);
+    };
+
+    if name.len() > MAX_BOX_NAME_LENGTH {
+        return Err(BoxNameError::TooLong);
+    }
+
+    let is_valid = first_char.is_ascii_alphanumeric()
+        && chars.all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-'));
+    if !is_valid {
+        return Err(BoxNameError::InvalidCharacters);
+    }
+
+    if existing_names.iter().any(|n| n == name) {
+        return Err(BoxNameError::AlreadyExists);
+    }
+
+    Ok(())
+}
+
+/// Checks an image reference has the form `[registry[:port]/]path[:tag][@digest]`,
+/// such as "registry.fedoraproject.org/fedora-toolbox:40". It isn't checked against any registry.
+pub fn is_valid_image_reference(image: &str) -> bool {
+    if image.is_empty() || image.chars().any(char::is_whitespace) {
+        return false;
+    }
+
+    let (name_and_tag, digest) = match image.split_once('@') {
+        Some((name_and_tag, digest)) => (name_and_tag, Some(digest)),
+        None => (image, None),
+    };
+
+    if let Some(digest) = digest {
+        let Some((algorithm, hex)) = digest.split_once(':') else {
+            return false;
+        };
+        let is_valid_digest = !algorithm.is_empty()
+            && algorithm.chars().all(|c| c.is_ascii_alphanumeric())
+            && hex.len() >= 32
+            && hex.chars().all(|c| c.is_ascii_hexdigit());
+        if !is_valid_digest {
+            return false;
+        }
+    }
+
+    let (repository, tag) = split_image_reference(name_and_tag);
+
+    if let Some(tag) = tag {
+        let is_valid_tag = tag.len() <= 128
+            && tag
+                .chars()
+                .next()
+                .is_some_and(|c| c.is_ascii_alphanumeric() || c == '_')
+            && tag
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || matches!(c, '_' | '.' | '-'));
+        if !is_valid_tag {
+            return false;
+        }
+    }
+
+    let mut components: Vec<&str> = repository.split('/').collect();
+
+    // the first part is a registry if it looks like a host name
+    if components.len() > 1
+        && (components[0].contains('.')
+            || components[0].contains(':')
+            || components[0] == "localhost")
+    {
+        let registry = components.remove(0);
+        let (host, port) = match registry.split_once(':') {
+            Some((host, port)) => (host, Some(port)),
+            None => (registry, None),
+        };
+
+        let is_valid_host = !host.is_empty()
+            && host.split('.').all(|part| {
+                !part.is_empty() && part.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+            });
+        let is_valid_port =
+            port.is_none_or(|p| !p.is_empty() && p.chars().all(|c| c.is_ascii_digit()));
+        if !is_valid_host || !is_valid_port {
+            return false;
+        }
+    }
+
+    // path parts are lowercase, with single separators between letters and numbers
+    components.iter().all(|component| {
+        let is_lower_alnum = |c: char| c.is_ascii_lowercase() || c.is_ascii_digit();
+        component.starts_with(is_lower_alnum)
+            && component.ends_with(is_lower_alnum)
+            && component
+                .chars()
+                .all(|c| is_lower_alnum(c) || matches!(c, '.' | '_' | '-'))
+            && !component.contains("..")
+            && !component.contains("___")
+    })
+}
+
+/// Splits an image reference into its repository and tag. A colon only
+/// starts the tag if it comes after the last slash, as registries can have ports.
+pub fn split_image_reference(image: &str) -> (&str, Option<&str>) {
+    let image = image.split('@').next().unwrap_or_default();
+    let last_slash = image.rfind('/').map_or(0, |idx| idx + 1);
+
+    match image[last_slash..].rfind(':') {
+        Some(colon) => (
+            &image[..last_slash + colon],
+            Some(&image[last_slash + colon + 1..]),
+        ),
+        None => (image, None),
+    }
+}
+
+/// Lists the tags a registry has for a repository, such as "fedora" or
+/// "quay.io/toolbx/ubuntu-toolbox". Uses skopeo if it is installed, otherwise
+/// podman's search. Docker and lilipod can't list tags.
+pub fn get_image_tags(repository: &str) -> Result<Vec<String>, String> {
+    if has_command_installed("skopeo") {
+        let output = run_command(
+            "skopeo",
+            Some(&["list-tags", &format!("docker://{repository}")]),
+        )
+        .map_err(|e| e.to_string())?;
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+        }
+
+        return Ok(parse_skopeo_tags(&String::from_utf8_lossy(&output.stdout)));
+    }
+
+    if get_container_runtime() == "podman" {
+        let output = run_command(
+            "podman",
+            Some(&[
+                "search",
+                "--list-tags",
+                "--limit",
+                "1000",
+                "--format",
+                "{{.Tag}}",
+                repository,
+            ]),
+        )
+        .map_err(|e| e.to_string())?;
+        if !output.status.success() {
+            return Err(String::from_utf8_lossy(&output.stderr).trim().to_string());
+        }
+
+        return Ok(String::from_utf8_lossy(&output.stdout)
+            .lines()
+            .map(|t| t.trim().to_string())
+            .filter(|t| !t.is_empty())
+            .collect());
+    }
+
+    Err(String::from("skopeo is not installed"))
+}
+
+/// Pulls the tags out of `skopeo list-tags` output, which looks like
+/// `{"Repository": "...", "Tags": ["39", "40", "latest"]}`
+pub fn parse_skopeo_tags(output: &str) -> Vec<String> {
+    let Some(tags_start) = output.find("\"Tags\"") else {
+        return Vec::new();
+    };
+    let tags_section = &output[tags_start..];
+
+    let (Some(open), Some(close)) = (tags_section.find('['), tags_section.find(']')) else {
+        return Vec::new();
+    };
+
+    // tags can't contain quotes or commas, so there's nothing to unescape
+    tags_section[open + 1..close]
+        .split(',')
+        .map(|t| t.trim().trim_matches('"').to_string())
+        .filter(|t| !t.is_empty())
+        .collect()
+}
+
+/// Parses a JSON array of strings, such as the output of `{{json .Args}}` in an
+/// `inspect` format. Returns None if it is anything else.
+pub fn parse_json_string_array(json: &str) -> Option<Vec<String>> {
+    let mut chars = json.trim().chars().peekable();
+    let mut strings = Vec::new();
+
+    if chars.next()? != '[' {
+        return None;
+    }
+
+    loop {
+        match chars.find(|c| !c.is_whitespace())? {
+            ']' if strings.is_empty() => break,
+            '"' => {}
+            _ => return None,
+        }
+
+        let mut string = String::new();
+        loop {
+            match chars.next()? {
+                '"' => break,
+                '\\' => match chars.next()? {
+                    'n' => string.push('\n'),
+                    't' => string.push('\t'),
+                    'r' => string.push('\r'),
+                    'b' => string.push('\u{8}'),
+                    'f' => string.push('\u{c}'),
+                    'u' => {
+                        let hex: String = chars.by_ref().take(4).collect();
+                        let code = u32::from_str_radix(&hex, 16).ok()?;
+                        // characters outside the BMP come as surrogate pairs, which are left out
+                        string.extend(char::from_u32(code));
+                    }
+                    other => string.push(other),
+                },
+                c => string.push(c),
+            }
+        }
+        strings.push(string);
+
+        match chars.find(|c| !c.is_whitespace())? {
+            ',' => {}
+            ']' => break,
+            _ => return None,
+        }
+    }
+
+    chars.all(char::is_whitespace).then_some(strings)
+}
+
+/// Gets the disk space used by each box - its writable layer, its image and
+/// its custom home directory, if it has one - along with all downloaded images.
+/// Uses `system df -v` on the runtime, which can be slow.
+pub fn get_disk_usage() -> DiskUsageReport {
+    let runtime = get_container_runtime();
+    let output = get_command_output_no_err(&runtime, Some(&["system", "df", "-v"]));
+    let sections = parse_system_df_output(&output);
+
+    let mut report = DiskUsageReport::default();
+
+    if let Some(image_rows) = sections.get("Images") {
+        for row in image_rows {
+            let repo = row.get("REPOSITORY").cloned().unwrap_or_default();
+            let tag = row.get("TAG").cloned().unwrap_or_default();
+
+            report.images.push(ImageDiskUsage {
+                name: format!("{repo}:{tag}"),
+                id: row.get("IMAGE ID").cloned().unwrap_or_default(),
+                size: parse_size(row.get("SIZE").map_or("", String::as_str)),
+                containers: row
+                    .get("CONTAINERS")
+                    .and_then(|c| c.parse::<u32>().ok())
+                    .unwrap_or(0),
+            });
+        }
+    }
+
+    let my_boxes = get_all_distroboxes();
+    let box_names: Vec<&str> = my_boxes.iter().map(|b| b.name.as_str()).collect();
+    let home_paths = get_custom_home_paths(&runtime, &box_names);
+    let container_rows = sections.get("Containers").cloned().unwrap_or_default();
+
+    for dbox in &my_boxes {
+        let container_row = container_rows
+            .iter()
+            .find(|r| r.get("NAMES").is_some_and(|n| n == &dbox.name));
+
+        let container_size =
+            container_row.map_or(0, |r| parse_size(r.get("SIZE").map_or("", String::as_str)));
+
+        // podman lists the image ID against the container, docker the image name
+        let container_image = container_row
+            .and_then(|r| r.get("IMAGE").cloned())
+            .unwrap_or_default();
+        let image_size = report
+            .images
+            .iter()
+            .find(|i| {
+                i.name == dbox.image_url
+                    || (!container_image.is_empty()
+                        && (i.name == container_image || i.id.starts_with(&container_image)))
+            })
+            .map_or(0, |i| i.size);
+
+        let home_path = home_paths.get(&dbox.name).cloned();
+        let home_size = home_path.as_deref().map_or(0, get_directory_size);
+
+        report.boxes.push(BoxDiskUsage {
+            name: dbox.name.clone(),
+            container_size,
+            image: dbox.image_url.clone(),
+            image_size,
+            home_path,
+            home_size,
+        });
+    }
+
+    report
+}
+
+/// Splits the output of `system df -v` into its sections ("Images",
+/// "Containers", "Local Volumes" and docker's "Build cache"), each a list of
+/// rows keyed by column heading.
+pub fn parse_system_df_output(output: &str) -> HashMap<String, Vec<HashMap<String, String>>> {
+    let mut sections: HashMap<String, Vec<HashMap<String, String>>> = HashMap::new();
+
+    let mut current_section = String::new();
+    let mut header: Option<&str> = None;
+
+    for line in output.lines() {
+        if line.trim().is_empty() {
+            continue;
+        }
+
+        // e.g. "Images space usage:", or docker's "Build cache usage: 0B"
+        if let Some((section, _)) = line.trim().split_once(" usage:") {
+            current_section = section.trim_end_matches(" space").trim().to_string();
+            header = None;
+            continue;
+        }
+
+        if current_section.is_empty() {
+            continue;
+        }
+
+        match header {
+            None => header = Some(line),
+            Some(h) => sections
+                .entry(current_section.clone())
+                .or_default()
+                .push(split_row_by_headings(h, line)),
+        }
+    }
+
+    sections
+}
+
+/// Splits a row of column-aligned output (such as `system df -v`) into cells,
+/// keyed by the heading each cell sits under. Columns are found by their
+/// position in the header, so empty cells and cells containing spaces are fine.
+fn split_row_by_headings(header: &str, row: &str) -> HashMap<String, String> {
+    let header_chars: Vec<char> = header.chars().collect();
+    let row_chars: Vec<char> = row.chars().collect();
+
+    // headings can contain single spaces, e.g. "IMAGE ID", so only two or
+    // more spaces separate columns
+    let mut headings: Vec<(usize, String)> = Vec::new();
+    let mut idx = 0;
+    while idx < header_chars.len() {
+        if header_chars[idx] == ' ' {
+            idx += 1;
+            continue;
+        }
+
+        let start = idx;
+        while idx < header_chars.len() {
+            let is_last = idx + 1 == header_chars.len();
+            if header_chars[idx] == ' ' && (is_last || header_chars[idx + 1] == ' ') {
+                break;
+            }
+            idx += 1;
+        }
+
+        headings.push((start, header_chars[start..idx].iter().collect()));
+    }
+
+    let mut cells = HashMap::new();
+    for (heading_idx, (start, heading)) in headings.iter().enumerate() {
+        let end = headings
+            .get(heading_idx + 1)
+            .map_or(row_chars.len(), |(next_start, _)| *next_start)
+            .min(row_chars.len());
+        let start = (*start).min(end);
+
+        let cell: String = row_chars[start..end].iter().collect();
+        cells.insert(heading.clone(), cell.trim().to_string());
+    }
+
+    cells
+}
+
+/// Returns the custom home directory of each box which has one, keyed by box name.
+/// Distrobox always sets `HOME` in the container, so anything other than the
+/// user's own home is a custom one.
+fn get_custom_home_paths(runtime: &str, box_names: &[&str]) -> HashMap<String, String> {
+    let mut home_paths = HashMap::new();
+    if box_names.is_empty() {
+        return home_paths;
+    }
+
+    let host_home = env::var("HOME").unwrap_or_default();
+
+    let mut args = vec![
+        "inspect",
+        "--type",
+        "container",
+        "--format",
+        "{{.Name}}|{{range .Config.Env}}{{.}};{{end}}",
+    ];
+    args.extend_from_slice(box_names);
+
+    let output = get_command_output_no_err(runtime, Some(args.as_slice()));
+
+    for line in output.lines() {
+        let Some((name, env_vars)) = line.split_once('|') else {
+            continue;
+        };
+
+        // docker prefixes container names with a slash
+        let name = name.trim().trim_start_matches('/');
+
+        let home = env_vars
+            .split(';')
+            .find_map(|e| e.strip_prefix("HOME="))
+            .unwrap_or_default()
+            .trim_end_matches('/');
+
+        if !home.is_empty() && home != host_home.trim_end_matches('/') {
+            home_paths.insert(name.to_string(), home.to_string());
+        }
+    }
+
+    home_paths
+}
+
+/// Size of a directory on the host in bytes, via `du`. Returns 0 if it can't be read.
+pub fn get_directory_size(path: &str) -> u64 {
+    let output = get_command_output_no_err("du", Some(&["-s", "-B1", path]));
+
+    output
+        .split_whitespace()
+        .next()
+        .and_then(|s| s.parse::<u64>().ok())
+        .unwrap_or(0)
+}
+
+/// Turns a size from the runtime, such as "1.9GB" or "12.3 MiB", into bytes.
+/// Returns 0 if it can't be parsed.
+pub fn parse_size(size: &str) -> u64 {
+    let size = size.trim();
+    let unit_start = size
+        .find(|c: char| !c.is_ascii_digit() && c != '.')
+        .unwrap_or(size.len());
+
+    let Ok(number) = size[..unit_start].parse::<f64>() else {
+        return 0;
+    };
+
+    let multiplier: f64 = match size[unit_start..].trim().to_lowercase().as_str() {
+        "" | "b" => 1.0,
+        "kb" => 1e3,
+        "kib" => 1024.0,
+        "mb" => 1e6,
+        "mib" => 1024.0 * 1024.0,
+        "gb" => 1e9,
+        "gib" => 1024.0 * 1024.0 * 1024.0,
+        "tb" => 1e12,
+        "tib" => 1024.0 * 1024.0 * 1024.0 * 1024.0,
+        _ => return 0,
+    };
+
+    (number * multiplier) as u64
+}
+
+/// Turns a number of bytes into a human-readable size, such as "1.9 GB"
+pub fn format_size(bytes: u64) -> String {
+    let units = ["B", "kB", "MB", "GB", "TB"];
+
+    let mut size = bytes as f64;
+    let mut unit_idx = 0;
+    while size >= 1000.0 && unit_idx < units.len() - 1 {
+        size /= 1000.0;
+        unit_idx += 1;
+    }
+
+    if unit_idx == 0 {
+        return format!("{bytes} B");
+    }
+
+    format!("{size:.1} {}", units[unit_idx])
+}
+
+/// Starts the runtime's `logs` command for a box, with its output piped back
+/// for streaming. Use `stop_spawned_command` to stop following.
+pub fn spawn_box_logs(
+    box_name: &str,
+    follow: bool,
+    timestamps: bool,
+) -> Result<Child, std::io::Error> {
+    let runtime = get_container_runtime();
+
+    let mut args = vec!["logs"];
+    if follow {
+        args.push("--follow");
+    }
+    if timestamps {
+        args.push("--timestamps");
+    }
+    args.push(box_name);
+
+    spawn_command_with_output(&runtime, Some(args.as_slice()))
+}
+
+/// Lists the processes running inside a box, with their PIDs as seen from the host.
+///
+/// Boxes share the host's PID namespace unless everything is separated, so
+/// `top` and `ps` inside the box would list every process on the host. Instead
+/// the host's `ps` is filtered to the processes in the box's own cgroup.
+pub fn get_box_processes(box_name: &str) -> Vec<BoxProcess> {
+    let Ok(container_id) = get_container_id(box_name) else {
+        return vec![];
+    };
+
+    let output = get_command_output_no_err("ps", Some(&["-eo", "pid,user,pcpu,pmem,cgroup,args"]));
+
+    parse_top_output(&output)
+        .into_iter()
+        .filter(|process| is_in_container_cgroup(&process.cgroup, &container_id))
+        .collect()
+}
+
+/// Gets the full ID of a box's container
+fn get_container_id(box_name: &str) -> Result<String, String> {
+    run_image_command(&[
+        "inspect",
+        "--type",
+        "container",
+        "--format",
+        "{{.Id}}",
+        box_name,
+    ])
+}
+
+/// Parses the output of `ps` using the columns from `get_box_processes`.
+/// The command is last as it can contain spaces. The header, and any other
+/// line which doesn't start with a PID, is skipped.
+pub fn parse_top_output(output: &str) -> Vec<BoxProcess> {
+    let mut processes = Vec::new();
+
+    for line in output.lines() {
+        let mut pieces = line.split_whitespace();
+        let (Some(pid), Some(user), Some(cpu), Some(mem), Some(cgroup)) = (
+            pieces.next(),
+            pieces.next(),
+            pieces.next(),
+            pieces.next(),
+            pieces.next(),
+        ) else {
+            continue;
+        };
+
+        if pid.parse::<u32>().is_err() {
+            continue;
+        }
+
+        processes.push(BoxProcess {
+            pid: pid.to_string(),
+            user: user.to_string(),
+            cpu: cpu.to_string(),
+            mem: mem.to_string(),
+            cgroup: cgroup.to_string(),
+            command: pieces.collect::<Vec<&str>>().join(" "),
+        });
+    }
+
+    processes
+}
+
+/// Whether a process's cgroups - as in `ps`'s cgroup column or
+/// `/proc/<pid>/cgroup` - belong to a container. Podman and Docker both name
+/// the container's cgroup after its full ID, e.g. `libpod-<id>.scope`.
+fn is_in_container_cgroup(cgroup: &str, container_id: &str) -> bool {
+    // a short or empty ID could match part of an unrelated path
+    container_id.len() == 64
+        && container_id.chars().all(|c| c.is_ascii_hexdigit())
+        && cgroup.contains(container_id)
+}
+
+/// Gets a process's PID inside its innermost PID namespace, from the
+/// `NSpid` line of `/proc/<pid>/status`. For boxes which share the host's
+/// PID namespace this is the same as the host's PID.
+fn parse_namespace_pid(status: &str) -> Option<String> {
+    status
+        .lines()
+        .find_map(|line| line.strip_prefix("NSpid:"))?
+        .split_whitespace()
+        .last()
+        .filter(|pid| pid.parse::<u32>().is_ok())
+        .map(String::from)
+}
+
+/// Sends a signal to a process listed by `get_box_processes`, whose PIDs are
+/// as seen from the host. Refuses any process which isn't in the box, e.g.
+/// because it has exited and its PID has been reused. Returns the runtime's
+/// error if it failed.
+pub fn kill_process_in_box(
+    box_name: &str,
+    pid: &str,
+    signal: ProcessSignal,
+) -> Result<String, String> {
+    if pid.parse::<u32>().is_err() {
+        return Err(format!("{pid} is not a process ID"));
+    }
+
+    let container_id = get_container_id(box_name)?;
+    let cgroup = get_command_output_no_err("cat", Some(&[&format!("/proc/{pid}/cgroup")]));
+    if !is_in_container_cgroup(&cgroup, &container_id) {
+        return Err(format!("Process {pid} is not running in {box_name}"));
+    }
+
+    // the box may have its own PID namespace, where the process has another PID
+    let status = get_command_output_no_err("cat", Some(&[&format!("/proc/{pid}/status")]));
+    let box_pid = parse_namespace_pid(&status)
+        .ok_or_else(|| format!("Process {pid} is not running in {box_name}"))?;
+
+    run_image_command(&["exec", box_name, "kill", "-s", signal.as_str(), &box_pid])
+}
+
+/// Whether or not `BoxBuddy` is running as a Flatpak
+pub fn is_flatpak() -> bool {
+    let fp_env = std::env::var("FLATPAK_ID").is_ok();
+    if fp_env {
+        return true;
+    }
+
+    Path::new("/.flatpak-info").exists()
+}
+
+/// What was found on the host when looking for an NVIDIA GPU
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NvidiaDetection {
+    /// `/proc/driver/nvidia` exists, which the proprietary driver creates
+    pub has_proc_driver: bool,
+    /// The `nvidia` kernel module is loaded
+    pub has_kernel_module: bool,
+    /// `lspci` lists an NVIDIA device
+    pub has_pci_device: bool,
+}
+
+impl NvidiaDetection {
+    /// Whether the proprietary driver is running, which `--nvidia` shares with the box.
+    /// A card on its own isn't enough - it may be using nouveau.
+    pub fn has_driver(&self) -> bool {
+        self.has_proc_driver || self.has_kernel_module
+    }
+}
+
+/// Looks for an NVIDIA GPU and its driver on the host, used to decide
+/// whether to pass the --nvidia flag when creating a new box.
+pub fn detect_nvidia() -> NvidiaDetection {
+    let has_proc_driver =
+        run_command("test", Some(&["-d", "/proc/driver/nvidia"])).is_ok_and(|o| o.status.success());
+
+    let modules = get_command_output_no_err("cat", Some(&["/proc/modules"]));
+    let has_kernel_module = modules
+        .lines()
+        .any(|line| line.split_whitespace().next() == Some("nvidia"));
+
+    // cant detect hardware without lspci, assume no
+    let has_pci_device = has_command_installed("lspci") && {
+        // 10de is NVIDIA's vendor ID, which is there however the device is described
+        let lspci_output = get_command_output_no_err("lspci", Some(&["-nn"]));
+        lspci_output
+            .lines()
+            .any(|line| line.contains("[10de:") || line.to_lowercase().contains("nvidia"))
+    };
+
+    NvidiaDetection {
+        has_proc_driver,
+        has_kernel_module,
+        has_pci_device,
+    }
+}
+
+/// Set up gettext
+#[allow(unused_assignments)]
+pub fn set_up_localisation() {
+    textdomain("boxbuddyrs").expect("failed to initialise gettext");
+    bind_textdomain_codeset("boxbuddyrs", "UTF-8").expect("failed to bind textdomain for gettext");
+
+    let language_code = env::var("LANG").unwrap_or_else(|_| "en_US".to_string());
+
+    let mut locale_directory = String::from("./po");
+
+    // --TRANSLATORS: Comment out the next 8 lines to test your development locale
+    if is_flatpak() {
+        locale_directory = String::from("/app/po");
+    } else {
+        let home_dir = env::var("HOME").unwrap_or_else(|_| ".".to_string());
+        let data_home =
+            env::var("XDG_DATA_HOME").unwrap_or_else(|_| format!("{home_dir}/.local/share"));
+
+        locale_directory = format!("{data_home}/locale");
+    }
+
+    let locale_directory_path = std::path::PathBuf::from(&locale_directory);
+    gettextrs::bindtextdomain("boxbuddyrs", locale_directory_path).expect("a");
+
+    setlocale(LocaleCategory::LcMessages, language_code);
+}
+
+/// Gets list of .desktop files on the host system which may have been exported from
+/// a box. This is to determine whether to show the "Remove from Menu" button on the
+/// View Applications pop-up
+pub fn get_host_desktop_files() -> Vec<String> {
+    let mut host_apps: Vec<String> = Vec::<String>::new();
+
+    if is_flatpak() {
+        // we can't use fs in the flatpak sandbox, so parse `ls`.
+        let mut data_home = get_command_output("bash", Some(&["-c", "echo $XDG_DATA_HOME"]));
+        if data_home.trim().is_empty() {
+            let mut home_dir = get_command_output("bash", Some(&["-c", "echo $HOME"]));
+            home_dir = home_dir.trim().to_string();
+            data_home = format!("{home_dir}/.local/share");
+        }
+
+        let applications_dir = format!("{data_home}/applications");
+
+        let ls_lines = get_command_output("ls", Some(&[applications_dir.as_str()]));
+
+        let desktop_files = ls_lines.split('\n');
+        for df in desktop_files {
+            if !df.is_empty() {
+                host_apps.push(df.to_string());
+            }
+        }
+    } else {
+        let home_dir = env::var("HOME").unwrap_or_else(|_| ".".to_string());
+        let data_home =
+            env::var("XDG_DATA_HOME").unwrap_or_else(|_| format!("{home_dir}/.local/share"));
+
+        let applications_dir = format!("{data_home}/applications");
+        let applications_dir_path = Path::new(&applications_dir);
+
+        if applications_dir_path.exists() {
+            let my_apps = std::fs::read_dir(applications_dir_path);
+            if let Ok(apps) = my_apps {
+                for host_app in apps.flatten() {
+                    if let Ok(fname) = host_app.file_name().into_string() {
+                        host_apps.push(fname);
+                    }
+                }
+            }
+        }
+    }
+
+    host_apps
+}
+
+/// Returns a struct which allows us to determine whether the user has added
+/// a `home` or `host` Filesystem override to a Flatpak install.
+/// This lets us disable features which won't work without these permissions.
 pub fn get_flatpak_filesystem_permissions() -> FilesystemAccess {
     let mut access = FilesystemAccess::new();
     // this will check for BoxBuddy installed as a system flatpak
@@ -729,3 +2037,838 @@ pub fn get_download_dir_path() -> String {
         format!("{hme}/Downloads")
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DIGEST: &str = "sha256:0123456789abcdef0123456789abcdef0123456789abcdef0123456789abcdef";
+
+    #[test]
+    fn valid_image_references() {
+        for image in [
+            "fedora".to_string(),
+            "fedora:40".to_string(),
+            "registry.fedoraproject.org/fedora-toolbox:40".to_string(),
+            "quay.io/toolbx/ubuntu-toolbox:24.04".to_string(),
+            "docker.io/library/ubuntu:latest".to_string(),
+            "localhost/my-box".to_string(),
+            "localhost:5000/me/my_image:v1.0-rc_2".to_string(),
+            "my-registry.example.com:443/a/b/c".to_string(),
+            format!("quay.io/toolbx/arch-toolbox@{DIGEST}"),
+            format!("ubuntu:24.04@{DIGEST}"),
+            format!("fedora:{}", "a".repeat(128)),
+        ] {
+            assert!(is_valid_image_reference(&image), "{image:?}");
+        }
+    }
+
+    #[test]
+    fn invalid_image_references() {
+        for image in [
+            String::new(),
+            "Fedora".to_string(),
+            "docker.io/Library/ubuntu".to_string(),
+            "my image".to_string(),
+            "fedora:".to_string(),
+            "fedora:-40".to_string(),
+            "fedora:40:x".to_string(),
+            format!("fedora:{}", "a".repeat(129)),
+            "localhost:port/img".to_string(),
+            "registry.io:/img".to_string(),
+            "bad_host!.io/img".to_string(),
+            ".io/img".to_string(),
+            "-fedora".to_string(),
+            "fedora-".to_string(),
+            "fed..ora".to_string(),
+            "fed___ora".to_string(),
+            "a//b".to_string(),
+            "fedora@sha256".to_string(),
+            "fedora@sha256:0123abcd".to_string(),
+            format!("fedora@{}", DIGEST.replace('a', "g")),
+            format!("fedora@:{}", &DIGEST[7..]),
+        ] {
+            assert!(!is_valid_image_reference(&image), "{image:?}");
+        }
+    }
+
+    #[test]
+    fn image_reference_parts() {
+        let digested = format!("fedora:40@{DIGEST}");
+        let cases = [
+            ("fedora", ("fedora", None)),
+            ("fedora:40", ("fedora", Some("40"))),
+            ("localhost:5000/img", ("localhost:5000/img", None)),
+            ("localhost:5000/img:1", ("localhost:5000/img", Some("1"))),
+            (
+                "quay.io/toolbx/ubuntu-toolbox:24.04",
+                ("quay.io/toolbx/ubuntu-toolbox", Some("24.04")),
+            ),
+            (digested.as_str(), ("fedora", Some("40"))),
+        ];
+
+        for (image, expected) in cases {
+            assert_eq!(split_image_reference(image), expected, "{image:?}");
+        }
+    }
+
+    #[test]
+    fn json_string_arrays() {
+        let cases = [
+            ("[]", Some(vec![])),
+            (" [ \"a\" , \"b c\" ]\n", Some(vec!["a", "b c"])),
+            (
+                r#"["--init","1","--",
+"echo \"hi\"; cd \\tmp\n\u0026\u00e9"]"#,
+                Some(vec!["--init", "1", "--", "echo \"hi\"; cd \\tmp\n&é"]),
+            ),
+            (r#"["a/b\/c"]"#, Some(vec!["a/b/c"])),
+            ("null", None),
+            ("", None),
+            (r#"["a""#, None),
+            (r#"["a",]"#, None),
+            (r#"["a" "b"]"#, None),
+            (r#"["a"] extra"#, None),
+            (r#"[1]"#, None),
+        ];
+
+        for (json, expected) in cases {
+            assert_eq!(
+                parse_json_string_array(json),
+                expected.map(|v| v.into_iter().map(String::from).collect()),
+                "{json:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn skopeo_tags() {
+        let pretty = r#"{
+    "Repository": "registry.fedoraproject.org/fedora-toolbox",
+    "Tags": [
+        "39",
+        "40",
+        "latest"
+    ]
+}"#;
+        let cases = [
+            (pretty, vec!["39", "40", "latest"]),
+            (
+                r#"{"Repository": "a/b", "Tags": ["1.0","v2_x"]}"#,
+                vec!["1.0", "v2_x"],
+            ),
+            (r#"{"Repository": "a/b", "Tags": []}"#, vec![]),
+            (r#"{"Repository": "a/b", "Tags": null}"#, vec![]),
+            (r#"{"Repository": "a/b"}"#, vec![]),
+            (
+                "time=\"...\" level=fatal msg=\"Error listing repository tags\"",
+                vec![],
+            ),
+            ("", vec![]),
+        ];
+
+        for (output, expected) in cases {
+            assert_eq!(parse_skopeo_tags(output), expected, "{output:?}");
+        }
+    }
+
+    #[test]
+    fn box_names() {
+        let existing = vec!["fedora-box".to_string(), "ubuntu".to_string()];
+        let cases = [
+            (String::new(), Err(BoxNameError::Empty)),
+            ("my-box".to_string(), Ok(())),
+            ("Box_2.0".to_string(), Ok(())),
+            ("9lives".to_string(), Ok(())),
+            ("-box".to_string(), Err(BoxNameError::InvalidCharacters)),
+            (".box".to_string(), Err(BoxNameError::InvalidCharacters)),
+            ("_box".to_string(), Err(BoxNameError::InvalidCharacters)),
+            ("my box".to_string(), Err(BoxNameError::InvalidCharacters)),
+            ("my/box".to_string(), Err(BoxNameError::InvalidCharacters)),
+            ("my:box".to_string(), Err(BoxNameError::InvalidCharacters)),
+            ("bõx".to_string(), Err(BoxNameError::InvalidCharacters)),
+            ("a".repeat(MAX_BOX_NAME_LENGTH), Ok(())),
+            (
+                "a".repeat(MAX_BOX_NAME_LENGTH + 1),
+                Err(BoxNameError::TooLong),
+            ),
+            ("fedora-box".to_string(), Err(BoxNameError::AlreadyExists)),
+            ("Fedora-box".to_string(), Ok(())),
+            ("fedora".to_string(), Ok(())),
+        ];
+
+        for (name, expected) in cases {
+            assert_eq!(validate_box_name(&name, &existing), expected, "{name:?}");
+        }
+    }
+
+    #[test]
+    fn stats_output() {
+        let output = "\
+fedora;1.23%;0.45%;123.4MB / 16.5GB;1.2kB / 3.4kB;0B / 0B
+ubuntu;0.00%;0.12%;20.5MiB / 15.5GiB;5.6kB / 0B;8.19kB / 4.1kB
+ arch ; 12.5% ; 3% ; 1GB / 2GB ; -- ; --
+
+not a stats line
+too;few;columns
+;1%;1%;1MB / 2MB;0B / 0B;0B / 0B
+one;too;many;columns;in;this;line
+";
+        let stats = parse_stats_output(output);
+
+        let cases = [
+            (
+                "fedora",
+                [
+                    "1.23%",
+                    "0.45%",
+                    "123.4MB / 16.5GB",
+                    "1.2kB / 3.4kB",
+                    "0B / 0B",
+                ],
+            ),
+            (
+                "ubuntu",
+                [
+                    "0.00%",
+                    "0.12%",
+                    "20.5MiB / 15.5GiB",
+                    "5.6kB / 0B",
+                    "8.19kB / 4.1kB",
+                ],
+            ),
+            ("arch", ["12.5%", "3%", "1GB / 2GB", "--", "--"]),
+        ];
+
+        assert_eq!(stats.len(), cases.len());
+        for (name, [cpu, mem, mem_percent, net_io, block_io]) in cases {
+            let usage = &stats[name];
+            assert_eq!(usage.cpu, cpu, "{name:?}");
+            assert_eq!(usage.mem, mem, "{name:?}");
+            assert_eq!(usage.mem_percent, mem_percent, "{name:?}");
+            assert_eq!(usage.net_io, net_io, "{name:?}");
+            assert_eq!(usage.block_io, block_io, "{name:?}");
+        }
+    }
+
+    #[test]
+    fn percentages() {
+        let cases = [
+            ("12.34%", 12.34),
+            (" 0.00% ", 0.0),
+            ("100%", 100.0),
+            ("7", 7.0),
+            ("1.5 %", 1.5),
+            ("--", 0.0),
+            ("", 0.0),
+            ("abc%", 0.0),
+        ];
+
+        for (percentage, expected) in cases {
+            assert_eq!(parse_percentage(percentage), expected, "{percentage:?}");
+        }
+    }
+
+    /// A row of `system df -v` output as (heading, cell) pairs
+    fn df_row(cells: &[(&str, &str)]) -> HashMap<String, String> {
+        cells
+            .iter()
+            .map(|(heading, cell)| (heading.to_string(), cell.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn podman_system_df_output() {
+        let output = "\
+Images space usage:
+
+REPOSITORY                                 TAG     IMAGE ID      CREATED       SIZE   SHARED SIZE  UNIQUE SIZE  CONTAINERS
+registry.fedoraproject.org/fedora-toolbox  40      2f3bfb4e9fb4  2 weeks ago   1.9GB  0B           1.9GB        1
+<none>                                     <none>  5c7d9e0f1a2b  2 months ago  1.2GB  0B           1.2GB        0
+
+Containers space usage:
+
+CONTAINER ID  IMAGE         COMMAND  LOCAL VOLUMES  SIZE    CREATED      STATUS   NAMES
+b1c2d3e4f5a6  2f3bfb4e9fb4           0              57.3MB  2 weeks ago  running  fedora
+
+Local Volumes space usage:
+
+VOLUME NAME  LINKS  SIZE
+";
+        let sections = parse_system_df_output(output);
+
+        assert_eq!(
+            sections["Images"],
+            vec![
+                df_row(&[
+                    ("REPOSITORY", "registry.fedoraproject.org/fedora-toolbox"),
+                    ("TAG", "40"),
+                    ("IMAGE ID", "2f3bfb4e9fb4"),
+                    ("CREATED", "2 weeks ago"),
+                    ("SIZE", "1.9GB"),
+                    ("SHARED SIZE", "0B"),
+                    ("UNIQUE SIZE", "1.9GB"),
+                    ("CONTAINERS", "1"),
+                ]),
+                df_row(&[
+                    ("REPOSITORY", "<none>"),
+                    ("TAG", "<none>"),
+                    ("IMAGE ID", "5c7d9e0f1a2b"),
+                    ("CREATED", "2 months ago"),
+                    ("SIZE", "1.2GB"),
+                    ("SHARED SIZE", "0B"),
+                    ("UNIQUE SIZE", "1.2GB"),
+                    ("CONTAINERS", "0"),
+                ]),
+            ]
+        );
+        assert_eq!(
+            sections["Containers"],
+            vec![df_row(&[
+                ("CONTAINER ID", "b1c2d3e4f5a6"),
+                ("IMAGE", "2f3bfb4e9fb4"),
+                ("COMMAND", ""),
+                ("LOCAL VOLUMES", "0"),
+                ("SIZE", "57.3MB"),
+                ("CREATED", "2 weeks ago"),
+                ("STATUS", "running"),
+                ("NAMES", "fedora"),
+            ])]
+        );
+        assert!(!sections.contains_key("Local Volumes"));
+    }
+
+    #[test]
+    fn docker_system_df_output() {
+        let output = "\
+Images space usage:
+
+REPOSITORY   TAG     IMAGE ID       CREATED       SIZE     SHARED SIZE   UNIQUE SIZE   CONTAINERS
+ubuntu       22.04   3db8720ecbf5   5 weeks ago   77.9MB   0B            77.9MB        1
+
+Containers space usage:
+
+CONTAINER ID   IMAGE          COMMAND                  LOCAL VOLUMES   SIZE     CREATED      STATUS       NAMES
+e7a3b4c5d6f7   ubuntu:22.04   \"/usr/bin/entrypoint…\"   0               12.3MB   2 days ago   Up 2 hours   ubuntu-box
+
+Local Volumes space usage:
+
+VOLUME NAME   LINKS   SIZE
+my-volume     1       4.096kB
+
+Build cache usage: 0B
+
+CACHE ID   CACHE TYPE   SIZE   CREATED   LAST USED   USAGE   SHARED
+";
+        let sections = parse_system_df_output(output);
+
+        assert_eq!(
+            sections["Images"],
+            vec![df_row(&[
+                ("REPOSITORY", "ubuntu"),
+                ("TAG", "22.04"),
+                ("IMAGE ID", "3db8720ecbf5"),
+                ("CREATED", "5 weeks ago"),
+                ("SIZE", "77.9MB"),
+                ("SHARED SIZE", "0B"),
+                ("UNIQUE SIZE", "77.9MB"),
+                ("CONTAINERS", "1"),
+            ])]
+        );
+        assert_eq!(
+            sections["Containers"],
+            vec![df_row(&[
+                ("CONTAINER ID", "e7a3b4c5d6f7"),
+                ("IMAGE", "ubuntu:22.04"),
+                ("COMMAND", "\"/usr/bin/entrypoint…\""),
+                ("LOCAL VOLUMES", "0"),
+                ("SIZE", "12.3MB"),
+                ("CREATED", "2 days ago"),
+                ("STATUS", "Up 2 hours"),
+                ("NAMES", "ubuntu-box"),
+            ])]
+        );
+        assert_eq!(
+            sections["Local Volumes"],
+            vec![df_row(&[
+                ("VOLUME NAME", "my-volume"),
+                ("LINKS", "1"),
+                ("SIZE", "4.096kB"),
+            ])]
+        );
+        assert!(!sections.contains_key("Build cache"));
+    }
+
+    #[test]
+    fn rows_split_by_headings() {
+        let header = "NAME    IMAGE ID   SIZE";
+        let cases = [
+            (
+                "box     abc123     1GB",
+                [("NAME", "box"), ("IMAGE ID", "abc123"), ("SIZE", "1GB")],
+            ),
+            (
+                "box                1GB",
+                [("NAME", "box"), ("IMAGE ID", ""), ("SIZE", "1GB")],
+            ),
+            (
+                "my box  abc123     12.5 MB",
+                [
+                    ("NAME", "my box"),
+                    ("IMAGE ID", "abc123"),
+                    ("SIZE", "12.5 MB"),
+                ],
+            ),
+            (
+                "box     abc",
+                [("NAME", "box"), ("IMAGE ID", "abc"), ("SIZE", "")],
+            ),
+            ("", [("NAME", ""), ("IMAGE ID", ""), ("SIZE", "")]),
+        ];
+
+        for (row, expected) in cases {
+            assert_eq!(
+                split_row_by_headings(header, row),
+                df_row(&expected),
+                "{row:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn sizes() {
+        let cases = [
+            ("0B", 0),
+            ("512", 512),
+            ("1.9GB", 1_900_000_000),
+            ("1.9 GB", 1_900_000_000),
+            ("448MB", 448_000_000),
+            ("4.096kB", 4096),
+            ("12.3 MiB", 12_897_484),
+            ("1KiB", 1024),
+            ("2GiB", 2_147_483_648),
+            ("1.5TB", 1_500_000_000_000),
+            ("1TiB", 1_099_511_627_776),
+            (" 77.9mb ", 77_900_000),
+            ("", 0),
+            ("MB", 0),
+            ("12 parsecs", 0),
+            ("1.2.3GB", 0),
+        ];
+
+        for (size, expected) in cases {
+            assert_eq!(parse_size(size), expected, "{size:?}");
+        }
+    }
+
+    #[test]
+    fn formatted_sizes() {
+        let cases = [
+            (0, "0 B"),
+            (999, "999 B"),
+            (1000, "1.0 kB"),
+            (4096, "4.1 kB"),
+            (1_900_000_000, "1.9 GB"),
+            (448_000_000, "448.0 MB"),
+            (1_500_000_000_000, "1.5 TB"),
+            (2_000_000_000_000_000, "2000.0 TB"),
+        ];
+
+        for (bytes, expected) in cases {
+            assert_eq!(format_size(bytes), expected, "{bytes:?}");
+        }
+    }
+
+    #[test]
+    fn sizes_round_trip() {
+        // format_size rounds to one decimal place, so only these come back exactly
+        for bytes in [
+            0,
+            7,
+            999,
+            1000,
+            1_500,
+            2_300_000,
+            448_000_000,
+            1_900_000_000,
+        ] {
+            assert_eq!(parse_size(&format_size(bytes)), bytes, "{bytes:?}");
+        }
+
+        for size in ["0 B", "1.0 kB", "12.5 MB", "1.9 GB", "3.2 TB"] {
+            assert_eq!(format_size(parse_size(size)), size, "{size:?}");
+        }
+    }
+
+    const CONTAINER_ID: &str = "4c1f0a2b3d4e5f60718293a4b5c6d7e8f90a1b2c3d4e5f60718293a4b5c6d7e8";
+
+    #[test]
+    fn top_output() {
+        let output = "\
+    PID USER     %CPU %MEM CGROUP                                       COMMAND
+      1 root      0.0  0.1 0::/init.scope                               /usr/lib/systemd/systemd --switched-root --system
+
+   4242 alice     1.5  0.3 0::/user.slice/libpod-abc.scope/container    /bin/sh -c  echo  'two  spaces'
+   4243 alice     0.0  0.0 -                                            [kworker/0:1-events]
+   4244 alice     2.0  1.0 0::/user.slice/libpod-abc.scope/container
+not a process line
+   abc  alice     0.0  0.0 0::/                                         sh
+   4245 alice     0.0
+";
+        let processes = parse_top_output(output);
+
+        let expected = [
+            (
+                "1",
+                "root",
+                "0.0",
+                "0.1",
+                "0::/init.scope",
+                "/usr/lib/systemd/systemd --switched-root --system",
+            ),
+            (
+                "4242",
+                "alice",
+                "1.5",
+                "0.3",
+                "0::/user.slice/libpod-abc.scope/container",
+                "/bin/sh -c echo 'two spaces'",
+            ),
+            ("4243", "alice", "0.0", "0.0", "-", "[kworker/0:1-events]"),
+            (
+                "4244",
+                "alice",
+                "2.0",
+                "1.0",
+                "0::/user.slice/libpod-abc.scope/container",
+                "",
+            ),
+        ];
+
+        assert_eq!(processes.len(), expected.len());
+        for (process, (pid, user, cpu, mem, cgroup, command)) in processes.iter().zip(expected) {
+            assert_eq!(process.pid, pid);
+            assert_eq!(process.user, user, "{pid:?}");
+            assert_eq!(process.cpu, cpu, "{pid:?}");
+            assert_eq!(process.mem, mem, "{pid:?}");
+            assert_eq!(process.cgroup, cgroup, "{pid:?}");
+            assert_eq!(process.command, command, "{pid:?}");
+        }
+    }
+
+    #[test]
+    fn container_cgroups() {
+        let other_id = CONTAINER_ID.replace('4', "5");
+        let cases = [
+            // rootless podman
+            (
+                format!("0::/user.slice/user-1000.slice/user@1000.service/user.slice/libpod-{CONTAINER_ID}.scope/container"),
+                true,
+            ),
+            // rootful podman
+            (format!("0::/machine.slice/libpod-{CONTAINER_ID}.scope"), true),
+            // docker with systemd, and with cgroupfs
+            (format!("0::/system.slice/docker-{CONTAINER_ID}.scope"), true),
+            (format!("0::/docker/{CONTAINER_ID}"), true),
+            // cgroup v1, one line per controller in /proc/<pid>/cgroup
+            (
+                format!("12:pids:/docker/{CONTAINER_ID}\n11:memory:/docker/{CONTAINER_ID}\n"),
+                true,
+            ),
+            // the host's own processes, and another box's
+            (
+                "0::/user.slice/user-1000.slice/user@1000.service/app.slice/app-gnome-firefox.scope"
+                    .to_string(),
+                false,
+            ),
+            ("0::/init.scope".to_string(), false),
+            (format!("0::/machine.slice/libpod-{other_id}.scope"), false),
+            // conmon sits next to the container, not in it
+            (
+                format!("0::/user.slice/user-1000.slice/user@1000.service/user.slice/libpod-conmon-{other_id}.scope"),
+                false,
+            ),
+            ("-".to_string(), false),
+            (String::new(), false),
+        ];
+
+        for (cgroup, expected) in cases {
+            assert_eq!(
+                is_in_container_cgroup(&cgroup, CONTAINER_ID),
+                expected,
+                "{cgroup:?}"
+            );
+        }
+
+        // anything but a full ID could match part of an unrelated path
+        let cgroup = format!("0::/docker/{CONTAINER_ID}");
+        for container_id in [
+            "",
+            "4c1f0a2b3d4e",
+            "docker",
+            &CONTAINER_ID.replace('a', "x"),
+        ] {
+            assert!(
+                !is_in_container_cgroup(&cgroup, container_id),
+                "{container_id:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn namespace_pids() {
+        let cases = [
+            ("Name:\tsh\nPid:\t4242\nNSpid:\t4242\n", Some("4242")),
+            ("Name:\tsh\nPid:\t4242\nNSpid:\t4242\t7\n", Some("7")),
+            ("NSpid:\t4242\t120\t7", Some("7")),
+            ("Name:\tsh\nPid:\t4242\n", None),
+            ("NSpid:\t", None),
+            ("NSpid:\tx", None),
+            ("", None),
+        ];
+
+        for (status, expected) in cases {
+            assert_eq!(
+                parse_namespace_pid(status).as_deref(),
+                expected,
+                "{status:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn images_output() {
+        let output = "\
+2f3bfb4e9fb4;registry.fedoraproject.org/fedora-toolbox:40;1.9GB;2024-01-10 12:00:00 +0000 UTC
+5c7d9e0f1a2b;<none>:<none>;1.2 GB;2023-11-02 08:30:00 +0000 UTC
+ 3db8720ecbf5;docker.io/library/ubuntu:22.04;77.9MB;2024-02-01 00:00:00 +0000 UTC 
+
+not an image
+3db8720ecbf5;ubuntu:22.04;77.9MB
+";
+        let images = parse_images_output(output);
+
+        let expected = [
+            (
+                "2f3bfb4e9fb4",
+                "registry.fedoraproject.org/fedora-toolbox:40",
+                1_900_000_000,
+                "2024-01-10",
+            ),
+            ("5c7d9e0f1a2b", "<none>:<none>", 1_200_000_000, "2023-11-02"),
+            (
+                "3db8720ecbf5",
+                "docker.io/library/ubuntu:22.04",
+                77_900_000,
+                "2024-02-01",
+            ),
+        ];
+
+        assert_eq!(images.len(), expected.len());
+        for (image, (id, name, size, created)) in images.iter().zip(expected) {
+            assert_eq!(image.id, id);
+            assert_eq!(image.name, name, "{id:?}");
+            assert_eq!(image.size, size, "{id:?}");
+            assert_eq!(image.created, created, "{id:?}");
+            assert!(image.boxes.is_empty(), "{id:?}");
+        }
+    }
+
+    #[test]
+    fn image_boxes() {
+        let fedora_id = format!("2f3bfb4e9fb4{}", "1".repeat(52));
+        let untagged_id = format!("5c7d9e0f1a2b{}", "2".repeat(52));
+        let inspect_output = format!(
+            "fedora-dev|{fedora_id}\n\
+             /fedora-test|sha256:{fedora_id}\n\
+             old-box|{untagged_id}\n\
+             aardvark|{fedora_id}\n\
+             \n\
+             not a box\n"
+        );
+
+        let box_image_ids = parse_box_image_ids(&inspect_output);
+        assert_eq!(box_image_ids.len(), 4);
+        assert_eq!(box_image_ids["fedora-test"], fedora_id);
+
+        let mut images = parse_images_output(
+            "2f3bfb4e9fb4;registry.fedoraproject.org/fedora-toolbox:40;1.9GB;2024-01-10\n\
+             2f3bfb4e9fb4;localhost/fedora-toolbox:mine;1.9GB;2024-01-10\n\
+             5c7d9e0f1a2b;<none>:<none>;1.2GB;2023-11-02\n\
+             3db8720ecbf5;docker.io/library/ubuntu:22.04;77.9MB;2024-02-01\n\
+             ;<none>:<none>;0B;2023-01-01\n",
+        );
+        set_image_boxes(&mut images, &box_image_ids);
+
+        let expected: [&[&str]; 5] = [
+            &["aardvark", "fedora-dev", "fedora-test"],
+            &["aardvark", "fedora-dev", "fedora-test"],
+            &["old-box"],
+            &[],
+            &[],
+        ];
+        for (image, boxes) in images.iter().zip(expected) {
+            assert_eq!(image.boxes, boxes, "{:?}", image.name);
+        }
+    }
+
+    fn assert_close(actual: f64, expected: f64, context: &str) {
+        assert!(
+            (actual - expected).abs() < 1e-9,
+            "{context}: {actual} != {expected}"
+        );
+    }
+
+    #[test]
+    fn pull_lines() {
+        let cases = [
+            // podman, when not in a terminal
+            ("Copying blob 4f4fb700ef54 done", Some(("4f4fb700ef54", 1.0))),
+            ("Copying blob 4f4fb700ef54 done   | ", Some(("4f4fb700ef54", 1.0))),
+            (
+                "Copying blob sha256:4f4fb700ef54461cfa02571ae0db9a0dc1e0cdb5577484a6d75e68dc38e8acc1",
+                Some(("4f4fb700ef54", 0.0)),
+            ),
+            ("Copying blob 9d2b6a1c7e3f skipped: already exists", Some(("9d2b6a1c7e3f", 1.0))),
+            ("Copying blob 9d2b6a1c7e3f already exists", Some(("9d2b6a1c7e3f", 1.0))),
+            // podman, in a terminal
+            (
+                "Copying blob 4f4fb700ef54 [====>-------] 10.0MiB / 40.0MiB",
+                Some(("4f4fb700ef54", 0.25)),
+            ),
+            // docker
+            ("4f4fb700ef54: Pulling fs layer", Some(("4f4fb700ef54", 0.0))),
+            ("4f4fb700ef54: Waiting", Some(("4f4fb700ef54", 0.0))),
+            (
+                "4f4fb700ef54: Downloading [=====>      ]  10MB/40MB",
+                Some(("4f4fb700ef54", 0.7 * 0.25)),
+            ),
+            ("4f4fb700ef54: Verifying Checksum", Some(("4f4fb700ef54", 0.7))),
+            ("4f4fb700ef54: Download complete", Some(("4f4fb700ef54", 0.7))),
+            (
+                "4f4fb700ef54: Extracting [==========>  ]  20MB/40MB",
+                Some(("4f4fb700ef54", 0.85)),
+            ),
+            ("4f4fb700ef54: Pull complete", Some(("4f4fb700ef54", 1.0))),
+            ("4f4fb700ef54: Already exists", Some(("4f4fb700ef54", 1.0))),
+            ("  4f4fb700ef54: Pull complete  ", Some(("4f4fb700ef54", 1.0))),
+            // lines which aren't about a layer
+            ("Trying to pull registry.fedoraproject.org/fedora-toolbox:40...", None),
+            ("Getting image source signatures", None),
+            ("Copying config 2f3bfb4e9f done", None),
+            ("Writing manifest to image destination", None),
+            ("40: Pulling from library/ubuntu", None),
+            (
+                "Digest: sha256:4f4fb700ef54461cfa02571ae0db9a0dc1e0cdb5577484a6d75e68dc38e8acc1",
+                None,
+            ),
+            ("Status: Downloaded newer image for ubuntu:24.04", None),
+            ("docker.io/library/ubuntu:24.04", None),
+            ("4f4fb700ef5g: Pull complete", None),
+            ("Copying blob ", None),
+            ("", None),
+        ];
+
+        for (line, expected) in cases {
+            let parsed = parse_pull_line(line);
+            match (parsed, expected) {
+                (Some((layer_id, progress)), Some((expected_id, expected_progress))) => {
+                    assert_eq!(layer_id, expected_id, "{line:?}");
+                    assert_close(progress, expected_progress, line);
+                }
+                (parsed, expected) => {
+                    assert_eq!(parsed.is_some(), expected.is_some(), "{line:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn transfer_fractions() {
+        let cases = [
+            ("10.0MiB / 40.0MiB", Some(0.25)),
+            ("Downloading [==>   ]  1MB/4MB", Some(0.25)),
+            ("[========>] 2KiB / 1KiB", Some(1.0)),
+            ("512B/1kB", Some(0.512)),
+            ("1MB/0B", None),
+            ("no transfer here", None),
+            ("", None),
+        ];
+
+        for (status, expected) in cases {
+            match (parse_transfer_fraction(status), expected) {
+                (Some(fraction), Some(expected)) => assert_close(fraction, expected, status),
+                (fraction, expected) => assert_eq!(fraction, expected, "{status:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn podman_pull_progress() {
+        let mut progress = PullProgress::default();
+        let lines = [
+            (
+                "Trying to pull registry.fedoraproject.org/fedora-toolbox:40...",
+                false,
+                0.0,
+            ),
+            ("Getting image source signatures", false, 0.0),
+            ("Copying blob 4f4fb700ef54 done", true, 1.0),
+            ("Copying blob 9d2b6a1c7e3f", true, 0.5),
+            ("Copying config 2f3bfb4e9f done", true, 1.0),
+            ("Writing manifest to image destination", true, 1.0),
+        ];
+
+        for (line, is_layer_line, fraction) in lines {
+            assert_eq!(progress.update(line), is_layer_line, "{line:?}");
+            assert_close(progress.fraction(), fraction, line);
+        }
+        assert_eq!(progress.layers_done(), 2);
+        assert_eq!(progress.layers_total(), 2);
+    }
+
+    #[test]
+    fn docker_pull_progress() {
+        let mut progress = PullProgress::default();
+        let lines = [
+            ("24.04: Pulling from library/ubuntu", false, 0.0, 0),
+            ("4f4fb700ef54: Pulling fs layer", true, 0.0, 0),
+            ("9d2b6a1c7e3f: Already exists", true, 0.5, 1),
+            (
+                "4f4fb700ef54: Downloading [=====>      ]  10MB/40MB",
+                true,
+                0.5875,
+                1,
+            ),
+            ("4f4fb700ef54: Download complete", true, 0.85, 1),
+            (
+                "4f4fb700ef54: Extracting [==========>  ]  20MB/40MB",
+                true,
+                0.925,
+                1,
+            ),
+            // progress never goes backwards, even if lines arrive out of order
+            (
+                "4f4fb700ef54: Downloading [=====>      ]  20MB/40MB",
+                true,
+                0.925,
+                1,
+            ),
+            ("4f4fb700ef54: Pull complete", true, 1.0, 2),
+            ("Digest: sha256:4f4fb700ef54", false, 1.0, 2),
+            (
+                "Status: Downloaded newer image for ubuntu:24.04",
+                false,
+                1.0,
+                2,
+            ),
+        ];
+
+        for (line, is_layer_line, fraction, done) in lines {
+            assert_eq!(progress.update(line), is_layer_line, "{line:?}");
+            assert_close(progress.fraction(), fraction, line);
+            assert_eq!(progress.layers_done(), done, "{line:?}");
+        }
+        assert_eq!(progress.layers_total(), 2);
+    }
+}